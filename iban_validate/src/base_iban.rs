@@ -1,4 +1,4 @@
-use crate::IbanLike;
+use crate::{Formatted, IbanLike, Masked, PhoneticAlphabet, Spoken};
 #[cfg(doc)]
 use crate::{Iban, ParseIbanError};
 use arrayvec::ArrayString;
@@ -11,12 +11,28 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use zeroize_derive::ZeroizeOnDrop;
 
 /// The size of a group of characters in the paper format.
-const PAPER_GROUP_SIZE: usize = 4;
+pub(crate) const PAPER_GROUP_SIZE: usize = 4;
 
 /// The maximum length an IBAN can be, according to the spec. This variable is
 /// used for the capacity of the arrayvec, which in turn determines how long a
 /// valid IBAN can be.
-const MAX_IBAN_LEN: usize = 34;
+pub(crate) const MAX_IBAN_LEN: usize = 34;
+
+/// Split an IBAN in the electronic format into groups of the given size, as
+/// in the paper format. A group size of zero results in a single group.
+pub(crate) fn groups(electronic: &str, group_size: usize) -> impl Iterator<Item = &str> {
+    let group_size = if group_size == 0 {
+        electronic.len().max(1)
+    } else {
+        group_size
+    };
+    electronic.as_bytes().chunks(group_size).map(|group| {
+        core::str::from_utf8(group).expect(
+            "An IBAN contained non-ASCII characters. Please create an issue at \
+             https://github.com/ThomasdenH/iban_validate.",
+        )
+    })
+}
 
 /// Represents an IBAN that passed basic checks, but not necessarily the BBAN
 /// validation. This corresponds to the validation as described in ISO 13616-1.
@@ -77,14 +93,20 @@ const MAX_IBAN_LEN: usize = 34;
 /// formatting, [`Debug`] can be used to output the former and [`Display`] for
 /// the latter. This is true for a [`BaseIban`] as well as an [`Iban`].
 /// Alternatively, you can use [`IbanLike::electronic_str`] to obtain the
-/// electronic format as a string slice.
+/// electronic format as a string slice. If the `redact_debug` feature is
+/// enabled, [`Debug`] shows a [`masked`](BaseIban::masked) IBAN instead. The
+/// alternate flag, `{:#}`, makes [`Display`] output the electronic format. For
+/// more options, see [`BaseIban::formatted`].
 /// ```
 /// # use iban::ParseBaseIbanError;
 /// let iban: iban::BaseIban = "RO66BACX0000001234567890".parse()?;
 /// // Use Debug for the electronic format.
+/// # #[cfg(not(feature = "redact_debug"))]
 /// assert_eq!(&format!("{:?}", iban), "RO66BACX0000001234567890");
 /// // Use Display for the paper format.
 /// assert_eq!(&format!("{}", iban), "RO66 BACX 0000 0012 3456 7890");
+/// // Use the alternate flag for the electronic format.
+/// assert_eq!(&format!("{:#}", iban), "RO66BACX0000001234567890");
 /// # Ok::<(), ParseBaseIbanError>(())
 /// ```
 ///
@@ -149,28 +171,96 @@ impl IbanLike for BaseIban {
     }
 }
 
+impl BaseIban {
+    /// Get a view of the IBAN in which most characters are replaced by `*`,
+    /// which can be displayed. See [`Masked`] for the options.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::{BaseIban, ParseBaseIbanError};
+    /// let iban: BaseIban = "DE44 5001 0517 5407 3249 31".parse()?;
+    /// assert_eq!(iban.masked().to_string(), "DE44 **** **** **** **49 31");
+    /// # Ok::<(), ParseBaseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn masked(&self) -> Masked<'_> {
+        Masked::new(self.electronic_str(), None)
+    }
+
+    /// Get a formatting of the IBAN with configurable options, which can be
+    /// displayed. See [`Formatted`] for the options.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::{BaseIban, ParseBaseIbanError};
+    /// let iban: BaseIban = "DE44 5001 0517 5407 3249 31".parse()?;
+    /// assert_eq!(
+    ///     iban.formatted().separator('-').to_string(),
+    ///     "DE44-5001-0517-5407-3249-31"
+    /// );
+    /// # Ok::<(), ParseBaseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn formatted(&self) -> Formatted<'_> {
+        Formatted::new(self.electronic_str())
+    }
+
+    /// Get the IBAN rendered for reading aloud, with letters spelled using
+    /// the given phonetic alphabet. See [`Spoken`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::{BaseIban, ParseBaseIbanError, PhoneticAlphabet};
+    /// let iban: BaseIban = "FR14 2004 1010 0505 0001 3M02 606".parse()?;
+    /// assert_eq!(
+    ///     iban.spoken(PhoneticAlphabet::French).to_string(),
+    ///     "François Raoul 1 4, 2 0 0 4, 1 0 1 0, 0 5 0 5, 0 0 0 1, 3 Marcel 0 2, 6 0 6"
+    /// );
+    /// # Ok::<(), ParseBaseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn spoken(&self, alphabet: PhoneticAlphabet) -> Spoken<'_> {
+        Spoken::new(self.electronic_str(), alphabet)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for BaseIban {
+    /// Overwrite the characters of the IBAN with zeros. The length is kept,
+    /// so the result is no longer a valid IBAN.
+    fn zeroize(&mut self) {
+        let len = self.s.len();
+        self.s.clear();
+        for _ in 0..len {
+            self.s.push('\0');
+        }
+    }
+}
+
 impl Debug for BaseIban {
+    #[cfg(not(feature = "redact_debug"))]
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         Display::fmt(&self.s, f)
     }
+
+    /// With the `redact_debug` feature, the IBAN is masked.
+    #[cfg(feature = "redact_debug")]
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.masked().electronic(), f)
+    }
 }
 
 impl Display for BaseIban {
+    /// Display the IBAN in the paper format, or in the electronic format if
+    /// the alternate flag `{:#}` is used. Padding is supported.
+    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for c in self.s.chars().enumerate().flat_map(|(i, c)| {
-            // Add a space before a character if it is the start of a group of four.
-            if i != 0 && i % PAPER_GROUP_SIZE == 0 {
-                Some(' ')
-            } else {
-                None
-            }
-            .into_iter()
-            .chain(core::iter::once(c))
-        }) {
-            write!(f, "{c}")?;
-        }
-        Ok(())
+        Display::fmt(&self.formatted(), f)
     }
 }
 
@@ -197,7 +287,9 @@ impl Display for BaseIban {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ParseBaseIbanError {
     /// The string doesn't have the correct format to be an IBAN. This can be because it's too
-    /// short, too long or because it contains unexpected characters at some location.
+    /// short, too long or because it contains unexpected characters at some location. Use
+    /// [`suspicious_characters`](crate::suspicious_characters) to find characters that only
+    /// look like those of an IBAN.
     InvalidFormat,
     /// The IBAN has an invalid structure.
     InvalidChecksum,
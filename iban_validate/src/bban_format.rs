@@ -0,0 +1,202 @@
+//! Public access to the BBAN formats that are used for validation, and a
+//! renderer for regular expressions that follow the same rules.
+
+use crate::countries::{self, CharacterType, Matchable};
+use crate::Country;
+use core::fmt::{self, Display};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The format of a BBAN, consisting of segments of characters of the same
+/// type. It is obtained via [`bban_format`] or [`Country::bban_format`], and
+/// describes exactly the rules that are used when parsing an [`Iban`](crate::Iban).
+///
+/// # Example
+/// ```rust
+/// use iban::CharacterType;
+/// let format = iban::bban_format("GB").expect("the United Kingdom is in the registry");
+/// assert_eq!(
+///     format.segments(),
+///     &[(4, CharacterType::A), (6, CharacterType::N), (8, CharacterType::N)]
+/// );
+/// assert_eq!(format.len(), 18);
+/// assert_eq!(format.to_string(), "4!a6!n8!n");
+/// assert!(format.matches("NWBK60161331926819"));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BbanFormat<'a> {
+    segments: &'a [(usize, CharacterType)],
+}
+
+impl<'a> BbanFormat<'a> {
+    pub(crate) fn new(segments: &'a [(usize, CharacterType)]) -> Self {
+        BbanFormat { segments }
+    }
+
+    /// Get the segments of the format. Every segment consists of a number of
+    /// characters of the same type.
+    #[inline]
+    #[must_use]
+    pub fn segments(&self) -> &'a [(usize, CharacterType)] {
+        self.segments
+    }
+
+    /// Get the length of a BBAN following this format.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        countries::len(self.segments)
+    }
+
+    /// Check whether the format is empty, i.e. whether it only matches an
+    /// empty BBAN.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Check whether a BBAN follows this format. The BBAN should be in the
+    /// electronic format, with uppercase letters.
+    #[inline]
+    #[must_use]
+    pub fn matches(&self, bban: &str) -> bool {
+        bban.bytes()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            && self.segments.match_str(bban)
+    }
+
+    /// Get a regular expression that matches exactly the BBANs in the
+    /// electronic format that follow this format. The expression can be
+    /// displayed in the given flavor.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::{Country, RegexFlavor};
+    /// let format = Country::GB.bban_format().expect("the format is known");
+    /// assert_eq!(
+    ///     format.regex(RegexFlavor::JavaScript).to_string(),
+    ///     r"^[A-Z]{4}\d{14}$"
+    /// );
+    /// assert_eq!(
+    ///     format.regex(RegexFlavor::Pcre).to_string(),
+    ///     r"\A[A-Z]{4}[0-9]{14}\z"
+    /// );
+    /// assert_eq!(
+    ///     format.regex(RegexFlavor::Posix).for_country(Country::GB).to_string(),
+    ///     "^GB[0-9]{2}[A-Z]{4}[0-9]{14}$"
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn regex(&self, flavor: RegexFlavor) -> BbanRegex<'a> {
+        BbanRegex {
+            format: *self,
+            flavor,
+            country: None,
+        }
+    }
+}
+
+impl Display for BbanFormat<'_> {
+    /// Display the format in the registry notation, such as `8!n10!n`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (count, character_type) in self.segments {
+            write!(f, "{}!{}", count, character_type.notation())?;
+        }
+        Ok(())
+    }
+}
+
+/// Get the BBAN format of the country with the given country code. This is
+/// the format that is used to validate an [`Iban`](crate::Iban), so it
+/// includes dependent territories and, if that feature is enabled, the
+/// extended countries. Returns `None` if the format is unknown.
+#[inline]
+#[must_use]
+pub fn bban_format(country_code: &str) -> Option<BbanFormat<'static>> {
+    countries::country_pattern(country_code).map(BbanFormat::new)
+}
+
+impl Country {
+    /// Get the BBAN format of this country. See [`bban_format`].
+    #[inline]
+    #[must_use]
+    pub fn bban_format(&self) -> Option<BbanFormat<'static>> {
+        bban_format(self.as_str())
+    }
+}
+
+/// The dialect of regular expressions to render.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RegexFlavor {
+    /// Regular expressions in JavaScript, such as for the `pattern` attribute
+    /// of an HTML input. Digits are matched by `\d`, which only matches ASCII
+    /// digits without the `u` flag.
+    JavaScript,
+    /// Perl compatible regular expressions. The expression is anchored using
+    /// `\A` and `\z`, since `$` would also allow a trailing newline.
+    Pcre,
+    /// POSIX extended regular expressions, as used by many databases.
+    Posix,
+}
+
+/// A regular expression matching a BBAN format, which can be displayed. It is
+/// returned by [`BbanFormat::regex`]. The expression is anchored at both ends
+/// and does not allow lowercase letters or whitespace.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BbanRegex<'a> {
+    format: BbanFormat<'a>,
+    flavor: RegexFlavor,
+    country: Option<Country>,
+}
+
+impl BbanRegex<'_> {
+    /// Match a full IBAN in the electronic format instead of a BBAN, by
+    /// prefixing the expression with the country code and the check digits.
+    #[inline]
+    #[must_use]
+    pub fn for_country(self, country: Country) -> Self {
+        BbanRegex {
+            country: Some(country),
+            ..self
+        }
+    }
+}
+
+impl Display for BbanRegex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digit = match self.flavor {
+            RegexFlavor::JavaScript => r"\d",
+            RegexFlavor::Pcre | RegexFlavor::Posix => "[0-9]",
+        };
+        let class = |character_type| match character_type {
+            CharacterType::N => digit,
+            CharacterType::A => "[A-Z]",
+            CharacterType::C => "[A-Z0-9]",
+        };
+        f.write_str(match self.flavor {
+            RegexFlavor::Pcre => r"\A",
+            RegexFlavor::JavaScript | RegexFlavor::Posix => "^",
+        })?;
+        if let Some(country) = self.country {
+            write!(f, "{}{}{{2}}", country, digit)?;
+        }
+        // Adjacent segments of the same type are combined.
+        let mut segments = self.format.segments().iter().copied().peekable();
+        while let Some((mut count, character_type)) = segments.next() {
+            while let Some((next, _)) = segments.next_if(|(_, next)| *next == character_type) {
+                count += next;
+            }
+            f.write_str(class(character_type))?;
+            if count != 1 {
+                write!(f, "{{{}}}", count)?;
+            }
+        }
+        f.write_str(match self.flavor {
+            RegexFlavor::Pcre => r"\z",
+            RegexFlavor::JavaScript | RegexFlavor::Posix => "$",
+        })
+    }
+}
@@ -0,0 +1,337 @@
+//! Business Identifier Codes (BIC), as described in ISO 9362, which identify
+//! the bank of an IBAN in international transfers.
+
+use crate::countries::{self, CharacterType::A};
+use crate::{generated, Country, Iban, IbanLike};
+use arrayvec::ArrayString;
+use core::error::Error;
+use core::fmt::{self, Debug, Display};
+use core::str::FromStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The length of a BIC without a branch code.
+const BIC8_LEN: usize = 8;
+
+/// The length of a BIC with a branch code.
+const BIC11_LEN: usize = 11;
+
+/// The branch code that indicates the primary office of an institution.
+const PRIMARY_OFFICE: &str = "XXX";
+
+/// Check whether the bank identifier of the IBANs of a country is the
+/// institution code of the BIC of the bank. This is the case when the BBAN
+/// starts with a bank identifier of four letters.
+fn is_institution_code(country_code: &str) -> bool {
+    generated::bank_identifier(country_code) == Some(0..4)
+        && countries::country_pattern(country_code)
+            .and_then(|pattern| pattern.first())
+            .is_some_and(|&(count, character_type)| count >= 4 && character_type == A)
+}
+
+/// A Business Identifier Code (BIC), also known as a SWIFT code. It consists
+/// of a four-character institution code, a two-letter country code, a
+/// two-character location code and optionally a three-character branch code.
+///
+/// Lowercase letters are converted to uppercase when parsing.
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let bic: Bic = "DEUTDEFF500".parse()?;
+/// assert_eq!(bic.institution_code(), "DEUT");
+/// assert_eq!(bic.country_code(), "DE");
+/// assert_eq!(bic.country(), Country::DE);
+/// assert_eq!(bic.location_code(), "FF");
+/// assert_eq!(bic.branch_code(), Some("500"));
+/// assert!(!bic.is_primary_office());
+/// assert!(!bic.is_test());
+/// # Ok::<(), ParseBicError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Bic {
+    /// The BIC, which consists of uppercase ASCII letters and digits.
+    s: ArrayString<BIC11_LEN>,
+}
+
+impl Bic {
+    /// Get the BIC as a string slice.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.s
+    }
+
+    /// Get the institution code, also known as the bank code, which
+    /// consists of four characters.
+    #[inline]
+    #[must_use]
+    pub fn institution_code(&self) -> &str {
+        &self.s[0..4]
+    }
+
+    /// Get the two-letter country code.
+    #[inline]
+    #[must_use]
+    pub fn country_code(&self) -> &str {
+        &self.s[4..6]
+    }
+
+    /// Get the country. Countries that are not in the IBAN registry are
+    /// returned as [`Country::Other`].
+    #[inline]
+    #[must_use]
+    pub fn country(&self) -> Country {
+        self.country_code().parse().expect(
+            "A BIC contained an invalid country code. Please create an issue at \
+             https://github.com/ThomasdenH/iban_validate.",
+        )
+    }
+
+    /// Get the location code, which consists of two characters.
+    #[inline]
+    #[must_use]
+    pub fn location_code(&self) -> &str {
+        &self.s[6..8]
+    }
+
+    /// Get the branch code, which consists of three characters. Returns
+    /// `None` if the BIC consists of eight characters.
+    #[inline]
+    #[must_use]
+    pub fn branch_code(&self) -> Option<&str> {
+        self.s.get(8..BIC11_LEN)
+    }
+
+    /// Check whether the BIC identifies the primary office of the institution,
+    /// meaning that there is no branch code or the branch code is `XXX`.
+    #[inline]
+    #[must_use]
+    pub fn is_primary_office(&self) -> bool {
+        self.branch_code()
+            .is_none_or(|branch| branch == PRIMARY_OFFICE)
+    }
+
+    /// Check whether the BIC is a test and training BIC, which is indicated
+    /// by a `0` as the second character of the location code. Such a BIC
+    /// cannot be used in real transfers.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// assert!("DEUTDEF0".parse::<Bic>()?.is_test());
+    /// # Ok::<(), ParseBicError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_test(&self) -> bool {
+        self.s.as_bytes()[7] == b'0'
+    }
+
+    /// Check whether the BIC is consistent with an IBAN. The country of the
+    /// BIC must be the country of the IBAN, where a dependent territory such
+    /// as Jersey may also use the country whose format it uses. In countries
+    /// where the bank identifier of the IBAN is the institution code of the
+    /// BIC, such as the United Kingdom, Ireland and the Netherlands, these
+    /// must be equal as well. These are the countries of which the BBAN
+    /// starts with a bank identifier of four letters.
+    ///
+    /// # Errors
+    /// If the BIC does not belong to the IBAN, an [`InconsistentBicError`]
+    /// indicating the difference is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let iban: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    /// assert_eq!("NWBKGB2L".parse::<Bic>()?.check_iban(&iban), Ok(()));
+    /// assert_eq!(
+    ///     "BARCGB22".parse::<Bic>()?.check_iban(&iban),
+    ///     Err(InconsistentBicError::DifferentInstitution)
+    /// );
+    /// assert_eq!(
+    ///     "ABNANL2A".parse::<Bic>()?.check_iban(&iban),
+    ///     Err(InconsistentBicError::DifferentCountry)
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn check_iban(&self, iban: &Iban) -> Result<(), InconsistentBicError> {
+        let format_country = |country: Country| country.parent().unwrap_or(country);
+        let (bic_country, iban_country) = (self.country(), iban.country());
+        if bic_country != iban_country
+            && format_country(bic_country) != format_country(iban_country)
+        {
+            return Err(InconsistentBicError::DifferentCountry);
+        }
+        let format_country = format_country(iban_country);
+        if is_institution_code(format_country.as_str())
+            && iban.bank_identifier() != Some(self.institution_code())
+        {
+            return Err(InconsistentBicError::DifferentInstitution);
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Bic {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for Bic {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl AsRef<str> for Bic {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl FromStr for Bic {
+    type Err = ParseBicError;
+    /// Parse a BIC of eight or eleven characters.
+    ///
+    /// # Errors
+    /// If the string does not follow the format of a BIC, a [`ParseBicError`]
+    /// is returned that indicates the invalid part.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != BIC8_LEN && s.len() != BIC11_LEN {
+            return Err(ParseBicError::InvalidLength);
+        }
+        let mut bic = ArrayString::<BIC11_LEN>::new();
+        for (i, c) in s.bytes().enumerate() {
+            let c = c.to_ascii_uppercase();
+            let valid = if (4..6).contains(&i) {
+                c.is_ascii_uppercase()
+            } else {
+                c.is_ascii_uppercase() || c.is_ascii_digit()
+            };
+            if !valid {
+                return Err(match i {
+                    0..4 => ParseBicError::InvalidInstitutionCode,
+                    4..6 => ParseBicError::InvalidCountryCode,
+                    6..8 => ParseBicError::InvalidLocationCode,
+                    _ => ParseBicError::InvalidBranchCode,
+                });
+            }
+            bic.push(char::from(c));
+        }
+        Ok(Bic { s: bic })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Bic {
+    type Error = ParseBicError;
+    /// Parse a BIC. See [`Bic::from_str`].
+    ///
+    /// # Errors
+    /// If the string does not follow the format of a BIC, a [`ParseBicError`]
+    /// is returned that indicates the invalid part.
+    #[inline]
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Bic {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Bic {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BicStringVisitor;
+        use serde::de;
+
+        impl<'vi> de::Visitor<'vi> for BicStringVisitor {
+            type Value = Bic;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a BIC string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Bic, E> {
+                value.parse::<Bic>().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(BicStringVisitor)
+    }
+}
+
+/// An error indicating that a string could not be parsed as a [`Bic`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParseBicError {
+    /// The string does not consist of eight or eleven characters.
+    InvalidLength,
+    /// The institution code contains a character that is not an ASCII
+    /// letter or digit.
+    InvalidInstitutionCode,
+    /// The country code contains a character that is not an ASCII letter.
+    InvalidCountryCode,
+    /// The location code contains a character that is not an ASCII letter or
+    /// digit.
+    InvalidLocationCode,
+    /// The branch code contains a character that is not an ASCII letter or
+    /// digit.
+    InvalidBranchCode,
+}
+
+impl Display for ParseBicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ParseBicError::InvalidLength => "a BIC must consist of 8 or 11 characters",
+                ParseBicError::InvalidInstitutionCode => "the institution code is invalid",
+                ParseBicError::InvalidCountryCode => "the country code is invalid",
+                ParseBicError::InvalidLocationCode => "the location code is invalid",
+                ParseBicError::InvalidBranchCode => "the branch code is invalid",
+            }
+        )
+    }
+}
+
+impl Error for ParseBicError {}
+
+/// An error indicating that a [`Bic`] does not belong to an [`Iban`]. It is
+/// obtained via [`Bic::check_iban`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InconsistentBicError {
+    /// The BIC and the IBAN belong to different countries.
+    DifferentCountry,
+    /// The institution code of the BIC differs from the bank identifier of
+    /// the IBAN.
+    DifferentInstitution,
+}
+
+impl Display for InconsistentBicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                InconsistentBicError::DifferentCountry =>
+                    "the BIC and the IBAN belong to different countries",
+                InconsistentBicError::DifferentInstitution =>
+                    "the institution code of the BIC differs from the bank of the IBAN",
+            }
+        )
+    }
+}
+
+impl Error for InconsistentBicError {}
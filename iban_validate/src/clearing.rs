@@ -0,0 +1,402 @@
+//! Clearing system member identification, as used in ISO 20022 payment
+//! messages. For several countries, the identification of a bank in the
+//! domestic clearing system is part of the BBAN.
+
+use crate::base_iban::MAX_IBAN_LEN;
+use crate::components::country_components;
+use crate::national::mod97;
+use crate::{countries, ComponentKind, Country, Iban, IbanLike};
+use arrayvec::ArrayString;
+use core::error::Error;
+use core::fmt::{self, Display, Write};
+use core::str::FromStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The maximum length of a member identification that is part of a BBAN.
+const MAX_MEMBER_ID_LEN: usize = 10;
+
+/// A clearing system, identified by its code from the ISO 20022 external
+/// code set `ExternalClearingSystemIdentification1Code`. Only the clearing
+/// systems of which the member identification is part of the BBAN are
+/// included.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ClearingSystem {
+    /// Austrian Bankleitzahl, `ATBLZ`.
+    #[cfg_attr(feature = "serde", serde(rename = "ATBLZ"))]
+    AtBlz,
+    /// Swiss bank clearing number, `CHBCC`, which is also used in
+    /// Liechtenstein.
+    #[cfg_attr(feature = "serde", serde(rename = "CHBCC"))]
+    ChBcc,
+    /// German Bankleitzahl, `DEBLZ`.
+    #[cfg_attr(feature = "serde", serde(rename = "DEBLZ"))]
+    DeBlz,
+    /// Spanish domestic interbanking code, `ESNCC`.
+    #[cfg_attr(feature = "serde", serde(rename = "ESNCC"))]
+    EsNcc,
+    /// UK domestic sort code, `GBDSC`, which is also used in Guernsey, the
+    /// Isle of Man and Jersey.
+    #[cfg_attr(feature = "serde", serde(rename = "GBDSC"))]
+    GbDsc,
+    /// Hellenic Bank Identification Code, `GRBIC`.
+    #[cfg_attr(feature = "serde", serde(rename = "GRBIC"))]
+    GrBic,
+    /// Irish national clearing code, `IENCC`.
+    #[cfg_attr(feature = "serde", serde(rename = "IENCC"))]
+    IeNcc,
+    /// Italian domestic identification code, `ITNCC`.
+    #[cfg_attr(feature = "serde", serde(rename = "ITNCC"))]
+    ItNcc,
+    /// Polish national clearing code, `PLKNR`.
+    #[cfg_attr(feature = "serde", serde(rename = "PLKNR"))]
+    PlKnr,
+    /// Portuguese national clearing code, `PTNCC`.
+    #[cfg_attr(feature = "serde", serde(rename = "PTNCC"))]
+    PtNcc,
+}
+
+impl ClearingSystem {
+    /// All clearing systems.
+    pub const ALL: &'static [ClearingSystem] = &[
+        ClearingSystem::AtBlz,
+        ClearingSystem::ChBcc,
+        ClearingSystem::DeBlz,
+        ClearingSystem::EsNcc,
+        ClearingSystem::GbDsc,
+        ClearingSystem::GrBic,
+        ClearingSystem::IeNcc,
+        ClearingSystem::ItNcc,
+        ClearingSystem::PlKnr,
+        ClearingSystem::PtNcc,
+    ];
+
+    /// Get the ISO 20022 code of the clearing system.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::ClearingSystem;
+    /// assert_eq!(ClearingSystem::GbDsc.code(), "GBDSC");
+    /// assert_eq!("GBDSC".parse(), Ok(ClearingSystem::GbDsc));
+    /// ```
+    #[must_use]
+    pub fn code(self) -> &'static str {
+        match self {
+            ClearingSystem::AtBlz => "ATBLZ",
+            ClearingSystem::ChBcc => "CHBCC",
+            ClearingSystem::DeBlz => "DEBLZ",
+            ClearingSystem::EsNcc => "ESNCC",
+            ClearingSystem::GbDsc => "GBDSC",
+            ClearingSystem::GrBic => "GRBIC",
+            ClearingSystem::IeNcc => "IENCC",
+            ClearingSystem::ItNcc => "ITNCC",
+            ClearingSystem::PlKnr => "PLKNR",
+            ClearingSystem::PtNcc => "PTNCC",
+        }
+    }
+
+    /// Get the country of the clearing system.
+    #[must_use]
+    pub fn country(self) -> Country {
+        match self {
+            ClearingSystem::AtBlz => Country::AT,
+            ClearingSystem::ChBcc => Country::CH,
+            ClearingSystem::DeBlz => Country::DE,
+            ClearingSystem::EsNcc => Country::ES,
+            ClearingSystem::GbDsc => Country::GB,
+            ClearingSystem::GrBic => Country::GR,
+            ClearingSystem::IeNcc => Country::IE,
+            ClearingSystem::ItNcc => Country::IT,
+            ClearingSystem::PlKnr => Country::PL,
+            ClearingSystem::PtNcc => Country::PT,
+        }
+    }
+
+    /// Get the clearing system that is used for IBANs of a country.
+    fn of_country(country_code: &str) -> Option<ClearingSystem> {
+        Some(match country_code {
+            "AT" => ClearingSystem::AtBlz,
+            "CH" | "LI" => ClearingSystem::ChBcc,
+            "DE" => ClearingSystem::DeBlz,
+            "ES" => ClearingSystem::EsNcc,
+            "GB" | "GG" | "IM" | "JE" => ClearingSystem::GbDsc,
+            "GR" => ClearingSystem::GrBic,
+            "IE" => ClearingSystem::IeNcc,
+            "IT" => ClearingSystem::ItNcc,
+            "PL" => ClearingSystem::PlKnr,
+            "PT" => ClearingSystem::PtNcc,
+            _ => return None,
+        })
+    }
+
+    /// Get the components of the BBAN that together form the member
+    /// identification.
+    fn components(self) -> &'static [ComponentKind] {
+        match self {
+            ClearingSystem::AtBlz | ClearingSystem::ChBcc | ClearingSystem::DeBlz => {
+                &[ComponentKind::BankIdentifier]
+            }
+            ClearingSystem::GbDsc | ClearingSystem::IeNcc | ClearingSystem::PlKnr => {
+                &[ComponentKind::BranchIdentifier]
+            }
+            ClearingSystem::EsNcc
+            | ClearingSystem::GrBic
+            | ClearingSystem::ItNcc
+            | ClearingSystem::PtNcc => &[
+                ComponentKind::BankIdentifier,
+                ComponentKind::BranchIdentifier,
+            ],
+        }
+    }
+
+    /// Get the positions in the BBAN of a country of the member
+    /// identification, ordered by their position.
+    fn ranges(self, country_code: &str) -> impl Iterator<Item = core::ops::Range<usize>> {
+        country_components(country_code)
+            .iter()
+            .filter(move |(kind, _)| self.components().contains(kind))
+            .map(|(_, range)| range.clone())
+    }
+
+    /// Get the length of a member identification.
+    fn member_id_len(self) -> usize {
+        self.ranges(self.country().as_str())
+            .map(|range| range.len())
+            .sum()
+    }
+}
+
+impl Display for ClearingSystem {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for ClearingSystem {
+    type Err = ParseClearingSystemError;
+    /// Parse the ISO 20022 code of a clearing system.
+    ///
+    /// # Errors
+    /// If the code is not that of a clearing system of [`ClearingSystem::ALL`],
+    /// a [`ParseClearingSystemError`] is returned.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ClearingSystem::ALL
+            .iter()
+            .copied()
+            .find(|system| system.code() == s)
+            .ok_or(ParseClearingSystemError)
+    }
+}
+
+/// An error indicating that a string is not the code of a [`ClearingSystem`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParseClearingSystemError;
+
+impl Display for ParseClearingSystemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the string is not the code of a supported clearing system"
+        )
+    }
+}
+
+impl Error for ParseClearingSystemError {}
+
+/// The identification of a bank or branch in a clearing system, as in the
+/// ISO 20022 element `ClearingSystemMemberIdentification`. It is obtained
+/// from an IBAN via [`Iban::clearing_member_id`], or created from domestic
+/// clearing data via [`ClearingMemberId::new`].
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let iban: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+/// let member = iban.clearing_member_id().expect("the UK has sort codes");
+/// assert_eq!(member.system(), ClearingSystem::GbDsc);
+/// assert_eq!(member.member_id(), "601613");
+/// # Ok::<(), ParseIbanError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ClearingMemberId {
+    system: ClearingSystem,
+    member_id: ArrayString<MAX_MEMBER_ID_LEN>,
+}
+
+impl ClearingMemberId {
+    /// Create a member identification of a clearing system, such as a sort
+    /// code of the UK or a Bankleitzahl of Germany.
+    ///
+    /// # Errors
+    /// If the member identification does not consist of the number of digits
+    /// that the clearing system uses in IBANs, an [`InvalidMemberIdError`] is
+    /// returned.
+    pub fn new(system: ClearingSystem, member_id: &str) -> Result<Self, InvalidMemberIdError> {
+        if member_id.len() != system.member_id_len()
+            || !member_id.bytes().all(|c| c.is_ascii_digit())
+        {
+            return Err(InvalidMemberIdError);
+        }
+        Ok(ClearingMemberId {
+            system,
+            member_id: ArrayString::from(member_id).map_err(|_| InvalidMemberIdError)?,
+        })
+    }
+
+    /// Get the clearing system.
+    #[inline]
+    #[must_use]
+    pub fn system(&self) -> ClearingSystem {
+        self.system
+    }
+
+    /// Get the identification of the member in the clearing system.
+    #[inline]
+    #[must_use]
+    pub fn member_id(&self) -> &str {
+        &self.member_id
+    }
+
+    /// Build an IBAN from the member identification and the other characters
+    /// of the BBAN, in order. The check digits of the IBAN are computed. For
+    /// most clearing systems, the other characters are the account number,
+    /// but they may also contain national check digits, or in the UK and
+    /// Ireland the bank identifier that precedes the sort code.
+    ///
+    /// # Errors
+    /// If the clearing system is not used in the country, or the resulting
+    /// BBAN does not follow the format of the country, a
+    /// [`FromClearingError`] is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let member = ClearingMemberId::new(ClearingSystem::DeBlz, "50010517")?;
+    /// let iban = member.to_iban(Country::DE, "5407324931")?;
+    /// assert_eq!(iban.to_string(), "DE44 5001 0517 5407 3249 31");
+    ///
+    /// // The bank identifier precedes the sort code.
+    /// let member = ClearingMemberId::new(ClearingSystem::GbDsc, "601613")?;
+    /// let iban = member.to_iban(Country::GB, "NWBK31926819")?;
+    /// assert_eq!(iban.to_string(), "GB29 NWBK 6016 1331 9268 19");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_iban(&self, country: Country, rest: &str) -> Result<Iban, FromClearingError> {
+        let country_code = country.as_str();
+        if ClearingSystem::of_country(country_code) != Some(self.system) {
+            return Err(FromClearingError::UnsupportedCountry);
+        }
+        let bban_len = countries::country_pattern(country_code)
+            .map(countries::len)
+            .ok_or(FromClearingError::UnsupportedCountry)?;
+        if self.member_id.len() + rest.len() != bban_len {
+            return Err(FromClearingError::InvalidBban);
+        }
+
+        let mut member_id = self.member_id.chars();
+        let mut rest = rest.chars();
+        let mut bban = ArrayString::<MAX_IBAN_LEN>::new();
+        for i in 0..bban_len {
+            let c = if self
+                .system
+                .ranges(country_code)
+                .any(|range| range.contains(&i))
+            {
+                member_id.next()
+            } else {
+                rest.next()
+            }
+            .filter(char::is_ascii_alphanumeric)
+            .ok_or(FromClearingError::InvalidBban)?;
+            bban.push(c.to_ascii_uppercase());
+        }
+
+        // The check digits make the remainder of the rearranged IBAN modulo 97
+        // equal to one.
+        let mut rearranged = bban;
+        rearranged.push_str(country_code);
+        rearranged.push_str("00");
+        let check_digits = 98 - mod97(rearranged.as_bytes());
+        let mut electronic = ArrayString::<MAX_IBAN_LEN>::new();
+        write!(electronic, "{country_code}{check_digits:02}{bban}")
+            .map_err(|_| FromClearingError::InvalidBban)?;
+        Iban::try_from(electronic.as_str()).map_err(|_| FromClearingError::InvalidBban)
+    }
+}
+
+/// An error indicating that a member identification does not match the
+/// format of its [`ClearingSystem`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InvalidMemberIdError;
+
+impl Display for InvalidMemberIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the member identification doesn't match the format of the clearing system"
+        )
+    }
+}
+
+impl Error for InvalidMemberIdError {}
+
+/// An error indicating that an IBAN could not be built from a
+/// [`ClearingMemberId`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FromClearingError {
+    /// The clearing system is not used for IBANs of the country.
+    UnsupportedCountry,
+    /// The BBAN does not have the correct length or does not follow the
+    /// format of the country.
+    InvalidBban,
+}
+
+impl Display for FromClearingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                FromClearingError::UnsupportedCountry =>
+                    "the clearing system is not used in the country",
+                FromClearingError::InvalidBban => "the BBAN doesn't match the country format",
+            }
+        )
+    }
+}
+
+impl Error for FromClearingError {}
+
+impl Iban {
+    /// Get the identification of the bank or branch in the domestic clearing
+    /// system, for use in ISO 20022 messages. It is derived from the bank and
+    /// branch identifiers. Returns `None` if the country has no supported
+    /// clearing system; see [`ClearingSystem`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let iban: Iban = "IT60 X054 2811 1010 0000 0123 456".parse()?;
+    /// let member = iban.clearing_member_id().expect("Italy has a clearing system");
+    /// assert_eq!(member.system().code(), "ITNCC");
+    /// assert_eq!(member.member_id(), "0542811101");
+    ///
+    /// let iban: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+    /// assert_eq!(iban.clearing_member_id(), None);
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[must_use]
+    pub fn clearing_member_id(&self) -> Option<ClearingMemberId> {
+        let system = ClearingSystem::of_country(self.country_code())?;
+        let mut member_id = ArrayString::new();
+        for range in system.ranges(self.country_code()) {
+            member_id.try_push_str(self.bban().get(range)?).ok()?;
+        }
+        Some(ClearingMemberId { system, member_id })
+    }
+}
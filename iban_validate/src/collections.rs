@@ -0,0 +1,622 @@
+//! Collections of IBANs that support queries by bank and branch, for example
+//! to screen payments against a blocklist.
+
+use crate::{ordering, Iban, IbanLike};
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FromIterator;
+use core::ops::Range;
+use core::slice;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::vec::{self, Vec};
+
+/// A map from IBANs to values. The entries are stored contiguously, ordered
+/// as described by the [`Ord`] implementation of [`Iban`]. Since this groups
+/// the IBANs of a bank and branch together, lookups of single IBANs as well as
+/// of all IBANs of a bank or branch are binary searches.
+///
+/// The map is intended to be built at once, for example using
+/// [`FromIterator`], and queried many times. Inserting or removing single
+/// entries takes time linear in the size of the map. Every entry stores the
+/// IBAN together with the positions of its bank and branch identifiers, so
+/// that lookups don't need to determine them again for every comparison.
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let map: IbanMap<&str> = [
+///     ("GB29 NWBK 6016 1331 9268 19".parse::<Iban>()?, "Alice"),
+///     ("GB07 NWBK 6016 1331 9268 27".parse()?, "Bob"),
+///     ("NL91 ABNA 0417 1643 00".parse()?, "Carol"),
+/// ]
+/// .into_iter()
+/// .collect();
+/// let iban: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+/// assert_eq!(map.get(&iban), Some(&"Alice"));
+/// assert_eq!(
+///     map.bank("GB", "NWBK").map(|(_, name)| *name).collect::<Vec<_>>(),
+///     ["Alice", "Bob"]
+/// );
+/// assert!(!map.contains_branch("GB", "NWBK", "123456"));
+/// # Ok::<(), ParseIbanError>(())
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct IbanMap<V> {
+    /// The entries, sorted by IBAN and without duplicate IBANs.
+    entries: Vec<Entry<V>>,
+}
+
+/// An entry of an [`IbanMap`]. The positions of the bank and branch
+/// identifiers are determined once, when the entry is created.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+struct Entry<V> {
+    iban: Iban,
+    bank_identifier: Option<Range<u8>>,
+    branch_identifier: Option<Range<u8>>,
+    value: V,
+}
+
+impl<V> Entry<V> {
+    fn new(iban: Iban, value: V) -> Self {
+        let (bank_identifier, branch_identifier) =
+            ordering::identifier_ranges(iban.electronic_str());
+        // The positions are within an IBAN, so they fit in a `u8`.
+        let compact = |range: Range<usize>| range.start as u8..range.end as u8;
+        Entry {
+            iban,
+            bank_identifier: bank_identifier.map(compact),
+            branch_identifier: branch_identifier.map(compact),
+            value,
+        }
+    }
+
+    /// Get the bank and branch identifiers of the IBAN.
+    fn identifiers(&self) -> (Option<&str>, Option<&str>) {
+        let identifier = |range: &Option<Range<u8>>| {
+            range.as_ref().map(|range| {
+                &self.iban.electronic_str()[usize::from(range.start)..usize::from(range.end)]
+            })
+        };
+        (
+            identifier(&self.bank_identifier),
+            identifier(&self.branch_identifier),
+        )
+    }
+
+    /// Compare the IBAN of this entry with another IBAN, consistently with
+    /// the ordering of IBANs.
+    fn compare(&self, electronic: &str, identifiers: (Option<&str>, Option<&str>)) -> Ordering {
+        ordering::compare_with_identifiers(
+            self.iban.electronic_str(),
+            self.identifiers(),
+            electronic,
+            identifiers,
+        )
+    }
+}
+
+/// A set of IBANs. It is an [`IbanMap`] without values, and shares its
+/// characteristics.
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let blocklist: IbanSet = ["GB29 NWBK 6016 1331 9268 19", "NL91 ABNA 0417 1643 00"]
+///     .iter()
+///     .map(|s| s.parse::<Iban>())
+///     .collect::<Result<_, _>>()?;
+///
+/// let iban: Iban = "GB07 NWBK 6016 1331 9268 27".parse()?;
+/// assert!(!blocklist.contains(&iban));
+/// assert!(blocklist.contains_bank_of(&iban));
+/// assert!(blocklist.contains_branch_of(&iban));
+/// # Ok::<(), ParseIbanError>(())
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Default)]
+pub struct IbanSet {
+    map: IbanMap<()>,
+}
+
+/// The identifiers of the entries that a query matches. Entries without a
+/// bank identifier never match.
+struct Query<'q> {
+    country_code: &'q str,
+    bank_identifier: &'q str,
+    branch_identifier: Option<&'q str>,
+}
+
+impl Query<'_> {
+    /// Compare the identifiers of an entry with the query, consistently with
+    /// the ordering of IBANs.
+    fn compare<V>(&self, entry: &Entry<V>) -> Ordering {
+        let (bank_identifier, branch_identifier) = entry.identifiers();
+        let ordering = entry
+            .iban
+            .country_code()
+            .cmp(self.country_code)
+            .then_with(|| bank_identifier.cmp(&Some(self.bank_identifier)));
+        match self.branch_identifier {
+            Some(branch) => ordering.then_with(|| branch_identifier.cmp(&Some(branch))),
+            None => ordering,
+        }
+    }
+}
+
+impl<V> Default for IbanMap<V> {
+    fn default() -> Self {
+        IbanMap {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for IbanMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl fmt::Debug for IbanSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<V> IbanMap<V> {
+    /// Create an empty map.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        IbanMap::default()
+    }
+
+    /// Get the number of entries in the map.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether the map has no entries.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Find the position of an IBAN in the entries.
+    fn search(&self, iban: &Iban) -> Result<usize, usize> {
+        let electronic = iban.electronic_str();
+        let identifiers = ordering::identifiers(electronic);
+        self.entries
+            .binary_search_by(|entry| entry.compare(electronic, identifiers))
+    }
+
+    /// Get the value of an IBAN, if it is in the map.
+    #[must_use]
+    pub fn get(&self, iban: &Iban) -> Option<&V> {
+        self.search(iban).ok().map(|i| &self.entries[i].value)
+    }
+
+    /// Get a mutable reference to the value of an IBAN, if it is in the map.
+    #[must_use]
+    pub fn get_mut(&mut self, iban: &Iban) -> Option<&mut V> {
+        self.search(iban).ok().map(|i| &mut self.entries[i].value)
+    }
+
+    /// Check whether an IBAN is in the map.
+    #[inline]
+    #[must_use]
+    pub fn contains_key(&self, iban: &Iban) -> bool {
+        self.search(iban).is_ok()
+    }
+
+    /// Insert an entry, returning the previous value of the IBAN if it was
+    /// already in the map. This takes time linear in the size of the map.
+    pub fn insert(&mut self, iban: Iban, value: V) -> Option<V> {
+        match self.search(&iban) {
+            Ok(i) => Some(core::mem::replace(&mut self.entries[i].value, value)),
+            Err(i) => {
+                self.entries.insert(i, Entry::new(iban, value));
+                None
+            }
+        }
+    }
+
+    /// Remove an IBAN, returning its value if it was in the map. This takes
+    /// time linear in the size of the map.
+    pub fn remove(&mut self, iban: &Iban) -> Option<V> {
+        self.search(iban).ok().map(|i| self.entries.remove(i).value)
+    }
+
+    /// Iterate over the entries, ordered by IBAN.
+    #[inline]
+    pub fn iter(&self) -> IbanMapIter<'_, V> {
+        IbanMapIter {
+            inner: self.entries.iter(),
+        }
+    }
+
+    /// Get the entries that match a query.
+    fn query(&self, query: &Query<'_>) -> IbanMapIter<'_, V> {
+        let start = self
+            .entries
+            .partition_point(|entry| query.compare(entry).is_lt());
+        let end = self
+            .entries
+            .partition_point(|entry| query.compare(entry).is_le());
+        IbanMapIter {
+            inner: self.entries[start..end].iter(),
+        }
+    }
+
+    /// Iterate over the entries of a bank, given the country code and the
+    /// bank identifier as returned by [`Iban::bank_identifier`].
+    #[must_use]
+    pub fn bank(&self, country_code: &str, bank_identifier: &str) -> IbanMapIter<'_, V> {
+        self.query(&Query {
+            country_code,
+            bank_identifier,
+            branch_identifier: None,
+        })
+    }
+
+    /// Iterate over the entries of a branch, given the country code, the bank
+    /// identifier and the branch identifier as returned by
+    /// [`Iban::bank_identifier`] and [`Iban::branch_identifier`].
+    #[must_use]
+    pub fn branch(
+        &self,
+        country_code: &str,
+        bank_identifier: &str,
+        branch_identifier: &str,
+    ) -> IbanMapIter<'_, V> {
+        self.query(&Query {
+            country_code,
+            bank_identifier,
+            branch_identifier: Some(branch_identifier),
+        })
+    }
+
+    /// Check whether the map contains an IBAN of a bank. See
+    /// [`bank`](IbanMap::bank).
+    #[inline]
+    #[must_use]
+    pub fn contains_bank(&self, country_code: &str, bank_identifier: &str) -> bool {
+        self.bank(country_code, bank_identifier).next().is_some()
+    }
+
+    /// Check whether the map contains an IBAN of a branch. See
+    /// [`branch`](IbanMap::branch).
+    #[inline]
+    #[must_use]
+    pub fn contains_branch(
+        &self,
+        country_code: &str,
+        bank_identifier: &str,
+        branch_identifier: &str,
+    ) -> bool {
+        self.branch(country_code, bank_identifier, branch_identifier)
+            .next()
+            .is_some()
+    }
+
+    /// Check whether the map contains an IBAN of the [same
+    /// bank](Iban::same_bank) as the given IBAN.
+    #[must_use]
+    pub fn contains_bank_of(&self, iban: &Iban) -> bool {
+        iban.bank_identifier()
+            .is_some_and(|bank| self.contains_bank(iban.country_code(), bank))
+    }
+
+    /// Check whether the map contains an IBAN of the [same
+    /// branch](Iban::same_branch) as the given IBAN.
+    #[must_use]
+    pub fn contains_branch_of(&self, iban: &Iban) -> bool {
+        match (iban.bank_identifier(), iban.branch_identifier()) {
+            (Some(bank), Some(branch)) => self.contains_branch(iban.country_code(), bank, branch),
+            _ => false,
+        }
+    }
+}
+
+impl<V> FromIterator<(Iban, V)> for IbanMap<V> {
+    /// Build a map from its entries. If an IBAN occurs more than once, the
+    /// last value is kept.
+    fn from_iter<I: IntoIterator<Item = (Iban, V)>>(iter: I) -> Self {
+        let sorted: BTreeMap<Iban, V> = iter.into_iter().collect();
+        IbanMap {
+            entries: sorted
+                .into_iter()
+                .map(|(iban, value)| Entry::new(iban, value))
+                .collect(),
+        }
+    }
+}
+
+impl<V> Extend<(Iban, V)> for IbanMap<V> {
+    fn extend<I: IntoIterator<Item = (Iban, V)>>(&mut self, iter: I) {
+        let entries = core::mem::take(&mut self.entries);
+        *self = entries
+            .into_iter()
+            .map(|entry| (entry.iban, entry.value))
+            .chain(iter)
+            .collect();
+    }
+}
+
+impl<V> IntoIterator for IbanMap<V> {
+    type Item = (Iban, V);
+    type IntoIter = IbanMapIntoIter<V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IbanMapIntoIter {
+            inner: self.entries.into_iter(),
+        }
+    }
+}
+
+impl<'a, V> IntoIterator for &'a IbanMap<V> {
+    type Item = (&'a Iban, &'a V);
+    type IntoIter = IbanMapIter<'a, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over entries of an [`IbanMap`], ordered by IBAN.
+#[derive(Clone, Debug)]
+pub struct IbanMapIter<'a, V> {
+    inner: slice::Iter<'a, Entry<V>>,
+}
+
+impl<'a, V> Iterator for IbanMapIter<'a, V> {
+    type Item = (&'a Iban, &'a V);
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|entry| (&entry.iban, &entry.value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<V> DoubleEndedIterator for IbanMapIter<'_, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next_back()
+            .map(|entry| (&entry.iban, &entry.value))
+    }
+}
+
+impl<V> ExactSizeIterator for IbanMapIter<'_, V> {}
+
+/// An owning iterator over the entries of an [`IbanMap`], ordered by IBAN.
+#[derive(Clone, Debug)]
+pub struct IbanMapIntoIter<V> {
+    inner: vec::IntoIter<Entry<V>>,
+}
+
+impl<V> Iterator for IbanMapIntoIter<V> {
+    type Item = (Iban, V);
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|entry| (entry.iban, entry.value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<V> DoubleEndedIterator for IbanMapIntoIter<V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next_back()
+            .map(|entry| (entry.iban, entry.value))
+    }
+}
+
+impl<V> ExactSizeIterator for IbanMapIntoIter<V> {}
+
+impl IbanSet {
+    /// Create an empty set.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        IbanSet::default()
+    }
+
+    /// Get the number of IBANs in the set.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Check whether the set is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Check whether an IBAN is in the set.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, iban: &Iban) -> bool {
+        self.map.contains_key(iban)
+    }
+
+    /// Insert an IBAN, returning whether it was not yet in the set. This
+    /// takes time linear in the size of the set.
+    #[inline]
+    pub fn insert(&mut self, iban: Iban) -> bool {
+        self.map.insert(iban, ()).is_none()
+    }
+
+    /// Remove an IBAN, returning whether it was in the set. This takes time
+    /// linear in the size of the set.
+    #[inline]
+    pub fn remove(&mut self, iban: &Iban) -> bool {
+        self.map.remove(iban).is_some()
+    }
+
+    /// Iterate over the IBANs in order.
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Iban> + ExactSizeIterator + '_ {
+        self.map.iter().map(|(iban, ())| iban)
+    }
+
+    /// Iterate over the IBANs of a bank. See [`IbanMap::bank`].
+    #[inline]
+    pub fn bank<'a>(
+        &'a self,
+        country_code: &str,
+        bank_identifier: &str,
+    ) -> impl DoubleEndedIterator<Item = &'a Iban> + ExactSizeIterator + 'a {
+        self.map
+            .bank(country_code, bank_identifier)
+            .map(|(iban, ())| iban)
+    }
+
+    /// Iterate over the IBANs of a branch. See [`IbanMap::branch`].
+    #[inline]
+    pub fn branch<'a>(
+        &'a self,
+        country_code: &str,
+        bank_identifier: &str,
+        branch_identifier: &str,
+    ) -> impl DoubleEndedIterator<Item = &'a Iban> + ExactSizeIterator + 'a {
+        self.map
+            .branch(country_code, bank_identifier, branch_identifier)
+            .map(|(iban, ())| iban)
+    }
+
+    /// Check whether the set contains an IBAN of a bank.
+    #[inline]
+    #[must_use]
+    pub fn contains_bank(&self, country_code: &str, bank_identifier: &str) -> bool {
+        self.map.contains_bank(country_code, bank_identifier)
+    }
+
+    /// Check whether the set contains an IBAN of a branch.
+    #[inline]
+    #[must_use]
+    pub fn contains_branch(
+        &self,
+        country_code: &str,
+        bank_identifier: &str,
+        branch_identifier: &str,
+    ) -> bool {
+        self.map
+            .contains_branch(country_code, bank_identifier, branch_identifier)
+    }
+
+    /// Check whether the set contains an IBAN of the [same
+    /// bank](Iban::same_bank) as the given IBAN.
+    #[inline]
+    #[must_use]
+    pub fn contains_bank_of(&self, iban: &Iban) -> bool {
+        self.map.contains_bank_of(iban)
+    }
+
+    /// Check whether the set contains an IBAN of the [same
+    /// branch](Iban::same_branch) as the given IBAN.
+    #[inline]
+    #[must_use]
+    pub fn contains_branch_of(&self, iban: &Iban) -> bool {
+        self.map.contains_branch_of(iban)
+    }
+}
+
+impl FromIterator<Iban> for IbanSet {
+    fn from_iter<I: IntoIterator<Item = Iban>>(iter: I) -> Self {
+        IbanSet {
+            map: iter.into_iter().map(|iban| (iban, ())).collect(),
+        }
+    }
+}
+
+impl Extend<Iban> for IbanSet {
+    fn extend<I: IntoIterator<Item = Iban>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|iban| (iban, ())));
+    }
+}
+
+impl IntoIterator for IbanSet {
+    type Item = Iban;
+    type IntoIter = IbanSetIntoIter;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IbanSetIntoIter {
+            inner: self.map.into_iter(),
+        }
+    }
+}
+
+/// An owning iterator over the IBANs of an [`IbanSet`], ordered by IBAN.
+#[derive(Clone, Debug)]
+pub struct IbanSetIntoIter {
+    inner: IbanMapIntoIter<()>,
+}
+
+impl Iterator for IbanSetIntoIter {
+    type Item = Iban;
+    #[inline]
+    fn next(&mut self) -> Option<Iban> {
+        self.inner.next().map(|(iban, ())| iban)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for IbanSetIntoIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Iban> {
+        self.inner.next_back().map(|(iban, ())| iban)
+    }
+}
+
+impl ExactSizeIterator for IbanSetIntoIter {}
+
+#[cfg(feature = "serde")]
+impl<V: Serialize> Serialize for IbanMap<V> {
+    /// A map is serialized as a map from IBANs to values.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, V: Deserialize<'de>> Deserialize<'de> for IbanMap<V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        BTreeMap::<Iban, V>::deserialize(deserializer).map(|map| map.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for IbanSet {
+    /// A set is serialized as a sequence of IBANs.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for IbanSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<Iban>::deserialize(deserializer).map(|ibans| ibans.into_iter().collect())
+    }
+}
@@ -0,0 +1,232 @@
+//! The labelled components of a BBAN, such as the bank identifier and the
+//! account number. The positions of the bank and branch identifiers come from
+//! the IBAN registry, the other components are curated from national
+//! documentation.
+
+use crate::masked::{Masked, Redacted};
+use crate::{generated, Country, Iban, IbanLike};
+use core::fmt::{self, Debug, Display};
+use core::ops::Range;
+use core::slice;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The kind of a [`Component`] of a BBAN.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ComponentKind {
+    /// The identifier of the bank.
+    BankIdentifier,
+    /// The identifier of the branch of the bank.
+    BranchIdentifier,
+    /// The account number. Some countries use several segments for this, for
+    /// example when a reserved field is placed in between.
+    AccountNumber,
+    /// Check digits that are computed using a national algorithm.
+    NationalCheckDigits,
+    /// A code indicating the type of the account.
+    AccountType,
+    /// A code indicating the currency of the account.
+    Currency,
+}
+
+impl Display for ComponentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ComponentKind::BankIdentifier => "bank identifier",
+            ComponentKind::BranchIdentifier => "branch identifier",
+            ComponentKind::AccountNumber => "account number",
+            ComponentKind::NationalCheckDigits => "national check digits",
+            ComponentKind::AccountType => "account type",
+            ComponentKind::Currency => "currency",
+        })
+    }
+}
+
+/// A labelled segment of a BBAN. It can be obtained via [`Iban::components`].
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct Component<'a> {
+    kind: ComponentKind,
+    range: Range<usize>,
+    value: &'a str,
+}
+
+impl<'a> Component<'a> {
+    /// Get the kind of the component.
+    #[inline]
+    #[must_use]
+    pub fn kind(&self) -> ComponentKind {
+        self.kind
+    }
+
+    /// Get the position of the component in the BBAN, as returned by
+    /// [`Iban::bban`].
+    #[inline]
+    #[must_use]
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Get the contents of the component.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &'a str {
+        self.value
+    }
+}
+
+impl Debug for Component<'_> {
+    /// With the `redact_debug` feature, the contents are masked.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Component")
+            .field("kind", &self.kind)
+            .field("range", &self.range)
+            .field("value", &Redacted(masked_entirely(self.value)))
+            .finish()
+    }
+}
+
+impl Display for Component<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.value, f)
+    }
+}
+
+/// An iterator over the [`Component`]s of a BBAN, ordered by their position.
+/// It is returned by [`Iban::components`].
+#[derive(Clone)]
+pub struct Components<'a> {
+    bban: &'a str,
+    components: slice::Iter<'static, (ComponentKind, Range<usize>)>,
+}
+
+impl<'a> Components<'a> {
+    pub(crate) fn new(iban: &'a Iban) -> Self {
+        Components {
+            bban: iban.bban(),
+            components: bban_components(iban.country_code(), iban.bban()).iter(),
+        }
+    }
+}
+
+impl Debug for Components<'_> {
+    /// With the `redact_debug` feature, the BBAN is masked.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Components")
+            .field("bban", &Redacted(masked_entirely(self.bban)))
+            .field("components", &self.components.as_slice())
+            .finish()
+    }
+}
+
+/// Mask every character of a part of an IBAN.
+fn masked_entirely(s: &str) -> Masked<'_> {
+    Masked::new(s, None)
+        .keep_prefix(0)
+        .keep_suffix(0)
+        .electronic()
+}
+
+/// Get the components of the BBAN of a country. Dependent territories use
+/// the components of their parent country.
+pub(crate) fn country_components(country_code: &str) -> &'static [(ComponentKind, Range<usize>)] {
+    let parent = generated::parent_country(country_code);
+    let country_code = parent.as_ref().map_or(country_code, Country::as_str);
+    generated::components(country_code)
+}
+
+/// Get the components of a BBAN. A BBAN that was accepted using a historical
+/// format is recognized by its length, since every format of a country has a
+/// different length.
+pub(crate) fn bban_components(
+    country_code: &str,
+    bban: &str,
+) -> &'static [(ComponentKind, Range<usize>)] {
+    historical_components(country_code, bban).unwrap_or_else(|| country_components(country_code))
+}
+
+/// Get the components of a BBAN that follows a historical format of its
+/// country, or `None` if it follows the current format.
+pub(crate) fn historical_components(
+    country_code: &str,
+    bban: &str,
+) -> Option<&'static [(ComponentKind, Range<usize>)]> {
+    let parent = generated::parent_country(country_code);
+    let country_code = parent.as_ref().map_or(country_code, Country::as_str);
+    generated::historical_country_patterns(country_code)
+        .iter()
+        .find(|(_, pattern, _)| pattern.iter().map(|(count, _)| count).sum::<usize>() == bban.len())
+        .map(|(_, _, components)| *components)
+}
+
+/// Get the position of a component in a BBAN, taking into account the format
+/// the BBAN follows.
+pub(crate) fn component_range(
+    country_code: &str,
+    bban: &str,
+    kind: ComponentKind,
+) -> Option<Range<usize>> {
+    match historical_components(country_code, bban) {
+        Some(components) => components
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, range)| range.clone()),
+        None => {
+            let parent = generated::parent_country(country_code);
+            let country_code = parent.as_ref().map_or(country_code, Country::as_str);
+            match kind {
+                ComponentKind::BankIdentifier => generated::bank_identifier(country_code),
+                ComponentKind::BranchIdentifier => generated::branch_identifier(country_code),
+                _ => country_components(country_code)
+                    .iter()
+                    .find(|(k, _)| *k == kind)
+                    .map(|(_, range)| range.clone()),
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for Components<'a> {
+    type Item = Component<'a>;
+
+    fn next(&mut self) -> Option<Component<'a>> {
+        let bban = self.bban;
+        self.components.find_map(|(kind, range)| {
+            bban.get(range.clone()).map(|value| Component {
+                kind: *kind,
+                range: range.clone(),
+                value,
+            })
+        })
+    }
+}
+
+/// A human readable breakdown of an IBAN, listing the country code, the check
+/// digits and every component of the BBAN on a separate line. It is returned
+/// by [`Iban::explain`].
+#[derive(Clone, Debug)]
+pub struct Explain<'a> {
+    iban: &'a Iban,
+}
+
+impl<'a> Explain<'a> {
+    pub(crate) fn new(iban: &'a Iban) -> Self {
+        Explain { iban }
+    }
+}
+
+impl Display for Explain<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "country code: {}", self.iban.country_code())?;
+        write!(f, "\ncheck digits: {}", self.iban.check_digits_str())?;
+        let mut components = self.iban.components().peekable();
+        if components.peek().is_none() {
+            write!(f, "\nbban: {}", self.iban.bban())?;
+        }
+        for component in components {
+            write!(f, "\n{}: {}", component.kind(), component.as_str())?;
+        }
+        Ok(())
+    }
+}
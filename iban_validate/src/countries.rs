@@ -1,21 +1,64 @@
 //! A module for parsing the BBAN structures from a definition. The format is
 //! very simple and can be optimized well by the compiler.
 
+use crate::{generated, ComponentKind, Country, RegistryDate};
+use core::ops::Range;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A BBAN format that preceded the current format of a country: the date
+/// from which it was no longer in effect, the format and the components of
+/// the BBAN.
+pub(crate) type HistoricalFormat = (
+    RegistryDate,
+    &'static [(usize, CharacterType)],
+    &'static [(ComponentKind, Range<usize>)],
+);
+
 /// A `CharacterType` can match a single character. This corresponds to the
 /// categories in the Swift registry for the most part, except that it doesn't
 /// allow lowercase characters for `c`. However, when parsing we have
 /// normalized the case anyway.
-#[derive(Copy, Clone)]
-pub(super) enum CharacterType {
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CharacterType {
+    /// Uppercase letters and digits, written as `c` in the registry.
     C,
+    /// Digits, written as `n` in the registry.
     N,
+    /// Uppercase letters, written as `a` in the registry.
     A,
 }
 
 impl CharacterType {
+    /// Get the character type from its letter in the registry notation, i.e.
+    /// `'n'`, `'a'` or `'c'`.
+    #[inline]
+    #[must_use]
+    pub fn from_notation(c: char) -> Option<CharacterType> {
+        match c {
+            'c' => Some(CharacterType::C),
+            'n' => Some(CharacterType::N),
+            'a' => Some(CharacterType::A),
+            _ => None,
+        }
+    }
+
+    /// Get the letter that represents this character type in the registry
+    /// notation.
+    #[inline]
+    #[must_use]
+    pub fn notation(self) -> char {
+        match self {
+            CharacterType::C => 'c',
+            CharacterType::N => 'n',
+            CharacterType::A => 'a',
+        }
+    }
+
     #[inline]
     #[must_use]
-    fn matches(self, c: u8) -> bool {
+    pub(crate) fn matches(self, c: u8) -> bool {
         use CharacterType::{A, C, N};
         const MASK_CAPITAL: u8 = 0b0100_0000;
         const MASK_DIGIT: u8 = 0b0010_0000;
@@ -28,7 +71,45 @@ impl CharacterType {
     }
 }
 
-pub(super) trait Matchable {
+/// Get the BBAN format of a country. This is the format in the registry, the
+/// format of the parent country for dependent territories, or the format from
+/// the extended countries if that feature is enabled.
+#[inline]
+#[must_use]
+pub(crate) fn country_pattern(country_code: &str) -> Option<&'static [(usize, CharacterType)]> {
+    generated::country_pattern(country_code)
+        .or_else(|| {
+            generated::parent_country(country_code)
+                .and_then(|parent| generated::country_pattern(parent.as_str()))
+        })
+        .or_else(|| crate::extended::country_pattern(country_code))
+}
+
+/// Get the BBAN format of a country as it was in effect at the given date.
+/// Returns `None` if the country format is unknown, or if the IBAN of the
+/// country was not yet in effect at that date. Dependent territories follow
+/// the dates of their parent country.
+#[must_use]
+pub(crate) fn country_pattern_as_of(
+    country_code: &str,
+    date: RegistryDate,
+) -> Option<&'static [(usize, CharacterType)]> {
+    let parent = generated::parent_country(country_code);
+    let country_code = parent.as_ref().map_or(country_code, Country::as_str);
+    if generated::country_info(country_code).is_some_and(|info| !info.is_in_effect(date)) {
+        return None;
+    }
+    let current = country_pattern(country_code)?;
+    Some(
+        generated::historical_country_patterns(country_code)
+            .iter()
+            .take_while(|(until, _, _)| date < *until)
+            .last()
+            .map_or(current, |(_, pattern, _)| *pattern),
+    )
+}
+
+pub(crate) trait Matchable {
     fn match_str(self, s: &str) -> bool;
 }
 
@@ -48,8 +129,27 @@ impl Matchable for &'_ [(usize, CharacterType)] {
     }
 }
 
+/// Check whether a string matches the part of a format at the given range of
+/// positions, such as the position of the bank identifier.
+#[cfg(feature = "std")]
+#[must_use]
+pub(crate) fn match_range(
+    pattern: &[(usize, CharacterType)],
+    range: Range<usize>,
+    s: &str,
+) -> bool {
+    range.end <= len(pattern)
+        && s.len() == range.len()
+        && pattern
+            .iter()
+            .flat_map(|(count, character_type)| (0..*count).map(move |_| character_type))
+            .skip(range.start)
+            .zip(s.as_bytes())
+            .all(|(character_type, c)| character_type.matches(*c))
+}
+
 #[inline]
 #[must_use]
-fn len(a: &[(usize, CharacterType)]) -> usize {
+pub(crate) fn len(a: &[(usize, CharacterType)]) -> usize {
     a.iter().map(|(count, _)| count).sum()
 }
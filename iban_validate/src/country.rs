@@ -0,0 +1,367 @@
+//! Country codes, the [`Country`] enum and country information. The enum and
+//! the information are generated from the IBAN registry, this module provides
+//! the types and the conversions from and to strings.
+
+use crate::{generated, Country};
+use core::error::Error;
+use core::fmt::{self, Debug, Display};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A two-letter country code, consisting of two uppercase ASCII letters. It
+/// is used by [`Country::Other`] to represent countries that are not in the
+/// IBAN registry.
+///
+/// # Example
+/// ```rust
+/// use iban::{Country, CountryCode, ParseCountryError};
+/// let country: Country = "AO".parse()?;
+/// assert_eq!(country, Country::Other("AO".parse::<CountryCode>()?));
+/// # Ok::<(), ParseCountryError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct CountryCode {
+    /// The two uppercase ASCII letters.
+    code: [u8; 2],
+}
+
+impl CountryCode {
+    /// Get the country code as a string slice.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.code).expect(
+            "A country code contained non-ASCII characters. Please create an issue at \
+             https://github.com/ThomasdenH/iban_validate.",
+        )
+    }
+}
+
+impl FromStr for CountryCode {
+    type Err = ParseCountryError;
+    /// Parse a country code.
+    ///
+    /// # Errors
+    /// If the string does not consist of exactly two uppercase ASCII letters,
+    /// a [`ParseCountryError`] will be returned.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            &[a, b] if a.is_ascii_uppercase() && b.is_ascii_uppercase() => {
+                Ok(CountryCode { code: [a, b] })
+            }
+            _ => Err(ParseCountryError),
+        }
+    }
+}
+
+impl Debug for CountryCode {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for CountryCode {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl FromStr for Country {
+    type Err = ParseCountryError;
+    /// Parse a country from its two-letter country code. Countries that are
+    /// not in the IBAN registry are returned as [`Country::Other`].
+    ///
+    /// # Errors
+    /// If the string does not consist of exactly two uppercase ASCII letters,
+    /// a [`ParseCountryError`] will be returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::{Country, ParseCountryError};
+    /// assert_eq!("NL".parse::<Country>()?, Country::NL);
+    /// assert_eq!("nl".parse::<Country>(), Err(ParseCountryError));
+    /// # Ok::<(), ParseCountryError>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let country_code: CountryCode = s.parse()?;
+        Ok(Country::from_registry_code(country_code.as_str())
+            .unwrap_or(Country::Other(country_code)))
+    }
+}
+
+impl Display for Country {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for Country {
+    #[inline]
+    fn eq(&self, other: &Country) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Country {}
+
+impl Hash for Country {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl Country {
+    /// Get the information in the IBAN registry about this country. Returns
+    /// `None` for [`Country::Other`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::Country;
+    /// let info = Country::DE.info().expect("Germany is in the registry");
+    /// assert_eq!(info.name(), "Germany");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn info(&self) -> Option<&'static CountryInfo> {
+        generated::country_info(self.as_str())
+    }
+
+    /// Get the country whose IBAN format is used by this country, if it is a
+    /// dependent territory that does not have its own entry in the registry.
+    /// For example, French overseas departments use the format of France.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::{Country, ParseCountryError};
+    /// let reunion: Country = "RE".parse()?;
+    /// assert_eq!(reunion.parent(), Some(Country::FR));
+    /// assert_eq!(Country::FR.parent(), None);
+    /// # Ok::<(), ParseCountryError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn parent(&self) -> Option<Country> {
+        generated::parent_country(self.as_str())
+    }
+}
+
+/// Get the information in the IBAN registry about the country with the given
+/// country code. Returns `None` if the country is not in the registry.
+///
+/// # Example
+/// ```rust
+/// use iban::{Country, RegistryDate};
+/// let info = iban::country_info("DE").expect("Germany is in the registry");
+/// assert_eq!(info.country(), Country::DE);
+/// assert_eq!(info.name(), "Germany");
+/// assert_eq!(info.iban_length(), 22);
+/// assert_eq!(info.bban_length(), 18);
+/// assert!(info.is_sepa());
+/// assert_eq!(info.effective_date(), RegistryDate::new(2007, 4));
+/// assert_eq!(info.example(), "DE89370400440532013000");
+///
+/// assert!(iban::country_info("AO").is_none());
+/// ```
+#[inline]
+#[must_use]
+pub fn country_info(country_code: &str) -> Option<&'static CountryInfo> {
+    generated::country_info(country_code)
+}
+
+/// Information about a country, as described in the IBAN registry. It can
+/// be obtained via [`country_info`] or [`Country::info`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct CountryInfo {
+    pub(crate) country: Country,
+    pub(crate) name: &'static str,
+    pub(crate) iban_length: usize,
+    pub(crate) bban_length: usize,
+    pub(crate) sepa: bool,
+    pub(crate) effective_date: RegistryDate,
+    pub(crate) example: &'static str,
+}
+
+impl CountryInfo {
+    /// Get the country this information describes.
+    #[inline]
+    #[must_use]
+    pub fn country(&self) -> Country {
+        self.country
+    }
+
+    /// Get the name of the country, as written in the IBAN registry.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the length of an IBAN of this country, in the electronic format.
+    #[inline]
+    #[must_use]
+    pub fn iban_length(&self) -> usize {
+        self.iban_length
+    }
+
+    /// Get the length of the BBAN of this country.
+    #[inline]
+    #[must_use]
+    pub fn bban_length(&self) -> usize {
+        self.bban_length
+    }
+
+    /// Check whether the country is part of the Single Euro Payments Area,
+    /// according to the IBAN registry.
+    #[inline]
+    #[must_use]
+    pub fn is_sepa(&self) -> bool {
+        self.sepa
+    }
+
+    /// Get the date from which the IBAN of this country is in effect.
+    #[inline]
+    #[must_use]
+    pub fn effective_date(&self) -> RegistryDate {
+        self.effective_date
+    }
+
+    /// Check whether the IBAN of this country is in effect at the given date.
+    /// If not, the IBAN is not yet mandatory in this country.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::{Country, RegistryDate};
+    /// let info = Country::OM.info().expect("Oman is in the registry");
+    /// assert!(!info.is_in_effect(RegistryDate::new(2020, 1)));
+    /// assert!(info.is_in_effect(RegistryDate::new(2024, 3)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_in_effect(&self, date: RegistryDate) -> bool {
+        date >= self.effective_date
+    }
+
+    /// Get the example IBAN from the registry, in the electronic format.
+    #[inline]
+    #[must_use]
+    pub fn example(&self) -> &'static str {
+        self.example
+    }
+}
+
+/// A date as used in the IBAN registry, which only specifies a year and a
+/// month. Dates are ordered chronologically.
+///
+/// # Example
+/// ```rust
+/// use iban::RegistryDate;
+/// let date = RegistryDate::new(2007, 4);
+/// assert_eq!(date.year(), 2007);
+/// assert_eq!(date.month(), 4);
+/// assert_eq!(date.to_string(), "2007-04");
+/// assert!(date < RegistryDate::new(2008, 1));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct RegistryDate {
+    pub(crate) year: u16,
+    pub(crate) month: u8,
+}
+
+impl RegistryDate {
+    /// Create a new date from a year and a month, where January is 1.
+    ///
+    /// # Panics
+    /// Panics if the month is not in the range `1..=12`.
+    #[inline]
+    #[must_use]
+    pub const fn new(year: u16, month: u8) -> RegistryDate {
+        assert!(month >= 1 && month <= 12, "the month should be in 1..=12");
+        RegistryDate { year, month }
+    }
+
+    /// Get the year.
+    #[inline]
+    #[must_use]
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Get the month, where January is 1.
+    #[inline]
+    #[must_use]
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+}
+
+impl Display for RegistryDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year, self.month)
+    }
+}
+
+/// An error indicating that a string is not a valid country code. A country
+/// code consists of exactly two uppercase ASCII letters.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParseCountryError;
+
+impl fmt::Display for ParseCountryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the string is not a two-letter country code")
+    }
+}
+
+impl Error for ParseCountryError {}
+
+#[cfg(feature = "serde")]
+impl Serialize for CountryCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Country {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CountryCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CountryCodeVisitor;
+        use serde::de;
+
+        impl<'vi> de::Visitor<'vi> for CountryCodeVisitor {
+            type Value = CountryCode;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a two-letter country code")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<CountryCode, E> {
+                value.parse::<CountryCode>().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(CountryCodeVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Country {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        CountryCode::deserialize(deserializer).map(|country_code| {
+            Country::from_registry_code(country_code.as_str())
+                .unwrap_or(Country::Other(country_code))
+        })
+    }
+}
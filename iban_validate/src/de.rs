@@ -0,0 +1,34 @@
+//! IBANs of Germany.
+
+use crate::markers::De;
+use crate::{ComponentKind, TypedIban};
+
+/// A German IBAN, which consists of the Bankleitzahl and the Kontonummer.
+///
+/// # Example
+/// ```rust
+/// use iban::de::GermanIban;
+/// let iban: GermanIban = "DE44 5001 0517 5407 3249 31".parse()?;
+/// assert_eq!(iban.blz(), "50010517");
+/// assert_eq!(iban.kontonummer(), "5407324931");
+/// assert!("NL91 ABNA 0417 1643 00".parse::<GermanIban>().is_err());
+/// # Ok::<(), iban::ParseTypedIbanError>(())
+/// ```
+pub type GermanIban = TypedIban<De>;
+
+impl TypedIban<De> {
+    /// Get the Bankleitzahl (BLZ), the eight-digit code of the bank.
+    #[inline]
+    #[must_use]
+    pub fn blz(&self) -> &str {
+        self.component(ComponentKind::BankIdentifier)
+    }
+
+    /// Get the Kontonummer, the ten-digit account number, including leading
+    /// zeros.
+    #[inline]
+    #[must_use]
+    pub fn kontonummer(&self) -> &str {
+        self.component(ComponentKind::AccountNumber)
+    }
+}
@@ -0,0 +1,664 @@
+//! A directory of banks, which gives access to the bank behind the bank and
+//! branch identifiers of an IBAN. The crate does not contain any bank data,
+//! but a directory can be loaded from the exports of a data vendor or be
+//! built manually.
+
+use crate::countries;
+use crate::registry::split_separated;
+use crate::{generated, Bic, ComponentKind, Country, Iban, IbanLike, ParseBicError};
+use core::error::Error;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{self, Read};
+use std::string::String;
+#[cfg(feature = "json")]
+use std::vec::Vec;
+
+/// Whether a bank can still receive transfers.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BankStatus {
+    /// The bank is in operation.
+    #[default]
+    Active,
+    /// The bank was closed or merged into another bank.
+    Inactive,
+}
+
+/// A bank or branch in a [`BankDirectory`].
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let bank = Bank::new("National Westminster Bank")
+///     .with_bic("NWBKGB2L".parse()?)
+///     .with_address("250 Bishopsgate, London");
+/// assert_eq!(bank.name(), "National Westminster Bank");
+/// assert_eq!(bank.bic(), Some("NWBKGB2L".parse()?));
+/// assert_eq!(bank.address(), Some("250 Bishopsgate, London"));
+/// assert_eq!(bank.status(), BankStatus::Active);
+/// # Ok::<(), ParseBicError>(())
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Bank {
+    name: String,
+    bic: Option<Bic>,
+    address: Option<String>,
+    status: BankStatus,
+}
+
+impl Bank {
+    /// Create an active bank without a BIC or address.
+    #[inline]
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Bank {
+        Bank {
+            name: name.into(),
+            bic: None,
+            address: None,
+            status: BankStatus::Active,
+        }
+    }
+
+    /// Set the BIC of the bank.
+    #[inline]
+    #[must_use]
+    pub fn with_bic(self, bic: Bic) -> Bank {
+        Bank {
+            bic: Some(bic),
+            ..self
+        }
+    }
+
+    /// Set the address of the bank.
+    #[inline]
+    #[must_use]
+    pub fn with_address(self, address: impl Into<String>) -> Bank {
+        Bank {
+            address: Some(address.into()),
+            ..self
+        }
+    }
+
+    /// Set the status of the bank.
+    #[inline]
+    #[must_use]
+    pub fn with_status(self, status: BankStatus) -> Bank {
+        Bank { status, ..self }
+    }
+
+    /// Get the name of the bank.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the BIC of the bank, if it is known.
+    #[inline]
+    #[must_use]
+    pub fn bic(&self) -> Option<Bic> {
+        self.bic
+    }
+
+    /// Get the address of the bank, if it is known.
+    #[inline]
+    #[must_use]
+    pub fn address(&self) -> Option<&str> {
+        self.address.as_deref()
+    }
+
+    /// Get whether the bank is still in operation.
+    #[inline]
+    #[must_use]
+    pub fn status(&self) -> BankStatus {
+        self.status
+    }
+}
+
+/// A source of bank data, keyed by country and the bank and branch
+/// identifiers of an IBAN. It can be implemented to look up banks in a
+/// database or an online service, or [`LocalBankDirectory`] can be used to
+/// load a file.
+pub trait BankDirectory {
+    /// Find the bank with the given identifiers. The identifiers are in the
+    /// electronic format, as returned by [`Iban::bank_identifier`] and
+    /// [`Iban::branch_identifier`]. If the directory doesn't contain the
+    /// branch, the bank itself should be returned if possible.
+    fn bank(
+        &self,
+        country: Country,
+        bank_identifier: &str,
+        branch_identifier: Option<&str>,
+    ) -> Option<&Bank>;
+}
+
+impl<D: BankDirectory + ?Sized> BankDirectory for &D {
+    #[inline]
+    fn bank(
+        &self,
+        country: Country,
+        bank_identifier: &str,
+        branch_identifier: Option<&str>,
+    ) -> Option<&Bank> {
+        (**self).bank(country, bank_identifier, branch_identifier)
+    }
+}
+
+impl Iban {
+    /// Look up the bank of the IBAN in a [`BankDirectory`]. The bank is
+    /// looked up using the country of the IBAN itself, so the banks of a
+    /// dependent territory such as Jersey must be listed under that
+    /// territory.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// # use core::error::Error;
+    /// let directory = LocalBankDirectory::parse_csv(
+    ///     "country,bank_id,branch_id,name,bic\n\
+    ///      GB,NWBK,,National Westminster Bank,NWBKGB2L\n",
+    /// )?;
+    /// let iban: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    /// let bank = iban.bank_in(&directory).expect("the bank is listed");
+    /// assert_eq!(bank.name(), "National Westminster Bank");
+    /// assert_eq!(bank.bic(), Some("NWBKGB2L".parse()?));
+    ///
+    /// let iban: Iban = "GB33 BUKB 2020 1555 5555 55".parse()?;
+    /// assert_eq!(iban.bank_in(&directory), None);
+    /// # Ok::<(), Box<dyn Error>>(())
+    /// ```
+    #[must_use]
+    pub fn bank_in<'a, D: BankDirectory + ?Sized>(&self, directory: &'a D) -> Option<&'a Bank> {
+        directory.bank(
+            self.country(),
+            self.bank_identifier()?,
+            self.branch_identifier(),
+        )
+    }
+}
+
+/// An error indicating that a bank directory could not be loaded.
+#[derive(Debug)]
+pub enum LoadBankDirectoryError {
+    /// The input could not be read.
+    Io(io::Error),
+    /// The input is not valid JSON, or does not consist of an array of
+    /// objects with the expected fields.
+    InvalidJson {
+        /// The line, starting at 1.
+        line: usize,
+        /// The column, starting at 1.
+        column: usize,
+    },
+    /// A column that is required is missing from the header of the CSV file.
+    MissingColumn(&'static str),
+    /// The record does not have the same number of fields as the header.
+    InvalidLayout {
+        /// The record, starting at 1. In a CSV file, the header is the first
+        /// record.
+        record: usize,
+    },
+    /// A field that is required is empty.
+    MissingField {
+        /// The record, starting at 1. In a CSV file, the header is the first
+        /// record.
+        record: usize,
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The country code is not valid.
+    InvalidCountryCode {
+        /// The record, starting at 1. In a CSV file, the header is the first
+        /// record.
+        record: usize,
+    },
+    /// The BIC is not valid.
+    InvalidBic {
+        /// The record, starting at 1. In a CSV file, the header is the first
+        /// record.
+        record: usize,
+        /// The error that occurred while parsing the BIC.
+        source: ParseBicError,
+    },
+    /// The status is neither `active` nor `inactive`.
+    InvalidStatus {
+        /// The record, starting at 1. In a CSV file, the header is the first
+        /// record.
+        record: usize,
+    },
+    /// The bank identifier does not follow the format of the country.
+    InvalidBankIdentifier {
+        /// The record, starting at 1. In a CSV file, the header is the first
+        /// record.
+        record: usize,
+    },
+    /// The branch identifier does not follow the format of the country.
+    InvalidBranchIdentifier {
+        /// The record, starting at 1. In a CSV file, the header is the first
+        /// record.
+        record: usize,
+    },
+}
+
+impl fmt::Display for LoadBankDirectoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadBankDirectoryError::Io(_) => write!(f, "the bank directory could not be read"),
+            LoadBankDirectoryError::InvalidJson { line, column } => write!(
+                f,
+                "the bank directory contains invalid JSON at {line}:{column}"
+            ),
+            LoadBankDirectoryError::MissingColumn(column) => {
+                write!(
+                    f,
+                    "the bank directory doesn't contain the column \"{column}\""
+                )
+            }
+            LoadBankDirectoryError::InvalidLayout { record } => {
+                write!(f, "the bank directory has an invalid layout at {record}")
+            }
+            LoadBankDirectoryError::MissingField { record, field } => {
+                write!(f, "the bank directory is missing the {field} at {record}")
+            }
+            LoadBankDirectoryError::InvalidCountryCode { record } => {
+                write!(
+                    f,
+                    "the bank directory has an invalid country code at {record}"
+                )
+            }
+            LoadBankDirectoryError::InvalidBic { record, .. } => {
+                write!(f, "the bank directory has an invalid BIC at {record}")
+            }
+            LoadBankDirectoryError::InvalidStatus { record } => {
+                write!(f, "the bank directory has an invalid status at {record}")
+            }
+            LoadBankDirectoryError::InvalidBankIdentifier { record } => {
+                write!(
+                    f,
+                    "the bank directory has an invalid bank identifier at {record}"
+                )
+            }
+            LoadBankDirectoryError::InvalidBranchIdentifier { record } => {
+                write!(
+                    f,
+                    "the bank directory has an invalid branch identifier at {record}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for LoadBankDirectoryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoadBankDirectoryError::Io(source) => Some(source),
+            LoadBankDirectoryError::InvalidBic { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for LoadBankDirectoryError {
+    #[inline]
+    fn from(source: io::Error) -> LoadBankDirectoryError {
+        LoadBankDirectoryError::Io(source)
+    }
+}
+
+/// A record of a bank directory export, before it is validated.
+#[derive(Default)]
+#[cfg_attr(feature = "json", derive(Deserialize))]
+struct Record {
+    #[cfg_attr(feature = "json", serde(default))]
+    country: String,
+    #[cfg_attr(feature = "json", serde(default))]
+    bank_id: String,
+    branch_id: Option<String>,
+    #[cfg_attr(feature = "json", serde(default))]
+    name: String,
+    bic: Option<String>,
+    address: Option<String>,
+    status: Option<String>,
+}
+
+/// Check whether a bank or branch identifier follows the format of a country
+/// at the position of the identifier. Dependent territories use the format of
+/// their parent country. If the format or the position is not known, the
+/// identifier is not validated.
+fn is_valid_identifier(country: Country, kind: ComponentKind, identifier: &str) -> bool {
+    let country = country.parent().unwrap_or(country);
+    let range = match kind {
+        ComponentKind::BankIdentifier => generated::bank_identifier(country.as_str()),
+        _ => generated::branch_identifier(country.as_str()),
+    };
+    match (countries::country_pattern(country.as_str()), range) {
+        (Some(pattern), Some(range)) => {
+            countries::match_range(pattern, range, &identifier.to_ascii_uppercase())
+        }
+        _ => true,
+    }
+}
+
+/// The key of a bank in a [`LocalBankDirectory`].
+type Key = (Country, String, Option<String>);
+
+/// A [`BankDirectory`] that is kept in memory. It can be loaded from a CSV or
+/// JSON export or be built manually. Every record consists of the following
+/// fields, of which only the country, the bank identifier and the name are
+/// required:
+///
+/// | Field       | Content                                            |
+/// |-------------|----------------------------------------------------|
+/// | `country`   | The country code of the IBAN.                      |
+/// | `bank_id`   | The bank identifier, as in [`Iban::bank_identifier`]. |
+/// | `branch_id` | The branch identifier, as in [`Iban::branch_identifier`]. If it is empty, the record applies to all branches of the bank. |
+/// | `name`      | The name of the bank.                              |
+/// | `bic`       | The BIC of the bank.                               |
+/// | `address`   | The address of the bank.                           |
+/// | `status`    | `active` or `inactive`. Defaults to `active`.      |
+///
+/// When an export is loaded, the bank and branch identifiers are checked
+/// against the format of the country, if both the format and the position of
+/// the identifier are known. Identifiers of other countries, and identifiers
+/// that are added using [`LocalBankDirectory::insert`], are not validated.
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// # use core::error::Error;
+/// let mut directory = LocalBankDirectory::parse_csv(
+///     "country,bank_id,name,bic\n\
+///      DE,50010517,ING-DiBa,INGDDEFFXXX\n",
+/// )?;
+/// directory.insert(
+///     Country::BE,
+///     "539",
+///     None,
+///     Bank::new("Banque Van Breda").with_status(BankStatus::Inactive),
+/// );
+///
+/// let iban: Iban = "DE44 5001 0517 5407 3249 31".parse()?;
+/// assert_eq!(iban.bank_in(&directory).map(Bank::name), Some("ING-DiBa"));
+/// let iban: Iban = "BE68 5390 0754 7034".parse()?;
+/// assert_eq!(
+///     iban.bank_in(&directory).map(Bank::status),
+///     Some(BankStatus::Inactive)
+/// );
+/// # Ok::<(), Box<dyn Error>>(())
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct LocalBankDirectory {
+    banks: HashMap<Key, Bank>,
+}
+
+impl LocalBankDirectory {
+    /// Create an empty directory.
+    #[inline]
+    #[must_use]
+    pub fn new() -> LocalBankDirectory {
+        LocalBankDirectory::default()
+    }
+
+    /// Add a bank, returning the previous bank if the identifiers were
+    /// already present. If the branch identifier is `None`, the bank is
+    /// returned for all branches that are not listed separately. The
+    /// identifiers are converted to uppercase, but they are not validated.
+    pub fn insert(
+        &mut self,
+        country: Country,
+        bank_identifier: &str,
+        branch_identifier: Option<&str>,
+        bank: Bank,
+    ) -> Option<Bank> {
+        self.banks.insert(
+            (
+                country,
+                bank_identifier.to_ascii_uppercase(),
+                branch_identifier.map(str::to_ascii_uppercase),
+            ),
+            bank,
+        )
+    }
+
+    /// Add all banks of another directory. Banks that are in both
+    /// directories get the data of the other directory.
+    #[inline]
+    pub fn extend(&mut self, other: LocalBankDirectory) {
+        self.banks.extend(other.banks);
+    }
+
+    /// Iterate over the country, bank identifier, branch identifier and data
+    /// of all banks, in no particular order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Country, &str, Option<&str>, &Bank)> {
+        self.banks
+            .iter()
+            .map(|((country, bank_identifier, branch_identifier), bank)| {
+                (
+                    *country,
+                    bank_identifier.as_str(),
+                    branch_identifier.as_deref(),
+                    bank,
+                )
+            })
+    }
+
+    /// Get the number of banks and branches in the directory.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.banks.len()
+    }
+
+    /// Check whether the directory contains no banks.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.banks.is_empty()
+    }
+
+    /// Validate a record and add it to the directory.
+    fn insert_record(
+        &mut self,
+        record: usize,
+        fields: Record,
+    ) -> Result<(), LoadBankDirectoryError> {
+        let non_empty = |field: Option<String>| field.filter(|field| !field.trim().is_empty());
+        let required = |field: String, name: &'static str| {
+            let field = field.trim();
+            if field.is_empty() {
+                Err(LoadBankDirectoryError::MissingField {
+                    record,
+                    field: name,
+                })
+            } else {
+                Ok(String::from(field))
+            }
+        };
+        let country = required(fields.country, "country")?
+            .to_ascii_uppercase()
+            .parse()
+            .map_err(|_| LoadBankDirectoryError::InvalidCountryCode { record })?;
+        let bank_identifier = required(fields.bank_id, "bank_id")?;
+        if !is_valid_identifier(country, ComponentKind::BankIdentifier, &bank_identifier) {
+            return Err(LoadBankDirectoryError::InvalidBankIdentifier { record });
+        }
+        let mut bank = Bank::new(required(fields.name, "name")?);
+        if let Some(bic) = non_empty(fields.bic) {
+            bank = bank.with_bic(
+                bic.trim()
+                    .parse()
+                    .map_err(|source| LoadBankDirectoryError::InvalidBic { record, source })?,
+            );
+        }
+        if let Some(address) = non_empty(fields.address) {
+            bank = bank.with_address(address.trim());
+        }
+        if let Some(status) = non_empty(fields.status) {
+            let status = status.trim();
+            bank = bank.with_status(if status.eq_ignore_ascii_case("active") {
+                BankStatus::Active
+            } else if status.eq_ignore_ascii_case("inactive") {
+                BankStatus::Inactive
+            } else {
+                return Err(LoadBankDirectoryError::InvalidStatus { record });
+            });
+        }
+        let branch_identifier = non_empty(fields.branch_id);
+        if branch_identifier
+            .as_deref()
+            .is_some_and(|branch_identifier| {
+                !is_valid_identifier(
+                    country,
+                    ComponentKind::BranchIdentifier,
+                    branch_identifier.trim(),
+                )
+            })
+        {
+            return Err(LoadBankDirectoryError::InvalidBranchIdentifier { record });
+        }
+        self.insert(
+            country,
+            &bank_identifier,
+            branch_identifier.as_deref().map(str::trim),
+            bank,
+        );
+        Ok(())
+    }
+
+    /// Parse a comma-separated export. The first line is a header containing
+    /// the names of the columns, which may be in any order. Columns with
+    /// other names are ignored. Fields can be enclosed in double quotes.
+    /// ```text
+    /// country,bank_id,branch_id,name,bic,address,status
+    /// GB,NWBK,601613,National Westminster Bank,NWBKGB2L,"250 Bishopsgate, London",active
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`LoadBankDirectoryError`] if a required column is missing,
+    /// or with the number of the first record that could not be parsed.
+    pub fn parse_csv(s: &str) -> Result<LocalBankDirectory, LoadBankDirectoryError> {
+        let mut rows = split_separated(s, ',').into_iter();
+        let header = rows.next().unwrap_or_default();
+        let column = |name: &str| {
+            header
+                .iter()
+                .position(|column| column.eq_ignore_ascii_case(name))
+        };
+        let required =
+            |name: &'static str| column(name).ok_or(LoadBankDirectoryError::MissingColumn(name));
+        let (country, bank_id, name) = (
+            required("country")?,
+            required("bank_id")?,
+            required("name")?,
+        );
+        let (branch_id, bic, address, status) = (
+            column("branch_id"),
+            column("bic"),
+            column("address"),
+            column("status"),
+        );
+
+        let mut directory = LocalBankDirectory::new();
+        for (i, mut row) in rows.enumerate() {
+            let record = i + 2;
+            if row.iter().all(String::is_empty) {
+                continue;
+            }
+            if row.len() != header.len() {
+                return Err(LoadBankDirectoryError::InvalidLayout { record });
+            }
+            let mut take = |column: usize| core::mem::take(&mut row[column]);
+            let fields = Record {
+                country: take(country),
+                bank_id: take(bank_id),
+                branch_id: branch_id.map(&mut take),
+                name: take(name),
+                bic: bic.map(&mut take),
+                address: address.map(&mut take),
+                status: status.map(&mut take),
+            };
+            directory.insert_record(record, fields)?;
+        }
+        Ok(directory)
+    }
+
+    /// Read a comma-separated export. See [`LocalBankDirectory::parse_csv`].
+    ///
+    /// # Errors
+    /// Returns a [`LoadBankDirectoryError`] if the input could not be read or
+    /// parsed.
+    pub fn read_csv(mut reader: impl Read) -> Result<LocalBankDirectory, LoadBankDirectoryError> {
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        LocalBankDirectory::parse_csv(&s)
+    }
+
+    /// Parse a JSON export, which consists of an array of objects with the
+    /// fields of a record. Fields that are missing or `null` are considered
+    /// empty. Requires the `json` feature.
+    /// ```json
+    /// [
+    ///     {
+    ///         "country": "GB",
+    ///         "bank_id": "NWBK",
+    ///         "branch_id": "601613",
+    ///         "name": "National Westminster Bank",
+    ///         "bic": "NWBKGB2L"
+    ///     }
+    /// ]
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`LoadBankDirectoryError`] if the input is not valid JSON,
+    /// or with the number of the first record that could not be parsed.
+    #[cfg(feature = "json")]
+    pub fn parse_json(s: &str) -> Result<LocalBankDirectory, LoadBankDirectoryError> {
+        let records: Vec<Record> =
+            serde_json::from_str(s).map_err(|error| LoadBankDirectoryError::InvalidJson {
+                line: error.line(),
+                column: error.column(),
+            })?;
+        let mut directory = LocalBankDirectory::new();
+        for (i, fields) in records.into_iter().enumerate() {
+            directory.insert_record(i + 1, fields)?;
+        }
+        Ok(directory)
+    }
+
+    /// Read a JSON export. See [`LocalBankDirectory::parse_json`]. Requires
+    /// the `json` feature.
+    ///
+    /// # Errors
+    /// Returns a [`LoadBankDirectoryError`] if the input could not be read or
+    /// parsed.
+    #[cfg(feature = "json")]
+    pub fn read_json(mut reader: impl Read) -> Result<LocalBankDirectory, LoadBankDirectoryError> {
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        LocalBankDirectory::parse_json(&s)
+    }
+}
+
+impl BankDirectory for LocalBankDirectory {
+    fn bank(
+        &self,
+        country: Country,
+        bank_identifier: &str,
+        branch_identifier: Option<&str>,
+    ) -> Option<&Bank> {
+        let mut key = (country, bank_identifier.to_ascii_uppercase(), None);
+        if let Some(branch_identifier) = branch_identifier {
+            key.2 = Some(branch_identifier.to_ascii_uppercase());
+            if let Some(bank) = self.banks.get(&key) {
+                return Some(bank);
+            }
+            key.2 = None;
+        }
+        self.banks.get(&key)
+    }
+}
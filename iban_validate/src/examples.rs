@@ -0,0 +1,90 @@
+//! Recognition of IBANs that are published as examples, such as those in the
+//! registry and in the documentation of banks and payment providers. These
+//! are often entered in forms by users who don't know their own IBAN.
+
+use crate::generated::REGISTRY_EXAMPLES;
+use crate::{BaseIban, Iban, IbanLike};
+
+/// Widely published example IBANs that are not in the registry, in the
+/// electronic format and sorted.
+const PUBLISHED_EXAMPLES: &[&str] = &[
+    "AT611904300234573201",
+    "BE68539007547034",
+    "BE71096123456769",
+    "CH5604835012345678009",
+    "DE02120300000000202051",
+    "DE02500105170137075030",
+    "DE12500105170648489890",
+    "DE44500105175407324931",
+    "DE75512108001245126199",
+    "DE89370400440532013000",
+    "DK5000400440116243",
+    "ES9121000418450200051332",
+    "FI2112345600000785",
+    "FR7630006000011234567890189",
+    "GB33BUKB20201555555555",
+    "GB82WEST12345698765432",
+    "GB94BARC10201530093459",
+    "IE29AIBK93115212345678",
+    "IT60X0542811101000000123456",
+    "LU280019400644750000",
+    "NL02ABNA0123456789",
+    "NL91ABNA0417164300",
+    "NO9386011117947",
+    "PL61109010140000071219812874",
+    "PT50000201231234567890154",
+    "SE4550000000058398257466",
+];
+
+/// Check whether an IBAN in the electronic format is a known example.
+fn is_known_example(electronic: &str) -> bool {
+    REGISTRY_EXAMPLES.binary_search(&electronic).is_ok()
+        || PUBLISHED_EXAMPLES.binary_search(&electronic).is_ok()
+}
+
+/// Get the IBANs that are recognized by [`Iban::is_known_example`], in the
+/// electronic format. These are the examples of the registry, followed by
+/// other widely published examples.
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// assert!(known_examples().any(|example| example == "DE89370400440532013000"));
+/// ```
+#[inline]
+pub fn known_examples() -> impl Iterator<Item = &'static str> {
+    REGISTRY_EXAMPLES.iter().chain(PUBLISHED_EXAMPLES).copied()
+}
+
+impl BaseIban {
+    /// Check whether this IBAN is a well-known example, such as one from the
+    /// registry or from documentation. See [`Iban::is_known_example`].
+    #[inline]
+    #[must_use]
+    pub fn is_known_example(&self) -> bool {
+        is_known_example(self.electronic_str())
+    }
+}
+
+impl Iban {
+    /// Check whether this IBAN is a well-known example, such as one from the
+    /// registry or from documentation. Such IBANs are valid, but are unlikely
+    /// to belong to the person entering them, so they may be rejected or
+    /// flagged. The recognized IBANs are listed by [`known_examples`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let example: Iban = "DE89 3704 0044 0532 0130 00".parse()?;
+    /// assert!(example.is_known_example());
+    ///
+    /// let other: Iban = "DE63 2005 0000 0193 8475 62".parse()?;
+    /// assert!(!other.is_known_example());
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_known_example(&self) -> bool {
+        is_known_example(self.electronic_str())
+    }
+}
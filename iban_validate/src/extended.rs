@@ -0,0 +1,97 @@
+//! Countries that use IBANs, but that are not in the IBAN registry. These are
+//! sometimes called partial IBAN countries. Their formats are curated by hand
+//! from national documentation and are NOT part of the registry, so they are
+//! only used when the `extended_countries` feature is enabled.
+
+use crate::countries::CharacterType;
+use crate::generated;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Indicates where the BBAN format of a country comes from.
+///
+/// # Example
+/// ```rust
+/// use iban::FormatSource;
+/// assert_eq!(iban::format_source("DE"), Some(FormatSource::Registry));
+/// # #[cfg(not(feature = "extended_countries"))]
+/// assert_eq!(iban::format_source("DZ"), None);
+/// # #[cfg(feature = "extended_countries")]
+/// assert_eq!(iban::format_source("DZ"), Some(FormatSource::Extended));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FormatSource {
+    /// The format is described in the IBAN registry.
+    Registry,
+    /// The format is not in the IBAN registry, but comes from the curated
+    /// list of countries that is enabled by the `extended_countries` feature.
+    Extended,
+}
+
+/// Get the source of the BBAN format of a country. Returns `None` if the
+/// country format is unknown, in which case an IBAN of this country can only
+/// be parsed as a [`BaseIban`](crate::BaseIban). Dependent territories that
+/// use the format of their parent country are included in the registry.
+#[inline]
+#[must_use]
+pub fn format_source(country_code: &str) -> Option<FormatSource> {
+    if generated::country_pattern(country_code).is_some()
+        || generated::parent_country(country_code).is_some()
+    {
+        Some(FormatSource::Registry)
+    } else if country_pattern(country_code).is_some() {
+        Some(FormatSource::Extended)
+    } else {
+        None
+    }
+}
+
+/// The countries that use the IBAN but are not in the IBAN registry, with
+/// their BBAN format.
+#[cfg(feature = "extended_countries")]
+pub(crate) const EXTENDED_COUNTRIES: &[(&str, &[(usize, CharacterType)])] = {
+    use CharacterType::{A, C, N};
+    &[
+        ("AO", &[(21, N)]),         // Angola
+        ("BF", &[(2, C), (22, N)]), // Burkina Faso
+        ("BJ", &[(2, C), (22, N)]), // Benin
+        ("CF", &[(23, N)]),         // Central African Republic
+        ("CG", &[(23, N)]),         // Congo
+        ("CI", &[(2, C), (22, N)]), // Côte d'Ivoire
+        ("CM", &[(23, N)]),         // Cameroon
+        ("CV", &[(21, N)]),         // Cabo Verde
+        ("DZ", &[(22, N)]),         // Algeria
+        ("GA", &[(23, N)]),         // Gabon
+        ("GQ", &[(23, N)]),         // Equatorial Guinea
+        ("GW", &[(2, C), (19, N)]), // Guinea-Bissau
+        ("HN", &[(4, A), (20, N)]), // Honduras
+        ("IR", &[(22, N)]),         // Iran
+        ("KM", &[(23, N)]),         // Comoros
+        ("MA", &[(24, N)]),         // Morocco
+        ("MG", &[(23, N)]),         // Madagascar
+        ("ML", &[(2, C), (22, N)]), // Mali
+        ("MZ", &[(21, N)]),         // Mozambique
+        ("NE", &[(2, C), (22, N)]), // Niger
+        ("SN", &[(2, C), (22, N)]), // Senegal
+        ("TD", &[(23, N)]),         // Chad
+        ("TG", &[(2, C), (22, N)]), // Togo
+    ]
+};
+
+/// Get the BBAN format of a country that is not in the registry.
+#[cfg(feature = "extended_countries")]
+#[inline]
+pub(crate) fn country_pattern(country_code: &str) -> Option<&'static [(usize, CharacterType)]> {
+    EXTENDED_COUNTRIES
+        .iter()
+        .find(|(code, _)| *code == country_code)
+        .map(|(_, pattern)| *pattern)
+}
+
+/// Without the `extended_countries` feature, no extra countries are known.
+#[cfg(not(feature = "extended_countries"))]
+#[inline]
+pub(crate) fn country_pattern(_country_code: &str) -> Option<&'static [(usize, CharacterType)]> {
+    None
+}
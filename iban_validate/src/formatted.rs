@@ -0,0 +1,200 @@
+//! Configurable formatting of IBANs. The [`Display`] implementations of
+//! [`BaseIban`](crate::BaseIban) and [`Iban`](crate::Iban) use the default
+//! options, which produce the paper format.
+
+use crate::base_iban::{groups, MAX_IBAN_LEN, PAPER_GROUP_SIZE};
+use crate::masked::{Masked, Redacted};
+use arrayvec::ArrayString;
+use core::error::Error;
+use core::fmt::{self, Display, Write};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The maximum length in bytes of a formatted IBAN: the maximum number of
+/// characters, with a separator of at most four bytes between every two
+/// characters.
+const MAX_FORMATTED_LEN: usize = MAX_IBAN_LEN + (MAX_IBAN_LEN - 1) * 4;
+
+/// A formatting of an IBAN, which can be configured and displayed. It is
+/// obtained via [`BaseIban::formatted`](crate::BaseIban::formatted) or
+/// [`Iban::formatted`](crate::Iban::formatted). By default, it produces the
+/// paper format, which consists of groups of four characters separated by a
+/// space.
+///
+/// The [`Display`] implementation honours the width, fill and alignment of
+/// the formatter, but ignores the precision. The alternate flag, `{:#}`, produces the electronic format
+/// instead. The same holds for the [`Display`] implementations of
+/// [`BaseIban`](crate::BaseIban) and [`Iban`](crate::Iban).
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let iban: Iban = "DE44 5001 0517 5407 3249 31".parse()?;
+/// assert_eq!(
+///     iban.formatted().group_size(3).separator('-').lowercase().to_string(),
+///     "de4-450-010-517-540-732-493-1"
+/// );
+/// assert_eq!(format!("{:#}", iban), "DE44500105175407324931");
+/// assert_eq!(format!("{:>30}|", iban), "   DE44 5001 0517 5407 3249 31|");
+///
+/// // Allocation-free output.
+/// let formatted = iban.formatted().separator('.').to_array_string();
+/// assert_eq!(formatted.as_str(), "DE44.5001.0517.5407.3249.31");
+/// let mut buffer = [0; 64];
+/// assert_eq!(
+///     iban.formatted().write_to(&mut buffer),
+///     Ok("DE44 5001 0517 5407 3249 31")
+/// );
+/// # Ok::<(), ParseIbanError>(())
+/// ```
+#[derive(Copy, Clone)]
+pub struct Formatted<'a> {
+    electronic: &'a str,
+    group_size: usize,
+    separator: char,
+    lowercase: bool,
+}
+
+impl<'a> Formatted<'a> {
+    pub(crate) fn new(electronic: &'a str) -> Self {
+        Formatted {
+            electronic,
+            group_size: PAPER_GROUP_SIZE,
+            separator: ' ',
+            lowercase: false,
+        }
+    }
+
+    /// Set the number of characters in a group. A group size of zero means
+    /// that the characters are not grouped. The default is four.
+    #[inline]
+    #[must_use]
+    pub fn group_size(self, group_size: usize) -> Self {
+        Formatted { group_size, ..self }
+    }
+
+    /// Set the character that separates the groups. The default is a space.
+    #[inline]
+    #[must_use]
+    pub fn separator(self, separator: char) -> Self {
+        Formatted { separator, ..self }
+    }
+
+    /// Output lowercase letters instead of uppercase letters.
+    #[inline]
+    #[must_use]
+    pub fn lowercase(self) -> Self {
+        Formatted {
+            lowercase: true,
+            ..self
+        }
+    }
+
+    /// Write the formatted IBAN without padding. If `grouped` is false, the
+    /// electronic format is written.
+    fn write(&self, w: &mut impl Write, grouped: bool) -> fmt::Result {
+        let group_size = if grouped { self.group_size } else { 0 };
+        for (i, group) in groups(self.electronic, group_size).enumerate() {
+            if i != 0 {
+                w.write_char(self.separator)?;
+            }
+            for c in group.chars() {
+                w.write_char(if self.lowercase {
+                    c.to_ascii_lowercase()
+                } else {
+                    c
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the formatted IBAN as an [`ArrayString`], which does not allocate.
+    /// Its capacity is large enough for any IBAN and separator.
+    #[must_use]
+    pub fn to_array_string(&self) -> ArrayString<MAX_FORMATTED_LEN> {
+        let mut s = ArrayString::new();
+        self.write(&mut s, true).expect(
+            "A formatted IBAN did not fit its buffer. Please create an issue at \
+             https://github.com/ThomasdenH/iban_validate.",
+        );
+        s
+    }
+
+    /// Write the formatted IBAN into the given buffer, returning the part of
+    /// the buffer that was written as a string slice.
+    ///
+    /// # Errors
+    /// If the buffer is too small, a [`BufferTooSmallError`] is returned.
+    pub fn write_to<'b>(&self, buffer: &'b mut [u8]) -> Result<&'b str, BufferTooSmallError> {
+        let formatted = self.to_array_string();
+        let target = buffer
+            .get_mut(..formatted.len())
+            .ok_or(BufferTooSmallError)?;
+        target.copy_from_slice(formatted.as_bytes());
+        Ok(core::str::from_utf8(target).expect(
+            "A formatted IBAN was not valid UTF-8. Please create an issue at \
+             https://github.com/ThomasdenH/iban_validate.",
+        ))
+    }
+}
+
+impl fmt::Debug for Formatted<'_> {
+    /// With the `redact_debug` feature, the IBAN is masked.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Formatted")
+            .field(
+                "electronic",
+                &Redacted(Masked::new(self.electronic, None).electronic()),
+            )
+            .field("group_size", &self.group_size)
+            .field("separator", &self.separator)
+            .field("lowercase", &self.lowercase)
+            .finish()
+    }
+}
+
+impl Display for Formatted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = ArrayString::<MAX_FORMATTED_LEN>::new();
+        self.write(&mut s, !f.alternate())?;
+        pad(f, &s)
+    }
+}
+
+/// Write a string using the width, fill and alignment of the formatter. Unlike
+/// [`fmt::Formatter::pad`], the precision is ignored, so that an IBAN is never
+/// truncated.
+pub(crate) fn pad(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let padding = f
+        .width()
+        .map_or(0, |width| width.saturating_sub(s.chars().count()));
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// An error indicating that a buffer was too small to contain a formatted
+/// IBAN.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BufferTooSmallError;
+
+impl fmt::Display for BufferTooSmallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the buffer is too small for the formatted IBAN")
+    }
+}
+
+impl Error for BufferTooSmallError {}
@@ -0,0 +1,35 @@
+//! IBANs of the United Kingdom.
+
+use crate::markers::Gb;
+use crate::{ComponentKind, TypedIban};
+
+/// A British IBAN, which consists of the bank identifier, the sort code and
+/// the account number. IBANs of dependent territories such as Jersey, which
+/// use the same format, have another country code and are not accepted.
+///
+/// # Example
+/// ```rust
+/// use iban::gb::BritishIban;
+/// let iban: BritishIban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+/// assert_eq!(iban.bank_identifier(), Some("NWBK"));
+/// assert_eq!(iban.sort_code(), "601613");
+/// assert_eq!(iban.account_number(), "31926819");
+/// # Ok::<(), iban::ParseTypedIbanError>(())
+/// ```
+pub type BritishIban = TypedIban<Gb>;
+
+impl TypedIban<Gb> {
+    /// Get the six-digit sort code, which identifies the branch.
+    #[inline]
+    #[must_use]
+    pub fn sort_code(&self) -> &str {
+        self.component(ComponentKind::BranchIdentifier)
+    }
+
+    /// Get the eight-digit account number.
+    #[inline]
+    #[must_use]
+    pub fn account_number(&self) -> &str {
+        self.component(ComponentKind::AccountNumber)
+    }
+}
@@ -1,5 +1,482 @@
 //! This file is automatically generated by `iban_validate_registry_generation` from the IBAN registry.
 
+/// A country, identified by its two-letter country code. Every country in the
+/// IBAN registry has its own variant. Other country codes, for example of a
+/// [`BaseIban`](crate::BaseIban) from a country that is not in the registry,
+/// are represented by [`Country::Other`]. Countries are compared by their
+/// country code, so `Country::Other` with the code of a country in the
+/// registry is equal to the variant of that country.
+#[derive(Copy, Clone, Debug)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Country {
+    /// Andorra
+    AD,
+    /// United Arab Emirates (The)
+    AE,
+    /// Albania
+    AL,
+    /// Austria
+    AT,
+    /// Azerbaijan
+    AZ,
+    /// Bosnia and Herzegovina
+    BA,
+    /// Belgium
+    BE,
+    /// Bulgaria
+    BG,
+    /// Bahrain
+    BH,
+    /// Burundi
+    BI,
+    /// Brazil
+    BR,
+    /// Republic of Belarus
+    BY,
+    /// Switzerland
+    CH,
+    /// Costa Rica
+    CR,
+    /// Cyprus
+    CY,
+    /// Czechia
+    CZ,
+    /// Germany
+    DE,
+    /// Djibouti
+    DJ,
+    /// Denmark
+    DK,
+    /// Dominican Republic
+    DO,
+    /// Estonia
+    EE,
+    /// Egypt
+    EG,
+    /// Spain
+    ES,
+    /// Finland
+    FI,
+    /// Falkland Islands
+    FK,
+    /// Faroe Islands
+    FO,
+    /// France
+    FR,
+    /// United Kingdom
+    GB,
+    /// Georgia
+    GE,
+    /// Gibraltar
+    GI,
+    /// Greenland
+    GL,
+    /// Greece
+    GR,
+    /// Guatemala
+    GT,
+    /// Croatia
+    HR,
+    /// Hungary
+    HU,
+    /// Ireland
+    IE,
+    /// Israel
+    IL,
+    /// Iraq
+    IQ,
+    /// Iceland
+    IS,
+    /// Italy
+    IT,
+    /// Jordan
+    JO,
+    /// Kuwait
+    KW,
+    /// Kazakhstan
+    KZ,
+    /// Lebanon
+    LB,
+    /// Saint Lucia
+    LC,
+    /// Liechtenstein
+    LI,
+    /// Lithuania
+    LT,
+    /// Luxembourg
+    LU,
+    /// Latvia
+    LV,
+    /// Libya
+    LY,
+    /// Monaco
+    MC,
+    /// Moldova
+    MD,
+    /// Montenegro
+    ME,
+    /// Macedonia
+    MK,
+    /// Mongolia
+    MN,
+    /// Mauritania
+    MR,
+    /// Malta
+    MT,
+    /// Mauritius
+    MU,
+    /// Nicaragua
+    NI,
+    /// Netherlands (The)
+    NL,
+    /// Norway
+    NO,
+    /// Oman
+    OM,
+    /// Poland
+    PL,
+    /// Palestine, State of
+    PS,
+    /// Portugal
+    PT,
+    /// Qatar
+    QA,
+    /// Romania
+    RO,
+    /// Serbia
+    RS,
+    /// Russia
+    RU,
+    /// Saudi Arabia
+    SA,
+    /// Seychelles
+    SC,
+    /// Sudan
+    SD,
+    /// Sweden
+    SE,
+    /// Slovenia
+    SI,
+    /// Slovakia
+    SK,
+    /// San Marino
+    SM,
+    /// Somalia
+    SO,
+    /// Sao Tome and Principe
+    ST,
+    /// El Salvador
+    SV,
+    /// Timor-Leste
+    TL,
+    /// Tunisia
+    TN,
+    /// Turkey
+    TR,
+    /// Ukraine
+    UA,
+    /// Vatican City State
+    VA,
+    /// Virgin Islands
+    VG,
+    /// Kosovo
+    XK,
+    /// Yemen
+    YE,
+    /// A country that is not in the IBAN registry.
+    Other(crate::CountryCode),
+}
+
+impl Country {
+    /// All countries in the IBAN registry, ordered by country code. This does
+    /// not include [`Country::Other`].
+    pub const ALL: &'static [Country] = &[
+        Country::AD,
+        Country::AE,
+        Country::AL,
+        Country::AT,
+        Country::AZ,
+        Country::BA,
+        Country::BE,
+        Country::BG,
+        Country::BH,
+        Country::BI,
+        Country::BR,
+        Country::BY,
+        Country::CH,
+        Country::CR,
+        Country::CY,
+        Country::CZ,
+        Country::DE,
+        Country::DJ,
+        Country::DK,
+        Country::DO,
+        Country::EE,
+        Country::EG,
+        Country::ES,
+        Country::FI,
+        Country::FK,
+        Country::FO,
+        Country::FR,
+        Country::GB,
+        Country::GE,
+        Country::GI,
+        Country::GL,
+        Country::GR,
+        Country::GT,
+        Country::HR,
+        Country::HU,
+        Country::IE,
+        Country::IL,
+        Country::IQ,
+        Country::IS,
+        Country::IT,
+        Country::JO,
+        Country::KW,
+        Country::KZ,
+        Country::LB,
+        Country::LC,
+        Country::LI,
+        Country::LT,
+        Country::LU,
+        Country::LV,
+        Country::LY,
+        Country::MC,
+        Country::MD,
+        Country::ME,
+        Country::MK,
+        Country::MN,
+        Country::MR,
+        Country::MT,
+        Country::MU,
+        Country::NI,
+        Country::NL,
+        Country::NO,
+        Country::OM,
+        Country::PL,
+        Country::PS,
+        Country::PT,
+        Country::QA,
+        Country::RO,
+        Country::RS,
+        Country::RU,
+        Country::SA,
+        Country::SC,
+        Country::SD,
+        Country::SE,
+        Country::SI,
+        Country::SK,
+        Country::SM,
+        Country::SO,
+        Country::ST,
+        Country::SV,
+        Country::TL,
+        Country::TN,
+        Country::TR,
+        Country::UA,
+        Country::VA,
+        Country::VG,
+        Country::XK,
+        Country::YE,
+    ];
+
+    /// Get the two-letter country code.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::Country;
+    /// assert_eq!(Country::DE.as_str(), "DE");
+    /// ```
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Country::AD => "AD",
+            Country::AE => "AE",
+            Country::AL => "AL",
+            Country::AT => "AT",
+            Country::AZ => "AZ",
+            Country::BA => "BA",
+            Country::BE => "BE",
+            Country::BG => "BG",
+            Country::BH => "BH",
+            Country::BI => "BI",
+            Country::BR => "BR",
+            Country::BY => "BY",
+            Country::CH => "CH",
+            Country::CR => "CR",
+            Country::CY => "CY",
+            Country::CZ => "CZ",
+            Country::DE => "DE",
+            Country::DJ => "DJ",
+            Country::DK => "DK",
+            Country::DO => "DO",
+            Country::EE => "EE",
+            Country::EG => "EG",
+            Country::ES => "ES",
+            Country::FI => "FI",
+            Country::FK => "FK",
+            Country::FO => "FO",
+            Country::FR => "FR",
+            Country::GB => "GB",
+            Country::GE => "GE",
+            Country::GI => "GI",
+            Country::GL => "GL",
+            Country::GR => "GR",
+            Country::GT => "GT",
+            Country::HR => "HR",
+            Country::HU => "HU",
+            Country::IE => "IE",
+            Country::IL => "IL",
+            Country::IQ => "IQ",
+            Country::IS => "IS",
+            Country::IT => "IT",
+            Country::JO => "JO",
+            Country::KW => "KW",
+            Country::KZ => "KZ",
+            Country::LB => "LB",
+            Country::LC => "LC",
+            Country::LI => "LI",
+            Country::LT => "LT",
+            Country::LU => "LU",
+            Country::LV => "LV",
+            Country::LY => "LY",
+            Country::MC => "MC",
+            Country::MD => "MD",
+            Country::ME => "ME",
+            Country::MK => "MK",
+            Country::MN => "MN",
+            Country::MR => "MR",
+            Country::MT => "MT",
+            Country::MU => "MU",
+            Country::NI => "NI",
+            Country::NL => "NL",
+            Country::NO => "NO",
+            Country::OM => "OM",
+            Country::PL => "PL",
+            Country::PS => "PS",
+            Country::PT => "PT",
+            Country::QA => "QA",
+            Country::RO => "RO",
+            Country::RS => "RS",
+            Country::RU => "RU",
+            Country::SA => "SA",
+            Country::SC => "SC",
+            Country::SD => "SD",
+            Country::SE => "SE",
+            Country::SI => "SI",
+            Country::SK => "SK",
+            Country::SM => "SM",
+            Country::SO => "SO",
+            Country::ST => "ST",
+            Country::SV => "SV",
+            Country::TL => "TL",
+            Country::TN => "TN",
+            Country::TR => "TR",
+            Country::UA => "UA",
+            Country::VA => "VA",
+            Country::VG => "VG",
+            Country::XK => "XK",
+            Country::YE => "YE",
+            Country::Other(country_code) => country_code.as_str(),
+        }
+    }
+
+    /// Get the country in the registry with the given country code.
+    #[inline]
+    pub(crate) fn from_registry_code(country_code: &str) -> Option<Country> {
+        match country_code {
+            "AD" => Some(Country::AD),
+            "AE" => Some(Country::AE),
+            "AL" => Some(Country::AL),
+            "AT" => Some(Country::AT),
+            "AZ" => Some(Country::AZ),
+            "BA" => Some(Country::BA),
+            "BE" => Some(Country::BE),
+            "BG" => Some(Country::BG),
+            "BH" => Some(Country::BH),
+            "BI" => Some(Country::BI),
+            "BR" => Some(Country::BR),
+            "BY" => Some(Country::BY),
+            "CH" => Some(Country::CH),
+            "CR" => Some(Country::CR),
+            "CY" => Some(Country::CY),
+            "CZ" => Some(Country::CZ),
+            "DE" => Some(Country::DE),
+            "DJ" => Some(Country::DJ),
+            "DK" => Some(Country::DK),
+            "DO" => Some(Country::DO),
+            "EE" => Some(Country::EE),
+            "EG" => Some(Country::EG),
+            "ES" => Some(Country::ES),
+            "FI" => Some(Country::FI),
+            "FK" => Some(Country::FK),
+            "FO" => Some(Country::FO),
+            "FR" => Some(Country::FR),
+            "GB" => Some(Country::GB),
+            "GE" => Some(Country::GE),
+            "GI" => Some(Country::GI),
+            "GL" => Some(Country::GL),
+            "GR" => Some(Country::GR),
+            "GT" => Some(Country::GT),
+            "HR" => Some(Country::HR),
+            "HU" => Some(Country::HU),
+            "IE" => Some(Country::IE),
+            "IL" => Some(Country::IL),
+            "IQ" => Some(Country::IQ),
+            "IS" => Some(Country::IS),
+            "IT" => Some(Country::IT),
+            "JO" => Some(Country::JO),
+            "KW" => Some(Country::KW),
+            "KZ" => Some(Country::KZ),
+            "LB" => Some(Country::LB),
+            "LC" => Some(Country::LC),
+            "LI" => Some(Country::LI),
+            "LT" => Some(Country::LT),
+            "LU" => Some(Country::LU),
+            "LV" => Some(Country::LV),
+            "LY" => Some(Country::LY),
+            "MC" => Some(Country::MC),
+            "MD" => Some(Country::MD),
+            "ME" => Some(Country::ME),
+            "MK" => Some(Country::MK),
+            "MN" => Some(Country::MN),
+            "MR" => Some(Country::MR),
+            "MT" => Some(Country::MT),
+            "MU" => Some(Country::MU),
+            "NI" => Some(Country::NI),
+            "NL" => Some(Country::NL),
+            "NO" => Some(Country::NO),
+            "OM" => Some(Country::OM),
+            "PL" => Some(Country::PL),
+            "PS" => Some(Country::PS),
+            "PT" => Some(Country::PT),
+            "QA" => Some(Country::QA),
+            "RO" => Some(Country::RO),
+            "RS" => Some(Country::RS),
+            "RU" => Some(Country::RU),
+            "SA" => Some(Country::SA),
+            "SC" => Some(Country::SC),
+            "SD" => Some(Country::SD),
+            "SE" => Some(Country::SE),
+            "SI" => Some(Country::SI),
+            "SK" => Some(Country::SK),
+            "SM" => Some(Country::SM),
+            "SO" => Some(Country::SO),
+            "ST" => Some(Country::ST),
+            "SV" => Some(Country::SV),
+            "TL" => Some(Country::TL),
+            "TN" => Some(Country::TN),
+            "TR" => Some(Country::TR),
+            "UA" => Some(Country::UA),
+            "VA" => Some(Country::VA),
+            "VG" => Some(Country::VG),
+            "XK" => Some(Country::XK),
+            "YE" => Some(Country::YE),
+            _ => None,
+        }
+    }
+}
+
 /// Get the position of the bank in the BBAN.
 #[inline]
 pub(crate) fn bank_identifier(country_code: &str) -> Option<core::ops::Range<usize>> {
@@ -192,10 +669,10 @@ pub(crate) fn branch_identifier(country_code: &str) -> Option<core::ops::Range<u
     }
 }
 
-use crate::countries::CharacterType;
+use crate::countries::{CharacterType, HistoricalFormat};
 
 #[inline]
-pub(crate) fn country_pattern(country_code: &str) -> Option<&[(usize, CharacterType)]> {
+pub(crate) fn country_pattern(country_code: &str) -> Option<&'static [(usize, CharacterType)]> {
     use core::borrow::Borrow;
     use CharacterType::{A, C, N};
     match country_code {
@@ -289,3 +766,2590 @@ pub(crate) fn country_pattern(country_code: &str) -> Option<&[(usize, CharacterT
         _ => None,
     }
 }
+
+use crate::{CountryInfo, RegistryDate};
+
+/// Get the information about a country in the registry.
+#[inline]
+pub(crate) fn country_info(country_code: &str) -> Option<&'static CountryInfo> {
+    match country_code {
+        "AD" => Some(&CountryInfo {
+            country: Country::AD,
+            name: "Andorra",
+            iban_length: 24,
+            bban_length: 20,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "AD1200012030200359100100",
+        }),
+        "AE" => Some(&CountryInfo {
+            country: Country::AE,
+            name: "United Arab Emirates (The)",
+            iban_length: 23,
+            bban_length: 19,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2011,
+                month: 10,
+            },
+            example: "AE070331234567890123456",
+        }),
+        "AL" => Some(&CountryInfo {
+            country: Country::AL,
+            name: "Albania",
+            iban_length: 28,
+            bban_length: 24,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2009,
+                month: 4,
+            },
+            example: "AL47212110090000000235698741",
+        }),
+        "AT" => Some(&CountryInfo {
+            country: Country::AT,
+            name: "Austria",
+            iban_length: 20,
+            bban_length: 16,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "AT611904300234573201",
+        }),
+        "AZ" => Some(&CountryInfo {
+            country: Country::AZ,
+            name: "Azerbaijan",
+            iban_length: 28,
+            bban_length: 24,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2013,
+                month: 1,
+            },
+            example: "AZ21NABZ00000000137010001944",
+        }),
+        "BA" => Some(&CountryInfo {
+            country: Country::BA,
+            name: "Bosnia and Herzegovina",
+            iban_length: 20,
+            bban_length: 16,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "BA391290079401028494",
+        }),
+        "BE" => Some(&CountryInfo {
+            country: Country::BE,
+            name: "Belgium",
+            iban_length: 16,
+            bban_length: 12,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "BE68539007547034",
+        }),
+        "BG" => Some(&CountryInfo {
+            country: Country::BG,
+            name: "Bulgaria",
+            iban_length: 22,
+            bban_length: 18,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "BG80BNBG96611020345678",
+        }),
+        "BH" => Some(&CountryInfo {
+            country: Country::BH,
+            name: "Bahrain",
+            iban_length: 22,
+            bban_length: 18,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2012,
+                month: 1,
+            },
+            example: "BH67BMAG00001299123456",
+        }),
+        "BI" => Some(&CountryInfo {
+            country: Country::BI,
+            name: "Burundi",
+            iban_length: 27,
+            bban_length: 23,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2021,
+                month: 10,
+            },
+            example: "BI4210000100010000332045181",
+        }),
+        "BR" => Some(&CountryInfo {
+            country: Country::BR,
+            name: "Brazil",
+            iban_length: 29,
+            bban_length: 25,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2013,
+                month: 7,
+            },
+            example: "BR1800360305000010009795493C1",
+        }),
+        "BY" => Some(&CountryInfo {
+            country: Country::BY,
+            name: "Republic of Belarus",
+            iban_length: 28,
+            bban_length: 24,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2017,
+                month: 7,
+            },
+            example: "BY13NBRB3600900000002Z00AB00",
+        }),
+        "CH" => Some(&CountryInfo {
+            country: Country::CH,
+            name: "Switzerland",
+            iban_length: 21,
+            bban_length: 17,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "CH9300762011623852957",
+        }),
+        "CR" => Some(&CountryInfo {
+            country: Country::CR,
+            name: "Costa Rica",
+            iban_length: 22,
+            bban_length: 18,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2011,
+                month: 6,
+            },
+            example: "CR05015202001026284066",
+        }),
+        "CY" => Some(&CountryInfo {
+            country: Country::CY,
+            name: "Cyprus",
+            iban_length: 28,
+            bban_length: 24,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "CY17002001280000001200527600",
+        }),
+        "CZ" => Some(&CountryInfo {
+            country: Country::CZ,
+            name: "Czechia",
+            iban_length: 24,
+            bban_length: 20,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "CZ6508000000192000145399",
+        }),
+        "DE" => Some(&CountryInfo {
+            country: Country::DE,
+            name: "Germany",
+            iban_length: 22,
+            bban_length: 18,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "DE89370400440532013000",
+        }),
+        "DJ" => Some(&CountryInfo {
+            country: Country::DJ,
+            name: "Djibouti",
+            iban_length: 27,
+            bban_length: 23,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2022,
+                month: 5,
+            },
+            example: "DJ2100010000000154000100186",
+        }),
+        "DK" => Some(&CountryInfo {
+            country: Country::DK,
+            name: "Denmark",
+            iban_length: 18,
+            bban_length: 14,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "DK5000400440116243",
+        }),
+        "DO" => Some(&CountryInfo {
+            country: Country::DO,
+            name: "Dominican Republic",
+            iban_length: 28,
+            bban_length: 24,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2010,
+                month: 12,
+            },
+            example: "DO28BAGR00000001212453611324",
+        }),
+        "EE" => Some(&CountryInfo {
+            country: Country::EE,
+            name: "Estonia",
+            iban_length: 20,
+            bban_length: 16,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "EE382200221020145685",
+        }),
+        "EG" => Some(&CountryInfo {
+            country: Country::EG,
+            name: "Egypt",
+            iban_length: 29,
+            bban_length: 25,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2020,
+                month: 4,
+            },
+            example: "EG380019000500000000263180002",
+        }),
+        "ES" => Some(&CountryInfo {
+            country: Country::ES,
+            name: "Spain",
+            iban_length: 24,
+            bban_length: 20,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "ES9121000418450200051332",
+        }),
+        "FI" => Some(&CountryInfo {
+            country: Country::FI,
+            name: "Finland",
+            iban_length: 18,
+            bban_length: 14,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2011,
+                month: 12,
+            },
+            example: "FI2112345600000785",
+        }),
+        "FK" => Some(&CountryInfo {
+            country: Country::FK,
+            name: "Falkland Islands",
+            iban_length: 18,
+            bban_length: 14,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2023,
+                month: 7,
+            },
+            example: "FK88SC123456789012",
+        }),
+        "FO" => Some(&CountryInfo {
+            country: Country::FO,
+            name: "Faroe Islands",
+            iban_length: 18,
+            bban_length: 14,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "FO6264600001631634",
+        }),
+        "FR" => Some(&CountryInfo {
+            country: Country::FR,
+            name: "France",
+            iban_length: 27,
+            bban_length: 23,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "FR1420041010050500013M02606",
+        }),
+        "GB" => Some(&CountryInfo {
+            country: Country::GB,
+            name: "United Kingdom",
+            iban_length: 22,
+            bban_length: 18,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "GB29NWBK60161331926819",
+        }),
+        "GE" => Some(&CountryInfo {
+            country: Country::GE,
+            name: "Georgia",
+            iban_length: 22,
+            bban_length: 18,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2010,
+                month: 5,
+            },
+            example: "GE29NB0000000101904917",
+        }),
+        "GI" => Some(&CountryInfo {
+            country: Country::GI,
+            name: "Gibraltar",
+            iban_length: 23,
+            bban_length: 19,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "GI75NWBK000000007099453",
+        }),
+        "GL" => Some(&CountryInfo {
+            country: Country::GL,
+            name: "Greenland",
+            iban_length: 18,
+            bban_length: 14,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "GL8964710001000206",
+        }),
+        "GR" => Some(&CountryInfo {
+            country: Country::GR,
+            name: "Greece",
+            iban_length: 27,
+            bban_length: 23,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "GR1601101250000000012300695",
+        }),
+        "GT" => Some(&CountryInfo {
+            country: Country::GT,
+            name: "Guatemala",
+            iban_length: 28,
+            bban_length: 24,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2016,
+                month: 8,
+            },
+            example: "GT82TRAJ01020000001210029690",
+        }),
+        "HR" => Some(&CountryInfo {
+            country: Country::HR,
+            name: "Croatia",
+            iban_length: 21,
+            bban_length: 17,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "HR1210010051863000160",
+        }),
+        "HU" => Some(&CountryInfo {
+            country: Country::HU,
+            name: "Hungary",
+            iban_length: 28,
+            bban_length: 24,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "HU42117730161111101800000000",
+        }),
+        "IE" => Some(&CountryInfo {
+            country: Country::IE,
+            name: "Ireland",
+            iban_length: 22,
+            bban_length: 18,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "IE29AIBK93115212345678",
+        }),
+        "IL" => Some(&CountryInfo {
+            country: Country::IL,
+            name: "Israel",
+            iban_length: 23,
+            bban_length: 19,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 7,
+            },
+            example: "IL620108000000099999999",
+        }),
+        "IQ" => Some(&CountryInfo {
+            country: Country::IQ,
+            name: "Iraq",
+            iban_length: 23,
+            bban_length: 19,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2017,
+                month: 1,
+            },
+            example: "IQ98NBIQ850123456789012",
+        }),
+        "IS" => Some(&CountryInfo {
+            country: Country::IS,
+            name: "Iceland",
+            iban_length: 26,
+            bban_length: 22,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "IS140159260076545510730339",
+        }),
+        "IT" => Some(&CountryInfo {
+            country: Country::IT,
+            name: "Italy",
+            iban_length: 27,
+            bban_length: 23,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "IT60X0542811101000000123456",
+        }),
+        "JO" => Some(&CountryInfo {
+            country: Country::JO,
+            name: "Jordan",
+            iban_length: 30,
+            bban_length: 26,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2014,
+                month: 2,
+            },
+            example: "JO94CBJO0010000000000131000302",
+        }),
+        "KW" => Some(&CountryInfo {
+            country: Country::KW,
+            name: "Kuwait",
+            iban_length: 30,
+            bban_length: 26,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2011,
+                month: 1,
+            },
+            example: "KW81CBKU0000000000001234560101",
+        }),
+        "KZ" => Some(&CountryInfo {
+            country: Country::KZ,
+            name: "Kazakhstan",
+            iban_length: 20,
+            bban_length: 16,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2010,
+                month: 9,
+            },
+            example: "KZ86125KZT5004100100",
+        }),
+        "LB" => Some(&CountryInfo {
+            country: Country::LB,
+            name: "Lebanon",
+            iban_length: 28,
+            bban_length: 24,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "LB62099900000001001901229114",
+        }),
+        "LC" => Some(&CountryInfo {
+            country: Country::LC,
+            name: "Saint Lucia",
+            iban_length: 32,
+            bban_length: 28,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2016,
+                month: 6,
+            },
+            example: "LC55HEMM000100010012001200023015",
+        }),
+        "LI" => Some(&CountryInfo {
+            country: Country::LI,
+            name: "Liechtenstein",
+            iban_length: 21,
+            bban_length: 17,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "LI21088100002324013AA",
+        }),
+        "LT" => Some(&CountryInfo {
+            country: Country::LT,
+            name: "Lithuania",
+            iban_length: 20,
+            bban_length: 16,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "LT121000011101001000",
+        }),
+        "LU" => Some(&CountryInfo {
+            country: Country::LU,
+            name: "Luxembourg",
+            iban_length: 20,
+            bban_length: 16,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "LU280019400644750000",
+        }),
+        "LV" => Some(&CountryInfo {
+            country: Country::LV,
+            name: "Latvia",
+            iban_length: 21,
+            bban_length: 17,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "LV80BANK0000435195001",
+        }),
+        "LY" => Some(&CountryInfo {
+            country: Country::LY,
+            name: "Libya",
+            iban_length: 25,
+            bban_length: 21,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2020,
+                month: 10,
+            },
+            example: "LY83002048000020100120361",
+        }),
+        "MC" => Some(&CountryInfo {
+            country: Country::MC,
+            name: "Monaco",
+            iban_length: 27,
+            bban_length: 23,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "MC5811222000010123456789030",
+        }),
+        "MD" => Some(&CountryInfo {
+            country: Country::MD,
+            name: "Moldova",
+            iban_length: 24,
+            bban_length: 20,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2011,
+                month: 12,
+            },
+            example: "MD24AG000225100013104168",
+        }),
+        "ME" => Some(&CountryInfo {
+            country: Country::ME,
+            name: "Montenegro",
+            iban_length: 22,
+            bban_length: 18,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "ME25505000012345678951",
+        }),
+        "MK" => Some(&CountryInfo {
+            country: Country::MK,
+            name: "Macedonia",
+            iban_length: 19,
+            bban_length: 15,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "MK07250120000058984",
+        }),
+        "MN" => Some(&CountryInfo {
+            country: Country::MN,
+            name: "Mongolia",
+            iban_length: 20,
+            bban_length: 16,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2023,
+                month: 4,
+            },
+            example: "MN121234123456789123",
+        }),
+        "MR" => Some(&CountryInfo {
+            country: Country::MR,
+            name: "Mauritania",
+            iban_length: 27,
+            bban_length: 23,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "MR1300020001010000123456753",
+        }),
+        "MT" => Some(&CountryInfo {
+            country: Country::MT,
+            name: "Malta",
+            iban_length: 31,
+            bban_length: 27,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "MT84MALT011000012345MTLCAST001S",
+        }),
+        "MU" => Some(&CountryInfo {
+            country: Country::MU,
+            name: "Mauritius",
+            iban_length: 30,
+            bban_length: 26,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "MU17BOMM0101101030300200000MUR",
+        }),
+        "NI" => Some(&CountryInfo {
+            country: Country::NI,
+            name: "Nicaragua",
+            iban_length: 28,
+            bban_length: 24,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2023,
+                month: 1,
+            },
+            example: "NI45BAPR00000013000003558124",
+        }),
+        "NL" => Some(&CountryInfo {
+            country: Country::NL,
+            name: "Netherlands (The)",
+            iban_length: 18,
+            bban_length: 14,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "NL91ABNA0417164300",
+        }),
+        "NO" => Some(&CountryInfo {
+            country: Country::NO,
+            name: "Norway",
+            iban_length: 15,
+            bban_length: 11,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "NO9386011117947",
+        }),
+        "OM" => Some(&CountryInfo {
+            country: Country::OM,
+            name: "Oman",
+            iban_length: 23,
+            bban_length: 19,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2024,
+                month: 3,
+            },
+            example: "OM810180000001299123456",
+        }),
+        "PL" => Some(&CountryInfo {
+            country: Country::PL,
+            name: "Poland",
+            iban_length: 28,
+            bban_length: 24,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "PL61109010140000071219812874",
+        }),
+        "PS" => Some(&CountryInfo {
+            country: Country::PS,
+            name: "Palestine, State of",
+            iban_length: 29,
+            bban_length: 25,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2012,
+                month: 7,
+            },
+            example: "PS92PALS000000000400123456702",
+        }),
+        "PT" => Some(&CountryInfo {
+            country: Country::PT,
+            name: "Portugal",
+            iban_length: 25,
+            bban_length: 21,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "PT50000201231234567890154",
+        }),
+        "QA" => Some(&CountryInfo {
+            country: Country::QA,
+            name: "Qatar",
+            iban_length: 29,
+            bban_length: 25,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2014,
+                month: 1,
+            },
+            example: "QA58DOHB00001234567890ABCDEFG",
+        }),
+        "RO" => Some(&CountryInfo {
+            country: Country::RO,
+            name: "Romania",
+            iban_length: 24,
+            bban_length: 20,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "RO49AAAA1B31007593840000",
+        }),
+        "RS" => Some(&CountryInfo {
+            country: Country::RS,
+            name: "Serbia",
+            iban_length: 22,
+            bban_length: 18,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "RS35260005601001611379",
+        }),
+        "RU" => Some(&CountryInfo {
+            country: Country::RU,
+            name: "Russia",
+            iban_length: 33,
+            bban_length: 29,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2021,
+                month: 10,
+            },
+            example: "RU0304452522540817810538091310419",
+        }),
+        "SA" => Some(&CountryInfo {
+            country: Country::SA,
+            name: "Saudi Arabia",
+            iban_length: 24,
+            bban_length: 20,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2016,
+                month: 7,
+            },
+            example: "SA0380000000608010167519",
+        }),
+        "SC" => Some(&CountryInfo {
+            country: Country::SC,
+            name: "Seychelles",
+            iban_length: 31,
+            bban_length: 27,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2016,
+                month: 7,
+            },
+            example: "SC18SSCB11010000000000001497USD",
+        }),
+        "SD" => Some(&CountryInfo {
+            country: Country::SD,
+            name: "Sudan",
+            iban_length: 18,
+            bban_length: 14,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2021,
+                month: 7,
+            },
+            example: "SD2129010501234001",
+        }),
+        "SE" => Some(&CountryInfo {
+            country: Country::SE,
+            name: "Sweden",
+            iban_length: 24,
+            bban_length: 20,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "SE4550000000058398257466",
+        }),
+        "SI" => Some(&CountryInfo {
+            country: Country::SI,
+            name: "Slovenia",
+            iban_length: 19,
+            bban_length: 15,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "SI56263300012039086",
+        }),
+        "SK" => Some(&CountryInfo {
+            country: Country::SK,
+            name: "Slovakia",
+            iban_length: 24,
+            bban_length: 20,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "SK3112000000198742637541",
+        }),
+        "SM" => Some(&CountryInfo {
+            country: Country::SM,
+            name: "San Marino",
+            iban_length: 27,
+            bban_length: 23,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "SM86U0322509800000000270100",
+        }),
+        "SO" => Some(&CountryInfo {
+            country: Country::SO,
+            name: "Somalia",
+            iban_length: 23,
+            bban_length: 19,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2023,
+                month: 2,
+            },
+            example: "SO211000001001000100141",
+        }),
+        "ST" => Some(&CountryInfo {
+            country: Country::ST,
+            name: "Sao Tome and Principe",
+            iban_length: 25,
+            bban_length: 21,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2015,
+                month: 9,
+            },
+            example: "ST32000200010192194210112",
+        }),
+        "SV" => Some(&CountryInfo {
+            country: Country::SV,
+            name: "El Salvador",
+            iban_length: 28,
+            bban_length: 24,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2016,
+                month: 10,
+            },
+            example: "SV62CENR00000000000000700025",
+        }),
+        "TL" => Some(&CountryInfo {
+            country: Country::TL,
+            name: "Timor-Leste",
+            iban_length: 23,
+            bban_length: 19,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2014,
+                month: 9,
+            },
+            example: "TL380080012345678910157",
+        }),
+        "TN" => Some(&CountryInfo {
+            country: Country::TN,
+            name: "Tunisia",
+            iban_length: 24,
+            bban_length: 20,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "TN5910006035183598478831",
+        }),
+        "TR" => Some(&CountryInfo {
+            country: Country::TR,
+            name: "Turkey",
+            iban_length: 26,
+            bban_length: 22,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2007,
+                month: 4,
+            },
+            example: "TR330006100519786457841326",
+        }),
+        "UA" => Some(&CountryInfo {
+            country: Country::UA,
+            name: "Ukraine",
+            iban_length: 29,
+            bban_length: 25,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2016,
+                month: 2,
+            },
+            example: "UA213223130000026007233566001",
+        }),
+        "VA" => Some(&CountryInfo {
+            country: Country::VA,
+            name: "Vatican City State",
+            iban_length: 22,
+            bban_length: 18,
+            sepa: true,
+            effective_date: RegistryDate {
+                year: 2019,
+                month: 9,
+            },
+            example: "VA59001123000012345678",
+        }),
+        "VG" => Some(&CountryInfo {
+            country: Country::VG,
+            name: "Virgin Islands",
+            iban_length: 24,
+            bban_length: 20,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2012,
+                month: 4,
+            },
+            example: "VG96VPVG0000012345678901",
+        }),
+        "XK" => Some(&CountryInfo {
+            country: Country::XK,
+            name: "Kosovo",
+            iban_length: 20,
+            bban_length: 16,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2014,
+                month: 9,
+            },
+            example: "XK051212012345678906",
+        }),
+        "YE" => Some(&CountryInfo {
+            country: Country::YE,
+            name: "Yemen",
+            iban_length: 30,
+            bban_length: 26,
+            sepa: false,
+            effective_date: RegistryDate {
+                year: 2025,
+                month: 1,
+            },
+            example: "YE15CBYE0001018861234567891234",
+        }),
+        _ => None,
+    }
+}
+
+/// Get the BBAN formats that preceded the current format, together with
+/// the date from which they were no longer in effect and the components of
+/// the BBAN. The most recent format comes first.
+#[inline]
+pub(crate) fn historical_country_patterns(country_code: &str) -> &'static [HistoricalFormat] {
+    #[allow(clippy::match_same_arms)] // For clarity, identical arms are not combined.
+    match country_code {
+        "CR" => &[(
+            RegistryDate {
+                year: 2017,
+                month: 7,
+            },
+            &[(3, CharacterType::N), (14, CharacterType::N)],
+            &[
+                (ComponentKind::BankIdentifier, 0..3),
+                (ComponentKind::AccountNumber, 3..17),
+            ],
+        )],
+        _ => &[],
+    }
+}
+
+/// Get the country whose format is used by a dependent territory.
+#[inline]
+pub(crate) fn parent_country(country_code: &str) -> Option<Country> {
+    #[allow(clippy::match_same_arms)] // For clarity, identical arms are not combined.
+    match country_code {
+        "AX" => Some(Country::FI),
+        "BL" => Some(Country::FR),
+        "GF" => Some(Country::FR),
+        "GG" => Some(Country::GB),
+        "GP" => Some(Country::FR),
+        "IM" => Some(Country::GB),
+        "JE" => Some(Country::GB),
+        "MF" => Some(Country::FR),
+        "MQ" => Some(Country::FR),
+        "NC" => Some(Country::FR),
+        "PF" => Some(Country::FR),
+        "PM" => Some(Country::FR),
+        "RE" => Some(Country::FR),
+        "TF" => Some(Country::FR),
+        "WF" => Some(Country::FR),
+        "YT" => Some(Country::FR),
+        _ => None,
+    }
+}
+
+use crate::ComponentKind;
+
+/// Get the components of the BBAN of a country, ordered by their position.
+#[inline]
+pub(crate) fn components(
+    country_code: &str,
+) -> &'static [(ComponentKind, core::ops::Range<usize>)] {
+    #[allow(clippy::match_same_arms)] // For clarity, identical arms are not combined.
+    match country_code {
+        "AD" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::BranchIdentifier, 4..8),
+            (ComponentKind::AccountNumber, 8..20),
+        ],
+        "AE" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::AccountNumber, 3..19),
+        ],
+        "AL" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::BranchIdentifier, 3..7),
+            (ComponentKind::NationalCheckDigits, 7..8),
+            (ComponentKind::AccountNumber, 8..24),
+        ],
+        "AT" => &[
+            (ComponentKind::BankIdentifier, 0..5),
+            (ComponentKind::AccountNumber, 5..16),
+        ],
+        "AZ" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..24),
+        ],
+        "BA" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::BranchIdentifier, 3..6),
+            (ComponentKind::AccountNumber, 6..14),
+            (ComponentKind::NationalCheckDigits, 14..16),
+        ],
+        "BE" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::AccountNumber, 3..10),
+            (ComponentKind::NationalCheckDigits, 10..12),
+        ],
+        "BG" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::BranchIdentifier, 4..8),
+            (ComponentKind::AccountType, 8..10),
+            (ComponentKind::AccountNumber, 10..18),
+        ],
+        "BH" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..18),
+        ],
+        "BI" => &[
+            (ComponentKind::BankIdentifier, 0..5),
+            (ComponentKind::BranchIdentifier, 5..10),
+            (ComponentKind::AccountNumber, 10..21),
+            (ComponentKind::NationalCheckDigits, 21..23),
+        ],
+        "BR" => &[
+            (ComponentKind::BankIdentifier, 0..8),
+            (ComponentKind::BranchIdentifier, 8..13),
+            (ComponentKind::AccountNumber, 13..23),
+            (ComponentKind::AccountType, 23..24),
+            (ComponentKind::AccountNumber, 24..25),
+        ],
+        "BY" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..24),
+        ],
+        "CH" => &[
+            (ComponentKind::BankIdentifier, 0..5),
+            (ComponentKind::AccountNumber, 5..17),
+        ],
+        "CR" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..18),
+        ],
+        "CY" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::BranchIdentifier, 3..8),
+            (ComponentKind::AccountNumber, 8..24),
+        ],
+        "CZ" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..20),
+        ],
+        "DE" => &[
+            (ComponentKind::BankIdentifier, 0..8),
+            (ComponentKind::AccountNumber, 8..18),
+        ],
+        "DJ" => &[
+            (ComponentKind::BankIdentifier, 0..5),
+            (ComponentKind::BranchIdentifier, 5..10),
+            (ComponentKind::AccountNumber, 10..21),
+            (ComponentKind::NationalCheckDigits, 21..23),
+        ],
+        "DK" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..13),
+            (ComponentKind::NationalCheckDigits, 13..14),
+        ],
+        "DO" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..24),
+        ],
+        "EE" => &[
+            (ComponentKind::BankIdentifier, 0..2),
+            (ComponentKind::AccountNumber, 2..15),
+            (ComponentKind::NationalCheckDigits, 15..16),
+        ],
+        "EG" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::BranchIdentifier, 4..8),
+            (ComponentKind::AccountNumber, 8..25),
+        ],
+        "ES" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::BranchIdentifier, 4..8),
+            (ComponentKind::NationalCheckDigits, 8..10),
+            (ComponentKind::AccountNumber, 10..20),
+        ],
+        "FI" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::AccountNumber, 3..13),
+            (ComponentKind::NationalCheckDigits, 13..14),
+        ],
+        "FK" => &[
+            (ComponentKind::BankIdentifier, 0..2),
+            (ComponentKind::AccountNumber, 2..14),
+        ],
+        "FO" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..13),
+            (ComponentKind::NationalCheckDigits, 13..14),
+        ],
+        "FR" => &[
+            (ComponentKind::BankIdentifier, 0..5),
+            (ComponentKind::BranchIdentifier, 5..10),
+            (ComponentKind::AccountNumber, 10..21),
+            (ComponentKind::NationalCheckDigits, 21..23),
+        ],
+        "GB" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::BranchIdentifier, 4..10),
+            (ComponentKind::AccountNumber, 10..18),
+        ],
+        "GE" => &[
+            (ComponentKind::BankIdentifier, 0..2),
+            (ComponentKind::AccountNumber, 2..18),
+        ],
+        "GI" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..19),
+        ],
+        "GL" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..13),
+            (ComponentKind::NationalCheckDigits, 13..14),
+        ],
+        "GR" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::BranchIdentifier, 3..7),
+            (ComponentKind::AccountNumber, 7..23),
+        ],
+        "GT" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::Currency, 4..6),
+            (ComponentKind::AccountType, 6..8),
+            (ComponentKind::AccountNumber, 8..24),
+        ],
+        "HR" => &[
+            (ComponentKind::BankIdentifier, 0..7),
+            (ComponentKind::AccountNumber, 7..17),
+        ],
+        "HU" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::BranchIdentifier, 3..7),
+            (ComponentKind::NationalCheckDigits, 7..8),
+            (ComponentKind::AccountNumber, 8..23),
+            (ComponentKind::NationalCheckDigits, 23..24),
+        ],
+        "IE" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::BranchIdentifier, 4..10),
+            (ComponentKind::AccountNumber, 10..18),
+        ],
+        "IL" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::BranchIdentifier, 3..6),
+            (ComponentKind::AccountNumber, 6..19),
+        ],
+        "IQ" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::BranchIdentifier, 4..7),
+            (ComponentKind::AccountNumber, 7..19),
+        ],
+        "IS" => &[
+            (ComponentKind::BankIdentifier, 0..2),
+            (ComponentKind::BranchIdentifier, 2..4),
+            (ComponentKind::AccountType, 4..6),
+            (ComponentKind::AccountNumber, 6..22),
+        ],
+        "IT" => &[
+            (ComponentKind::NationalCheckDigits, 0..1),
+            (ComponentKind::BankIdentifier, 1..6),
+            (ComponentKind::BranchIdentifier, 6..11),
+            (ComponentKind::AccountNumber, 11..23),
+        ],
+        "JO" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::BranchIdentifier, 4..8),
+            (ComponentKind::AccountNumber, 8..26),
+        ],
+        "KW" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..26),
+        ],
+        "KZ" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::AccountNumber, 3..16),
+        ],
+        "LB" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..24),
+        ],
+        "LC" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..28),
+        ],
+        "LI" => &[
+            (ComponentKind::BankIdentifier, 0..5),
+            (ComponentKind::AccountNumber, 5..17),
+        ],
+        "LT" => &[
+            (ComponentKind::BankIdentifier, 0..5),
+            (ComponentKind::AccountNumber, 5..16),
+        ],
+        "LU" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::AccountNumber, 3..16),
+        ],
+        "LV" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..17),
+        ],
+        "LY" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::BranchIdentifier, 3..6),
+            (ComponentKind::AccountNumber, 6..21),
+        ],
+        "MC" => &[
+            (ComponentKind::BankIdentifier, 0..5),
+            (ComponentKind::BranchIdentifier, 5..10),
+            (ComponentKind::AccountNumber, 10..21),
+            (ComponentKind::NationalCheckDigits, 21..23),
+        ],
+        "MD" => &[
+            (ComponentKind::BankIdentifier, 0..2),
+            (ComponentKind::AccountNumber, 2..20),
+        ],
+        "ME" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::AccountNumber, 3..16),
+            (ComponentKind::NationalCheckDigits, 16..18),
+        ],
+        "MK" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::AccountNumber, 3..13),
+            (ComponentKind::NationalCheckDigits, 13..15),
+        ],
+        "MN" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..16),
+        ],
+        "MR" => &[
+            (ComponentKind::BankIdentifier, 0..5),
+            (ComponentKind::BranchIdentifier, 5..10),
+            (ComponentKind::AccountNumber, 10..21),
+            (ComponentKind::NationalCheckDigits, 21..23),
+        ],
+        "MT" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::BranchIdentifier, 4..9),
+            (ComponentKind::AccountNumber, 9..27),
+        ],
+        "MU" => &[
+            (ComponentKind::BankIdentifier, 0..6),
+            (ComponentKind::BranchIdentifier, 6..8),
+            (ComponentKind::AccountNumber, 8..23),
+            (ComponentKind::Currency, 23..26),
+        ],
+        "NI" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..24),
+        ],
+        "NL" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..14),
+        ],
+        "NO" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..10),
+            (ComponentKind::NationalCheckDigits, 10..11),
+        ],
+        "OM" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::AccountNumber, 3..19),
+        ],
+        "PL" => &[
+            (ComponentKind::BranchIdentifier, 0..8),
+            (ComponentKind::AccountNumber, 8..24),
+        ],
+        "PS" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..25),
+        ],
+        "PT" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::BranchIdentifier, 4..8),
+            (ComponentKind::AccountNumber, 8..19),
+            (ComponentKind::NationalCheckDigits, 19..21),
+        ],
+        "QA" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..25),
+        ],
+        "RO" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..20),
+        ],
+        "RS" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::AccountNumber, 3..16),
+            (ComponentKind::NationalCheckDigits, 16..18),
+        ],
+        "RU" => &[
+            (ComponentKind::BankIdentifier, 0..9),
+            (ComponentKind::BranchIdentifier, 9..14),
+            (ComponentKind::AccountNumber, 14..29),
+        ],
+        "SA" => &[
+            (ComponentKind::BankIdentifier, 0..2),
+            (ComponentKind::AccountNumber, 2..20),
+        ],
+        "SC" => &[
+            (ComponentKind::BankIdentifier, 0..6),
+            (ComponentKind::BranchIdentifier, 6..8),
+            (ComponentKind::AccountNumber, 8..24),
+            (ComponentKind::Currency, 24..27),
+        ],
+        "SD" => &[
+            (ComponentKind::BankIdentifier, 0..2),
+            (ComponentKind::AccountNumber, 2..14),
+        ],
+        "SE" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::AccountNumber, 3..19),
+            (ComponentKind::NationalCheckDigits, 19..20),
+        ],
+        "SI" => &[
+            (ComponentKind::BankIdentifier, 0..5),
+            (ComponentKind::AccountNumber, 5..13),
+            (ComponentKind::NationalCheckDigits, 13..15),
+        ],
+        "SK" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..20),
+        ],
+        "SM" => &[
+            (ComponentKind::NationalCheckDigits, 0..1),
+            (ComponentKind::BankIdentifier, 1..6),
+            (ComponentKind::BranchIdentifier, 6..11),
+            (ComponentKind::AccountNumber, 11..23),
+        ],
+        "SO" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::BranchIdentifier, 4..7),
+            (ComponentKind::AccountNumber, 7..19),
+        ],
+        "ST" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::BranchIdentifier, 4..8),
+            (ComponentKind::AccountNumber, 8..19),
+            (ComponentKind::NationalCheckDigits, 19..21),
+        ],
+        "SV" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..24),
+        ],
+        "TL" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::AccountNumber, 3..17),
+            (ComponentKind::NationalCheckDigits, 17..19),
+        ],
+        "TN" => &[
+            (ComponentKind::BankIdentifier, 0..2),
+            (ComponentKind::BranchIdentifier, 2..5),
+            (ComponentKind::AccountNumber, 5..18),
+            (ComponentKind::NationalCheckDigits, 18..20),
+        ],
+        "TR" => &[
+            (ComponentKind::BankIdentifier, 0..5),
+            (ComponentKind::AccountNumber, 5..22),
+        ],
+        "UA" => &[
+            (ComponentKind::BankIdentifier, 0..6),
+            (ComponentKind::AccountNumber, 6..25),
+        ],
+        "VA" => &[
+            (ComponentKind::BankIdentifier, 0..3),
+            (ComponentKind::AccountNumber, 3..18),
+        ],
+        "VG" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::AccountNumber, 4..20),
+        ],
+        "XK" => &[
+            (ComponentKind::BankIdentifier, 0..2),
+            (ComponentKind::BranchIdentifier, 2..4),
+            (ComponentKind::AccountNumber, 4..14),
+            (ComponentKind::NationalCheckDigits, 14..16),
+        ],
+        "YE" => &[
+            (ComponentKind::BankIdentifier, 0..4),
+            (ComponentKind::BranchIdentifier, 4..8),
+            (ComponentKind::AccountNumber, 8..26),
+        ],
+        _ => &[],
+    }
+}
+
+/// Types that represent a country of the IBAN registry at the type level,
+/// for use with [`TypedIban`](crate::TypedIban).
+pub mod markers {
+    use crate::{typed::Sealed, Country, CountryMarker};
+
+    /// The country marker of Andorra.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ad;
+
+    impl Sealed for Ad {}
+
+    impl CountryMarker for Ad {
+        const COUNTRY: Country = Country::AD;
+        const COUNTRY_CODE: &'static str = "AD";
+    }
+
+    /// The country marker of United Arab Emirates (The).
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ae;
+
+    impl Sealed for Ae {}
+
+    impl CountryMarker for Ae {
+        const COUNTRY: Country = Country::AE;
+        const COUNTRY_CODE: &'static str = "AE";
+    }
+
+    /// The country marker of Albania.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Al;
+
+    impl Sealed for Al {}
+
+    impl CountryMarker for Al {
+        const COUNTRY: Country = Country::AL;
+        const COUNTRY_CODE: &'static str = "AL";
+    }
+
+    /// The country marker of Austria.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct At;
+
+    impl Sealed for At {}
+
+    impl CountryMarker for At {
+        const COUNTRY: Country = Country::AT;
+        const COUNTRY_CODE: &'static str = "AT";
+    }
+
+    /// The country marker of Azerbaijan.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Az;
+
+    impl Sealed for Az {}
+
+    impl CountryMarker for Az {
+        const COUNTRY: Country = Country::AZ;
+        const COUNTRY_CODE: &'static str = "AZ";
+    }
+
+    /// The country marker of Bosnia and Herzegovina.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ba;
+
+    impl Sealed for Ba {}
+
+    impl CountryMarker for Ba {
+        const COUNTRY: Country = Country::BA;
+        const COUNTRY_CODE: &'static str = "BA";
+    }
+
+    /// The country marker of Belgium.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Be;
+
+    impl Sealed for Be {}
+
+    impl CountryMarker for Be {
+        const COUNTRY: Country = Country::BE;
+        const COUNTRY_CODE: &'static str = "BE";
+    }
+
+    /// The country marker of Bulgaria.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Bg;
+
+    impl Sealed for Bg {}
+
+    impl CountryMarker for Bg {
+        const COUNTRY: Country = Country::BG;
+        const COUNTRY_CODE: &'static str = "BG";
+    }
+
+    /// The country marker of Bahrain.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Bh;
+
+    impl Sealed for Bh {}
+
+    impl CountryMarker for Bh {
+        const COUNTRY: Country = Country::BH;
+        const COUNTRY_CODE: &'static str = "BH";
+    }
+
+    /// The country marker of Burundi.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Bi;
+
+    impl Sealed for Bi {}
+
+    impl CountryMarker for Bi {
+        const COUNTRY: Country = Country::BI;
+        const COUNTRY_CODE: &'static str = "BI";
+    }
+
+    /// The country marker of Brazil.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Br;
+
+    impl Sealed for Br {}
+
+    impl CountryMarker for Br {
+        const COUNTRY: Country = Country::BR;
+        const COUNTRY_CODE: &'static str = "BR";
+    }
+
+    /// The country marker of Republic of Belarus.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct By;
+
+    impl Sealed for By {}
+
+    impl CountryMarker for By {
+        const COUNTRY: Country = Country::BY;
+        const COUNTRY_CODE: &'static str = "BY";
+    }
+
+    /// The country marker of Switzerland.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ch;
+
+    impl Sealed for Ch {}
+
+    impl CountryMarker for Ch {
+        const COUNTRY: Country = Country::CH;
+        const COUNTRY_CODE: &'static str = "CH";
+    }
+
+    /// The country marker of Costa Rica.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Cr;
+
+    impl Sealed for Cr {}
+
+    impl CountryMarker for Cr {
+        const COUNTRY: Country = Country::CR;
+        const COUNTRY_CODE: &'static str = "CR";
+    }
+
+    /// The country marker of Cyprus.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Cy;
+
+    impl Sealed for Cy {}
+
+    impl CountryMarker for Cy {
+        const COUNTRY: Country = Country::CY;
+        const COUNTRY_CODE: &'static str = "CY";
+    }
+
+    /// The country marker of Czechia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Cz;
+
+    impl Sealed for Cz {}
+
+    impl CountryMarker for Cz {
+        const COUNTRY: Country = Country::CZ;
+        const COUNTRY_CODE: &'static str = "CZ";
+    }
+
+    /// The country marker of Germany.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct De;
+
+    impl Sealed for De {}
+
+    impl CountryMarker for De {
+        const COUNTRY: Country = Country::DE;
+        const COUNTRY_CODE: &'static str = "DE";
+    }
+
+    /// The country marker of Djibouti.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Dj;
+
+    impl Sealed for Dj {}
+
+    impl CountryMarker for Dj {
+        const COUNTRY: Country = Country::DJ;
+        const COUNTRY_CODE: &'static str = "DJ";
+    }
+
+    /// The country marker of Denmark.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Dk;
+
+    impl Sealed for Dk {}
+
+    impl CountryMarker for Dk {
+        const COUNTRY: Country = Country::DK;
+        const COUNTRY_CODE: &'static str = "DK";
+    }
+
+    /// The country marker of Dominican Republic.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Do;
+
+    impl Sealed for Do {}
+
+    impl CountryMarker for Do {
+        const COUNTRY: Country = Country::DO;
+        const COUNTRY_CODE: &'static str = "DO";
+    }
+
+    /// The country marker of Estonia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ee;
+
+    impl Sealed for Ee {}
+
+    impl CountryMarker for Ee {
+        const COUNTRY: Country = Country::EE;
+        const COUNTRY_CODE: &'static str = "EE";
+    }
+
+    /// The country marker of Egypt.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Eg;
+
+    impl Sealed for Eg {}
+
+    impl CountryMarker for Eg {
+        const COUNTRY: Country = Country::EG;
+        const COUNTRY_CODE: &'static str = "EG";
+    }
+
+    /// The country marker of Spain.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Es;
+
+    impl Sealed for Es {}
+
+    impl CountryMarker for Es {
+        const COUNTRY: Country = Country::ES;
+        const COUNTRY_CODE: &'static str = "ES";
+    }
+
+    /// The country marker of Finland.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Fi;
+
+    impl Sealed for Fi {}
+
+    impl CountryMarker for Fi {
+        const COUNTRY: Country = Country::FI;
+        const COUNTRY_CODE: &'static str = "FI";
+    }
+
+    /// The country marker of Falkland Islands.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Fk;
+
+    impl Sealed for Fk {}
+
+    impl CountryMarker for Fk {
+        const COUNTRY: Country = Country::FK;
+        const COUNTRY_CODE: &'static str = "FK";
+    }
+
+    /// The country marker of Faroe Islands.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Fo;
+
+    impl Sealed for Fo {}
+
+    impl CountryMarker for Fo {
+        const COUNTRY: Country = Country::FO;
+        const COUNTRY_CODE: &'static str = "FO";
+    }
+
+    /// The country marker of France.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Fr;
+
+    impl Sealed for Fr {}
+
+    impl CountryMarker for Fr {
+        const COUNTRY: Country = Country::FR;
+        const COUNTRY_CODE: &'static str = "FR";
+    }
+
+    /// The country marker of United Kingdom.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Gb;
+
+    impl Sealed for Gb {}
+
+    impl CountryMarker for Gb {
+        const COUNTRY: Country = Country::GB;
+        const COUNTRY_CODE: &'static str = "GB";
+    }
+
+    /// The country marker of Georgia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ge;
+
+    impl Sealed for Ge {}
+
+    impl CountryMarker for Ge {
+        const COUNTRY: Country = Country::GE;
+        const COUNTRY_CODE: &'static str = "GE";
+    }
+
+    /// The country marker of Gibraltar.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Gi;
+
+    impl Sealed for Gi {}
+
+    impl CountryMarker for Gi {
+        const COUNTRY: Country = Country::GI;
+        const COUNTRY_CODE: &'static str = "GI";
+    }
+
+    /// The country marker of Greenland.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Gl;
+
+    impl Sealed for Gl {}
+
+    impl CountryMarker for Gl {
+        const COUNTRY: Country = Country::GL;
+        const COUNTRY_CODE: &'static str = "GL";
+    }
+
+    /// The country marker of Greece.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Gr;
+
+    impl Sealed for Gr {}
+
+    impl CountryMarker for Gr {
+        const COUNTRY: Country = Country::GR;
+        const COUNTRY_CODE: &'static str = "GR";
+    }
+
+    /// The country marker of Guatemala.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Gt;
+
+    impl Sealed for Gt {}
+
+    impl CountryMarker for Gt {
+        const COUNTRY: Country = Country::GT;
+        const COUNTRY_CODE: &'static str = "GT";
+    }
+
+    /// The country marker of Croatia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Hr;
+
+    impl Sealed for Hr {}
+
+    impl CountryMarker for Hr {
+        const COUNTRY: Country = Country::HR;
+        const COUNTRY_CODE: &'static str = "HR";
+    }
+
+    /// The country marker of Hungary.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Hu;
+
+    impl Sealed for Hu {}
+
+    impl CountryMarker for Hu {
+        const COUNTRY: Country = Country::HU;
+        const COUNTRY_CODE: &'static str = "HU";
+    }
+
+    /// The country marker of Ireland.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ie;
+
+    impl Sealed for Ie {}
+
+    impl CountryMarker for Ie {
+        const COUNTRY: Country = Country::IE;
+        const COUNTRY_CODE: &'static str = "IE";
+    }
+
+    /// The country marker of Israel.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Il;
+
+    impl Sealed for Il {}
+
+    impl CountryMarker for Il {
+        const COUNTRY: Country = Country::IL;
+        const COUNTRY_CODE: &'static str = "IL";
+    }
+
+    /// The country marker of Iraq.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Iq;
+
+    impl Sealed for Iq {}
+
+    impl CountryMarker for Iq {
+        const COUNTRY: Country = Country::IQ;
+        const COUNTRY_CODE: &'static str = "IQ";
+    }
+
+    /// The country marker of Iceland.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Is;
+
+    impl Sealed for Is {}
+
+    impl CountryMarker for Is {
+        const COUNTRY: Country = Country::IS;
+        const COUNTRY_CODE: &'static str = "IS";
+    }
+
+    /// The country marker of Italy.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct It;
+
+    impl Sealed for It {}
+
+    impl CountryMarker for It {
+        const COUNTRY: Country = Country::IT;
+        const COUNTRY_CODE: &'static str = "IT";
+    }
+
+    /// The country marker of Jordan.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Jo;
+
+    impl Sealed for Jo {}
+
+    impl CountryMarker for Jo {
+        const COUNTRY: Country = Country::JO;
+        const COUNTRY_CODE: &'static str = "JO";
+    }
+
+    /// The country marker of Kuwait.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Kw;
+
+    impl Sealed for Kw {}
+
+    impl CountryMarker for Kw {
+        const COUNTRY: Country = Country::KW;
+        const COUNTRY_CODE: &'static str = "KW";
+    }
+
+    /// The country marker of Kazakhstan.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Kz;
+
+    impl Sealed for Kz {}
+
+    impl CountryMarker for Kz {
+        const COUNTRY: Country = Country::KZ;
+        const COUNTRY_CODE: &'static str = "KZ";
+    }
+
+    /// The country marker of Lebanon.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Lb;
+
+    impl Sealed for Lb {}
+
+    impl CountryMarker for Lb {
+        const COUNTRY: Country = Country::LB;
+        const COUNTRY_CODE: &'static str = "LB";
+    }
+
+    /// The country marker of Saint Lucia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Lc;
+
+    impl Sealed for Lc {}
+
+    impl CountryMarker for Lc {
+        const COUNTRY: Country = Country::LC;
+        const COUNTRY_CODE: &'static str = "LC";
+    }
+
+    /// The country marker of Liechtenstein.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Li;
+
+    impl Sealed for Li {}
+
+    impl CountryMarker for Li {
+        const COUNTRY: Country = Country::LI;
+        const COUNTRY_CODE: &'static str = "LI";
+    }
+
+    /// The country marker of Lithuania.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Lt;
+
+    impl Sealed for Lt {}
+
+    impl CountryMarker for Lt {
+        const COUNTRY: Country = Country::LT;
+        const COUNTRY_CODE: &'static str = "LT";
+    }
+
+    /// The country marker of Luxembourg.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Lu;
+
+    impl Sealed for Lu {}
+
+    impl CountryMarker for Lu {
+        const COUNTRY: Country = Country::LU;
+        const COUNTRY_CODE: &'static str = "LU";
+    }
+
+    /// The country marker of Latvia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Lv;
+
+    impl Sealed for Lv {}
+
+    impl CountryMarker for Lv {
+        const COUNTRY: Country = Country::LV;
+        const COUNTRY_CODE: &'static str = "LV";
+    }
+
+    /// The country marker of Libya.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ly;
+
+    impl Sealed for Ly {}
+
+    impl CountryMarker for Ly {
+        const COUNTRY: Country = Country::LY;
+        const COUNTRY_CODE: &'static str = "LY";
+    }
+
+    /// The country marker of Monaco.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Mc;
+
+    impl Sealed for Mc {}
+
+    impl CountryMarker for Mc {
+        const COUNTRY: Country = Country::MC;
+        const COUNTRY_CODE: &'static str = "MC";
+    }
+
+    /// The country marker of Moldova.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Md;
+
+    impl Sealed for Md {}
+
+    impl CountryMarker for Md {
+        const COUNTRY: Country = Country::MD;
+        const COUNTRY_CODE: &'static str = "MD";
+    }
+
+    /// The country marker of Montenegro.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Me;
+
+    impl Sealed for Me {}
+
+    impl CountryMarker for Me {
+        const COUNTRY: Country = Country::ME;
+        const COUNTRY_CODE: &'static str = "ME";
+    }
+
+    /// The country marker of Macedonia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Mk;
+
+    impl Sealed for Mk {}
+
+    impl CountryMarker for Mk {
+        const COUNTRY: Country = Country::MK;
+        const COUNTRY_CODE: &'static str = "MK";
+    }
+
+    /// The country marker of Mongolia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Mn;
+
+    impl Sealed for Mn {}
+
+    impl CountryMarker for Mn {
+        const COUNTRY: Country = Country::MN;
+        const COUNTRY_CODE: &'static str = "MN";
+    }
+
+    /// The country marker of Mauritania.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Mr;
+
+    impl Sealed for Mr {}
+
+    impl CountryMarker for Mr {
+        const COUNTRY: Country = Country::MR;
+        const COUNTRY_CODE: &'static str = "MR";
+    }
+
+    /// The country marker of Malta.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Mt;
+
+    impl Sealed for Mt {}
+
+    impl CountryMarker for Mt {
+        const COUNTRY: Country = Country::MT;
+        const COUNTRY_CODE: &'static str = "MT";
+    }
+
+    /// The country marker of Mauritius.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Mu;
+
+    impl Sealed for Mu {}
+
+    impl CountryMarker for Mu {
+        const COUNTRY: Country = Country::MU;
+        const COUNTRY_CODE: &'static str = "MU";
+    }
+
+    /// The country marker of Nicaragua.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ni;
+
+    impl Sealed for Ni {}
+
+    impl CountryMarker for Ni {
+        const COUNTRY: Country = Country::NI;
+        const COUNTRY_CODE: &'static str = "NI";
+    }
+
+    /// The country marker of Netherlands (The).
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Nl;
+
+    impl Sealed for Nl {}
+
+    impl CountryMarker for Nl {
+        const COUNTRY: Country = Country::NL;
+        const COUNTRY_CODE: &'static str = "NL";
+    }
+
+    /// The country marker of Norway.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct No;
+
+    impl Sealed for No {}
+
+    impl CountryMarker for No {
+        const COUNTRY: Country = Country::NO;
+        const COUNTRY_CODE: &'static str = "NO";
+    }
+
+    /// The country marker of Oman.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Om;
+
+    impl Sealed for Om {}
+
+    impl CountryMarker for Om {
+        const COUNTRY: Country = Country::OM;
+        const COUNTRY_CODE: &'static str = "OM";
+    }
+
+    /// The country marker of Poland.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Pl;
+
+    impl Sealed for Pl {}
+
+    impl CountryMarker for Pl {
+        const COUNTRY: Country = Country::PL;
+        const COUNTRY_CODE: &'static str = "PL";
+    }
+
+    /// The country marker of Palestine, State of.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ps;
+
+    impl Sealed for Ps {}
+
+    impl CountryMarker for Ps {
+        const COUNTRY: Country = Country::PS;
+        const COUNTRY_CODE: &'static str = "PS";
+    }
+
+    /// The country marker of Portugal.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Pt;
+
+    impl Sealed for Pt {}
+
+    impl CountryMarker for Pt {
+        const COUNTRY: Country = Country::PT;
+        const COUNTRY_CODE: &'static str = "PT";
+    }
+
+    /// The country marker of Qatar.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Qa;
+
+    impl Sealed for Qa {}
+
+    impl CountryMarker for Qa {
+        const COUNTRY: Country = Country::QA;
+        const COUNTRY_CODE: &'static str = "QA";
+    }
+
+    /// The country marker of Romania.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ro;
+
+    impl Sealed for Ro {}
+
+    impl CountryMarker for Ro {
+        const COUNTRY: Country = Country::RO;
+        const COUNTRY_CODE: &'static str = "RO";
+    }
+
+    /// The country marker of Serbia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Rs;
+
+    impl Sealed for Rs {}
+
+    impl CountryMarker for Rs {
+        const COUNTRY: Country = Country::RS;
+        const COUNTRY_CODE: &'static str = "RS";
+    }
+
+    /// The country marker of Russia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ru;
+
+    impl Sealed for Ru {}
+
+    impl CountryMarker for Ru {
+        const COUNTRY: Country = Country::RU;
+        const COUNTRY_CODE: &'static str = "RU";
+    }
+
+    /// The country marker of Saudi Arabia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Sa;
+
+    impl Sealed for Sa {}
+
+    impl CountryMarker for Sa {
+        const COUNTRY: Country = Country::SA;
+        const COUNTRY_CODE: &'static str = "SA";
+    }
+
+    /// The country marker of Seychelles.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Sc;
+
+    impl Sealed for Sc {}
+
+    impl CountryMarker for Sc {
+        const COUNTRY: Country = Country::SC;
+        const COUNTRY_CODE: &'static str = "SC";
+    }
+
+    /// The country marker of Sudan.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Sd;
+
+    impl Sealed for Sd {}
+
+    impl CountryMarker for Sd {
+        const COUNTRY: Country = Country::SD;
+        const COUNTRY_CODE: &'static str = "SD";
+    }
+
+    /// The country marker of Sweden.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Se;
+
+    impl Sealed for Se {}
+
+    impl CountryMarker for Se {
+        const COUNTRY: Country = Country::SE;
+        const COUNTRY_CODE: &'static str = "SE";
+    }
+
+    /// The country marker of Slovenia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Si;
+
+    impl Sealed for Si {}
+
+    impl CountryMarker for Si {
+        const COUNTRY: Country = Country::SI;
+        const COUNTRY_CODE: &'static str = "SI";
+    }
+
+    /// The country marker of Slovakia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Sk;
+
+    impl Sealed for Sk {}
+
+    impl CountryMarker for Sk {
+        const COUNTRY: Country = Country::SK;
+        const COUNTRY_CODE: &'static str = "SK";
+    }
+
+    /// The country marker of San Marino.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Sm;
+
+    impl Sealed for Sm {}
+
+    impl CountryMarker for Sm {
+        const COUNTRY: Country = Country::SM;
+        const COUNTRY_CODE: &'static str = "SM";
+    }
+
+    /// The country marker of Somalia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct So;
+
+    impl Sealed for So {}
+
+    impl CountryMarker for So {
+        const COUNTRY: Country = Country::SO;
+        const COUNTRY_CODE: &'static str = "SO";
+    }
+
+    /// The country marker of Sao Tome and Principe.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct St;
+
+    impl Sealed for St {}
+
+    impl CountryMarker for St {
+        const COUNTRY: Country = Country::ST;
+        const COUNTRY_CODE: &'static str = "ST";
+    }
+
+    /// The country marker of El Salvador.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Sv;
+
+    impl Sealed for Sv {}
+
+    impl CountryMarker for Sv {
+        const COUNTRY: Country = Country::SV;
+        const COUNTRY_CODE: &'static str = "SV";
+    }
+
+    /// The country marker of Timor-Leste.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Tl;
+
+    impl Sealed for Tl {}
+
+    impl CountryMarker for Tl {
+        const COUNTRY: Country = Country::TL;
+        const COUNTRY_CODE: &'static str = "TL";
+    }
+
+    /// The country marker of Tunisia.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Tn;
+
+    impl Sealed for Tn {}
+
+    impl CountryMarker for Tn {
+        const COUNTRY: Country = Country::TN;
+        const COUNTRY_CODE: &'static str = "TN";
+    }
+
+    /// The country marker of Turkey.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Tr;
+
+    impl Sealed for Tr {}
+
+    impl CountryMarker for Tr {
+        const COUNTRY: Country = Country::TR;
+        const COUNTRY_CODE: &'static str = "TR";
+    }
+
+    /// The country marker of Ukraine.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ua;
+
+    impl Sealed for Ua {}
+
+    impl CountryMarker for Ua {
+        const COUNTRY: Country = Country::UA;
+        const COUNTRY_CODE: &'static str = "UA";
+    }
+
+    /// The country marker of Vatican City State.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Va;
+
+    impl Sealed for Va {}
+
+    impl CountryMarker for Va {
+        const COUNTRY: Country = Country::VA;
+        const COUNTRY_CODE: &'static str = "VA";
+    }
+
+    /// The country marker of Virgin Islands.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Vg;
+
+    impl Sealed for Vg {}
+
+    impl CountryMarker for Vg {
+        const COUNTRY: Country = Country::VG;
+        const COUNTRY_CODE: &'static str = "VG";
+    }
+
+    /// The country marker of Kosovo.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Xk;
+
+    impl Sealed for Xk {}
+
+    impl CountryMarker for Xk {
+        const COUNTRY: Country = Country::XK;
+        const COUNTRY_CODE: &'static str = "XK";
+    }
+
+    /// The country marker of Yemen.
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    pub struct Ye;
+
+    impl Sealed for Ye {}
+
+    impl CountryMarker for Ye {
+        const COUNTRY: Country = Country::YE;
+        const COUNTRY_CODE: &'static str = "YE";
+    }
+}
+
+/// The examples in the registry, in the electronic format and sorted.
+pub(crate) const REGISTRY_EXAMPLES: &[&str] = &[
+    "AD1200012030200359100100",
+    "AE070331234567890123456",
+    "AL47212110090000000235698741",
+    "AT611904300234573201",
+    "AZ21NABZ00000000137010001944",
+    "BA391290079401028494",
+    "BE68539007547034",
+    "BG80BNBG96611020345678",
+    "BH67BMAG00001299123456",
+    "BI4210000100010000332045181",
+    "BR1800360305000010009795493C1",
+    "BY13NBRB3600900000002Z00AB00",
+    "CH9300762011623852957",
+    "CR05015202001026284066",
+    "CY17002001280000001200527600",
+    "CZ6508000000192000145399",
+    "DE89370400440532013000",
+    "DJ2100010000000154000100186",
+    "DK5000400440116243",
+    "DO28BAGR00000001212453611324",
+    "EE382200221020145685",
+    "EG380019000500000000263180002",
+    "ES9121000418450200051332",
+    "FI2112345600000785",
+    "FK88SC123456789012",
+    "FO6264600001631634",
+    "FR1420041010050500013M02606",
+    "GB29NWBK60161331926819",
+    "GE29NB0000000101904917",
+    "GI75NWBK000000007099453",
+    "GL8964710001000206",
+    "GR1601101250000000012300695",
+    "GT82TRAJ01020000001210029690",
+    "HR1210010051863000160",
+    "HU42117730161111101800000000",
+    "IE29AIBK93115212345678",
+    "IL620108000000099999999",
+    "IQ98NBIQ850123456789012",
+    "IS140159260076545510730339",
+    "IT60X0542811101000000123456",
+    "JO94CBJO0010000000000131000302",
+    "KW81CBKU0000000000001234560101",
+    "KZ86125KZT5004100100",
+    "LB62099900000001001901229114",
+    "LC55HEMM000100010012001200023015",
+    "LI21088100002324013AA",
+    "LT121000011101001000",
+    "LU280019400644750000",
+    "LV80BANK0000435195001",
+    "LY83002048000020100120361",
+    "MC5811222000010123456789030",
+    "MD24AG000225100013104168",
+    "ME25505000012345678951",
+    "MK07250120000058984",
+    "MN121234123456789123",
+    "MR1300020001010000123456753",
+    "MT84MALT011000012345MTLCAST001S",
+    "MU17BOMM0101101030300200000MUR",
+    "NI45BAPR00000013000003558124",
+    "NL91ABNA0417164300",
+    "NO9386011117947",
+    "OM810180000001299123456",
+    "PL61109010140000071219812874",
+    "PS92PALS000000000400123456702",
+    "PT50000201231234567890154",
+    "QA58DOHB00001234567890ABCDEFG",
+    "RO49AAAA1B31007593840000",
+    "RS35260005601001611379",
+    "RU0304452522540817810538091310419",
+    "SA0380000000608010167519",
+    "SC18SSCB11010000000000001497USD",
+    "SD2129010501234001",
+    "SE4550000000058398257466",
+    "SI56263300012039086",
+    "SK3112000000198742637541",
+    "SM86U0322509800000000270100",
+    "SO211000001001000100141",
+    "ST32000200010192194210112",
+    "SV62CENR00000000000000700025",
+    "TL380080012345678910157",
+    "TN5910006035183598478831",
+    "TR330006100519786457841326",
+    "UA213223130000026007233566001",
+    "VA59001123000012345678",
+    "VG96VPVG0000012345678901",
+    "XK051212012345678906",
+    "YE15CBYE0001018861234567891234",
+];
@@ -0,0 +1,269 @@
+//! Validation of partial input, for example while an IBAN is being typed in
+//! an input field.
+
+use crate::base_iban::MAX_IBAN_LEN;
+use crate::countries::{self, CharacterType};
+use crate::Iban;
+use arrayvec::ArrayString;
+use core::error::Error;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A validator that is fed an IBAN one character at a time, and reports
+/// whether the input can still become a valid [`Iban`]. It does not allocate.
+///
+/// Spaces are ignored and lowercase letters are converted to uppercase, so
+/// that both the electronic and the paper format can be typed. A character
+/// that cannot lead to a valid IBAN is rejected and not added to the input.
+///
+/// # Example
+/// ```rust
+/// use iban::{CharacterType, IncrementalValidator, InputError, InputState};
+/// let mut validator = IncrementalValidator::new();
+/// assert_eq!(validator.next_character_type(), Some(CharacterType::A));
+/// validator.push_str("de44 5001")?;
+/// assert_eq!(validator.as_str(), "DE445001");
+/// assert_eq!(
+///     validator.state(),
+///     InputState::Incomplete { remaining: Some(14) }
+/// );
+/// assert_eq!(validator.next_character_type(), Some(CharacterType::N));
+/// assert!(!validator.can_evaluate_checksum());
+///
+/// // German BBANs consist of digits only.
+/// assert_eq!(
+///     validator.push('X'),
+///     Err(InputError::UnexpectedCharacter(CharacterType::N))
+/// );
+///
+/// assert_eq!(validator.push_str("0517 5407 3249 31")?, InputState::Valid);
+/// assert!(validator.to_iban().is_some());
+/// assert_eq!(validator.push('1'), Err(InputError::TooLong));
+/// # Ok::<(), InputError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct IncrementalValidator {
+    /// The input so far, in the electronic format.
+    input: ArrayString<MAX_IBAN_LEN>,
+}
+
+/// The state of the input of an [`IncrementalValidator`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InputState {
+    /// More characters are needed. The number of remaining characters is
+    /// known once the country code has been entered.
+    Incomplete {
+        /// The number of characters that remain to be entered.
+        remaining: Option<usize>,
+    },
+    /// The input is a valid IBAN.
+    Valid,
+    /// The input has the length and format of an IBAN, but the checksum is
+    /// invalid.
+    InvalidChecksum,
+}
+
+/// An error indicating that a character was rejected by an
+/// [`IncrementalValidator`], because the input could no longer become a
+/// valid IBAN.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InputError {
+    /// The character is not an ASCII letter, digit or space.
+    InvalidCharacter,
+    /// The character does not have the type that is expected at this
+    /// position.
+    UnexpectedCharacter(CharacterType),
+    /// No country with a known format has a country code that starts with
+    /// the input.
+    UnknownCountry,
+    /// The input already has the length of an IBAN of its country.
+    TooLong,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::InvalidCharacter => write!(f, "the character cannot appear in an IBAN"),
+            InputError::UnexpectedCharacter(CharacterType::A) => write!(f, "expected a letter"),
+            InputError::UnexpectedCharacter(CharacterType::N) => write!(f, "expected a digit"),
+            InputError::UnexpectedCharacter(CharacterType::C) => {
+                write!(f, "expected a letter or a digit")
+            }
+            InputError::UnknownCountry => write!(f, "the IBAN country code wasn't recognized"),
+            InputError::TooLong => write!(f, "the IBAN is already complete"),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl fmt::Debug for IncrementalValidator {
+    /// With the `redact_debug` feature, only the country code of the input is
+    /// shown.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let input = if cfg!(feature = "redact_debug") {
+            self.input.get(..2).unwrap_or(&self.input)
+        } else {
+            &self.input
+        };
+        f.debug_struct("IncrementalValidator")
+            .field("input", &input)
+            .field("len", &self.input.len())
+            .finish()
+    }
+}
+
+impl IncrementalValidator {
+    /// Create a validator without input.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        IncrementalValidator::default()
+    }
+
+    /// Get the input so far, in the electronic format.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.input
+    }
+
+    /// Get the number of characters in the input so far.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.input.len()
+    }
+
+    /// Check whether there is no input.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    /// Add a character to the input. Spaces are ignored and lowercase letters
+    /// are converted to uppercase.
+    ///
+    /// # Errors
+    /// If the input can no longer become a valid IBAN with this character, an
+    /// [`InputError`] is returned and the character is not added.
+    pub fn push(&mut self, c: char) -> Result<InputState, InputError> {
+        if c == ' ' {
+            return Ok(self.state());
+        }
+        if !c.is_ascii_alphanumeric() {
+            return Err(InputError::InvalidCharacter);
+        }
+        let c = c.to_ascii_uppercase();
+        let expected = self.next_character_type().ok_or(InputError::TooLong)?;
+        if !expected.matches(c as u8) {
+            return Err(InputError::UnexpectedCharacter(expected));
+        }
+        self.input.try_push(c).map_err(|_| InputError::TooLong)?;
+        if !self.country_is_possible() {
+            self.input.pop();
+            return Err(InputError::UnknownCountry);
+        }
+        Ok(self.state())
+    }
+
+    /// Add every character of a string to the input, as with
+    /// [`push`](IncrementalValidator::push).
+    ///
+    /// # Errors
+    /// At the first character that is rejected, an [`InputError`] is returned.
+    /// The characters before it remain in the input.
+    pub fn push_str(&mut self, s: &str) -> Result<InputState, InputError> {
+        s.chars().try_fold(self.state(), |_, c| self.push(c))
+    }
+
+    /// Remove the last character of the input and return it, or `None` if
+    /// there is no input.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        self.input.pop()
+    }
+
+    /// Remove all input.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.input.clear();
+    }
+
+    /// Check whether a country with a known format starts with the input.
+    fn country_is_possible(&self) -> bool {
+        match self.input.as_bytes() {
+            [first] => (b'A'..=b'Z').any(|second| {
+                core::str::from_utf8(&[*first, second])
+                    .is_ok_and(|code| countries::country_pattern(code).is_some())
+            }),
+            _ => self.input.len() < 2 || self.pattern().is_some(),
+        }
+    }
+
+    /// Get the BBAN format of the country, if the country code is complete.
+    fn pattern(&self) -> Option<&'static [(usize, CharacterType)]> {
+        self.input.get(0..2).and_then(countries::country_pattern)
+    }
+
+    /// Get the length of the IBAN, once the country code has been entered.
+    #[inline]
+    #[must_use]
+    pub fn expected_length(&self) -> Option<usize> {
+        self.pattern().map(|pattern| countries::len(pattern) + 4)
+    }
+
+    /// Get the number of characters that remain to be entered, once the
+    /// country code has been entered.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> Option<usize> {
+        self.expected_length()
+            .map(|length| length - self.input.len())
+    }
+
+    /// Get the type of the character that is expected next, or `None` if the
+    /// input is complete. The country code consists of letters and the check
+    /// digits of digits, after which the BBAN format of the country applies.
+    #[must_use]
+    pub fn next_character_type(&self) -> Option<CharacterType> {
+        match self.input.len() {
+            0 | 1 => Some(CharacterType::A),
+            2 | 3 => Some(CharacterType::N),
+            len => self
+                .pattern()?
+                .iter()
+                .flat_map(|(count, character_type)| (0..*count).map(move |_| *character_type))
+                .nth(len - 4),
+        }
+    }
+
+    /// Check whether the checksum can be evaluated, which is the case once
+    /// the input has the length of an IBAN of its country.
+    #[inline]
+    #[must_use]
+    pub fn can_evaluate_checksum(&self) -> bool {
+        self.remaining() == Some(0)
+    }
+
+    /// Get the state of the input.
+    #[must_use]
+    pub fn state(&self) -> InputState {
+        match self.remaining() {
+            Some(0) if self.to_iban().is_some() => InputState::Valid,
+            Some(0) => InputState::InvalidChecksum,
+            remaining => InputState::Incomplete { remaining },
+        }
+    }
+
+    /// Get the IBAN, if the input is complete and valid.
+    #[inline]
+    #[must_use]
+    pub fn to_iban(&self) -> Option<Iban> {
+        self.input.parse().ok()
+    }
+}
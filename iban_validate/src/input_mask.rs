@@ -0,0 +1,166 @@
+//! Input masks for user interfaces, derived from the BBAN format of every
+//! country.
+
+use crate::base_iban::PAPER_GROUP_SIZE;
+use crate::components::{self, ComponentKind};
+use crate::countries::{self, CharacterType};
+use crate::{generated, Country, CountryCode, Formatted};
+use core::fmt::{self, Display, Write};
+use core::ops::Range;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The way in which the positions of the BBAN are shown in an [`InputMask`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MaskStyle {
+    /// Show the type of character that is expected: `A` for a letter, `n` for
+    /// a digit and `c` for either, as in the registry notation.
+    CharacterTypes,
+    /// Show the [component](crate::Component) that the position belongs to:
+    /// `b` for the bank identifier, `s` for the branch identifier, `c` for the
+    /// account number, `x` for national check digits, `t` for the account
+    /// type and `m` for the currency. If the components of a country are not
+    /// known, the character type is shown instead.
+    Components,
+}
+
+/// An input mask for the IBAN of a country, such as
+/// `GBkk AAAA nnnn nnnn nnnn nn`. It is obtained via [`input_mask`] or
+/// [`Country::input_mask`] and displayed in the paper format. The country
+/// code is shown as is and the check digits as `kk`. See [`MaskStyle`] for the
+/// characters used for the BBAN.
+///
+/// # Example
+/// ```rust
+/// use iban::{Country, MaskStyle};
+/// let mask = Country::GB.input_mask().expect("the format is known");
+/// assert_eq!(mask.to_string(), "GBkk AAAA nnnn nnnn nnnn nn");
+/// assert_eq!(
+///     mask.style(MaskStyle::Components).to_string(),
+///     "GBkk bbbb ssss sscc cccc cc"
+/// );
+/// assert_eq!(mask.iban_length(), 22);
+/// assert_eq!(
+///     mask.placeholder().map(|placeholder| placeholder.to_string()),
+///     Some("GB29 NWBK 6016 1331 9268 19".to_owned())
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct InputMask {
+    country_code: CountryCode,
+    pattern: &'static [(usize, CharacterType)],
+    components: &'static [(ComponentKind, Range<usize>)],
+    style: MaskStyle,
+}
+
+/// Get the input mask for the IBAN of the country with the given country
+/// code. Returns `None` if the BBAN format of the country is unknown.
+#[must_use]
+pub fn input_mask(country_code: &str) -> Option<InputMask> {
+    let country_code: CountryCode = country_code.parse().ok()?;
+    Some(InputMask {
+        country_code,
+        pattern: countries::country_pattern(country_code.as_str())?,
+        components: components::country_components(country_code.as_str()),
+        style: MaskStyle::CharacterTypes,
+    })
+}
+
+impl Country {
+    /// Get the input mask for the IBAN of this country. See [`input_mask`].
+    #[inline]
+    #[must_use]
+    pub fn input_mask(&self) -> Option<InputMask> {
+        input_mask(self.as_str())
+    }
+}
+
+impl InputMask {
+    /// Set the style in which the BBAN is shown. The default is
+    /// [`MaskStyle::CharacterTypes`].
+    #[inline]
+    #[must_use]
+    pub fn style(self, style: MaskStyle) -> Self {
+        InputMask { style, ..self }
+    }
+
+    /// Get the length of an IBAN following this mask, in the electronic
+    /// format.
+    #[inline]
+    #[must_use]
+    pub fn iban_length(&self) -> usize {
+        countries::len(self.pattern) + 4
+    }
+
+    /// Get the type of character expected at every position of the IBAN, in
+    /// the electronic format. The country code consists of letters and the
+    /// check digits of digits.
+    pub fn character_types(&self) -> impl Iterator<Item = CharacterType> + '_ {
+        [
+            CharacterType::A,
+            CharacterType::A,
+            CharacterType::N,
+            CharacterType::N,
+        ]
+        .into_iter()
+        .chain(
+            self.pattern
+                .iter()
+                .flat_map(|(count, character_type)| (0..*count).map(move |_| *character_type)),
+        )
+    }
+
+    /// Get an example IBAN in the paper format that can be used as a
+    /// placeholder. The example comes from the registry, so this returns
+    /// `None` for countries that do not have their own entry in it.
+    #[must_use]
+    pub fn placeholder(&self) -> Option<Formatted<'static>> {
+        generated::country_info(self.country_code.as_str())
+            .map(|info| Formatted::new(info.example()))
+    }
+
+    /// Get the character to show at a position of the BBAN.
+    fn bban_character(&self, i: usize, character_type: CharacterType) -> char {
+        let component = self
+            .components
+            .iter()
+            .find(|(_, range)| range.contains(&i))
+            .map(|(kind, _)| *kind);
+        match (self.style, component) {
+            (MaskStyle::Components, Some(kind)) => match kind {
+                ComponentKind::BankIdentifier => 'b',
+                ComponentKind::BranchIdentifier => 's',
+                ComponentKind::AccountNumber => 'c',
+                ComponentKind::NationalCheckDigits => 'x',
+                ComponentKind::AccountType => 't',
+                ComponentKind::Currency => 'm',
+            },
+            _ => match character_type {
+                CharacterType::A => 'A',
+                CharacterType::N => 'n',
+                CharacterType::C => 'c',
+            },
+        }
+    }
+}
+
+impl Display for InputMask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let country_code = self.country_code.as_str().chars();
+        let check_digits = ['k', 'k'].into_iter();
+        let bban = self
+            .character_types()
+            .skip(4)
+            .enumerate()
+            .map(|(i, character_type)| self.bban_character(i, character_type));
+        for (i, c) in country_code.chain(check_digits).chain(bban).enumerate() {
+            // Add a space before a character if it is the start of a group of four.
+            if i != 0 && i % PAPER_GROUP_SIZE == 0 {
+                f.write_char(' ')?;
+            }
+            f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
@@ -7,18 +7,88 @@
 #![deny(missing_debug_implementations)]
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::convert::TryFrom;
 use core::error::Error;
 use core::fmt::{self, Debug, Display};
 use core::str;
 
 mod base_iban;
+mod bban_format;
+mod bic;
+mod clearing;
+#[cfg(feature = "std")]
+mod collections;
+mod components;
 mod countries;
+mod country;
+pub mod de;
+#[cfg(feature = "std")]
+mod directory;
+mod examples;
+mod extended;
+mod formatted;
+pub mod gb;
 mod generated;
+mod incremental;
+mod input_mask;
+mod masked;
+mod national;
+#[cfg(feature = "std")]
+mod ocr;
+mod ordering;
+#[cfg(feature = "std")]
+mod registry;
+mod sepa;
+mod similarity;
+mod spoken;
+mod suspicious;
+mod typed;
+mod validator;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 pub use base_iban::{BaseIban, ParseBaseIbanError};
+pub use bban_format::{bban_format, BbanFormat, BbanRegex, RegexFlavor};
+pub use bic::{Bic, InconsistentBicError, ParseBicError};
+pub use clearing::{
+    ClearingMemberId, ClearingSystem, FromClearingError, InvalidMemberIdError,
+    ParseClearingSystemError,
+};
+#[cfg(feature = "std")]
+pub use collections::{IbanMap, IbanMapIntoIter, IbanMapIter, IbanSet, IbanSetIntoIter};
+pub use components::{Component, ComponentKind, Components, Explain};
+pub use countries::CharacterType;
+pub use country::{country_info, CountryCode, CountryInfo, ParseCountryError, RegistryDate};
+#[cfg(feature = "std")]
+pub use directory::{Bank, BankDirectory, BankStatus, LoadBankDirectoryError, LocalBankDirectory};
+pub use examples::known_examples;
+pub use extended::{format_source, FormatSource};
+pub use formatted::{BufferTooSmallError, Formatted};
+pub use generated::{markers, Country};
+pub use incremental::{IncrementalValidator, InputError, InputState};
+pub use input_mask::{input_mask, InputMask, MaskStyle};
+pub use masked::Masked;
+pub use national::NationalCheck;
+#[cfg(feature = "std")]
+pub use ocr::{recover_ocr, OcrCandidate, OcrRecovery, RecoverOcrError};
+#[cfg(feature = "std")]
+pub use registry::{
+    BbanStructure, IncompatibleStructureError, LoadRegistryError, ParseStructureError, Registry,
+};
+pub use sepa::{sepa_transfer, SepaScope, SepaTransfer, SepaTransferError};
+#[cfg(feature = "std")]
+pub use similarity::cluster_similar;
+pub use similarity::{Similarity, SimilarityKind};
+pub use spoken::{PhoneticAlphabet, Spoken};
+pub use suspicious::{
+    fold_suspicious, suspicious_characters, FoldSuspicious, SuspiciousCharacter,
+    SuspiciousCharacters, SuspiciousKind,
+};
+pub use typed::{CountryMarker, ParseTypedIbanError, TypedIban, WrongCountryError};
+pub use validator::{IbanValidator, ValidateIbanError, ValidatedIban};
 
 /// A trait that provide basic functions on an IBAN. It is implemented by both [`Iban`],
 /// which represents a fully validated IBAN, and [`BaseIban`], which might not have a correct BBAN.
@@ -52,6 +122,26 @@ pub trait IbanLike {
         &self.electronic_str()[0..2]
     }
 
+    /// Get the country of the IBAN. Countries that are not in the IBAN
+    /// registry, which can occur for a [`BaseIban`], are returned as
+    /// [`Country::Other`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let iban: Iban = "DE44 5001 0517 5407 3249 31".parse()?;
+    /// assert_eq!(iban.country(), Country::DE);
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    fn country(&self) -> Country {
+        self.country_code().parse().expect(
+            "Could not parse country code. Please create an issue at \
+             https://github.com/ThomasdenH/iban_validate.",
+        )
+    }
+
     /// Get the check digits of the IBAN, as a string slice. This method simply returns
     /// a slice of the inner representation. To obtain an integer instead,
     /// use [`check_digits`](IbanLike::check_digits).
@@ -131,6 +221,48 @@ impl Iban {
         self.bban_unchecked()
     }
 
+    /// Get the source of the built-in BBAN format of the country of this
+    /// IBAN. This is [`FormatSource::Registry`], unless the
+    /// `extended_countries` feature is enabled and the country is not in the
+    /// IBAN registry. Returns `None` if the crate has no format for the
+    /// country, which is possible for an IBAN that was validated using a
+    /// runtime [`Registry`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let iban: Iban = "DE44 5001 0517 5407 3249 31".parse()?;
+    /// assert_eq!(iban.format_source(), Some(FormatSource::Registry));
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn format_source(&self) -> Option<FormatSource> {
+        extended::format_source(self.country_code())
+    }
+
+    /// Get the country whose format was applied to validate this IBAN, if the
+    /// IBAN belongs to a dependent territory that uses the format of another
+    /// country. For example, IBANs from Jersey use the format of the United
+    /// Kingdom. Returns `None` if the country has its own format.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let iban: Iban = "JE90 NWBK 6016 1331 9268 19".parse()?;
+    /// assert_eq!(iban.parent_country(), Some(Country::GB));
+    /// assert_eq!(iban.bank_identifier(), Some("NWBK"));
+    ///
+    /// let iban: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    /// assert_eq!(iban.parent_country(), None);
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn parent_country(&self) -> Option<Country> {
+        generated::parent_country(self.country_code())
+    }
+
     /// Get the bank identifier of the IBAN. The bank identifier might not be
     /// defined, in which case this method returns `None`.
     ///
@@ -144,8 +276,17 @@ impl Iban {
     #[inline]
     #[must_use]
     pub fn bank_identifier(&self) -> Option<&str> {
-        generated::bank_identifier(self.country_code())
-            .map(|range| &self.electronic_str()[4..][range])
+        self.bank_identifier_range()
+            .and_then(|range| self.bban().get(range))
+    }
+
+    /// Get the position of the bank identifier in the BBAN.
+    fn bank_identifier_range(&self) -> Option<core::ops::Range<usize>> {
+        components::component_range(
+            self.country_code(),
+            self.bban(),
+            ComponentKind::BankIdentifier,
+        )
     }
 
     /// Get the branch identifier of the IBAN. The branch identifier might not be
@@ -161,8 +302,129 @@ impl Iban {
     #[must_use]
     #[inline]
     pub fn branch_identifier(&self) -> Option<&str> {
-        generated::branch_identifier(self.country_code())
-            .map(|range| &self.electronic_str()[4..][range])
+        components::component_range(
+            self.country_code(),
+            self.bban(),
+            ComponentKind::BranchIdentifier,
+        )
+        .and_then(|range| self.bban().get(range))
+    }
+
+    /// Get the labelled components of the BBAN, such as the bank identifier
+    /// and the account number, ordered by their position. Together, the
+    /// components cover the entire BBAN. Countries for which the components
+    /// are not known, like those enabled by the `extended_countries` feature,
+    /// yield no components.
+    ///
+    /// Where the registry does not describe a component, it is added from
+    /// national documentation. For this reason, the components may contain a
+    /// branch identifier even if [`Iban::branch_identifier`] returns `None`.
+    ///
+    /// # Example
+    /// ```
+    /// use iban::*;
+    /// let iban: Iban = "BE68 5390 0754 7034".parse()?;
+    /// let components: Vec<_> = iban
+    ///     .components()
+    ///     .map(|component| (component.kind(), component.as_str()))
+    ///     .collect();
+    /// assert_eq!(
+    ///     components,
+    ///     [
+    ///         (ComponentKind::BankIdentifier, "539"),
+    ///         (ComponentKind::AccountNumber, "0075470"),
+    ///         (ComponentKind::NationalCheckDigits, "34"),
+    ///     ]
+    /// );
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn components(&self) -> Components<'_> {
+        Components::new(self)
+    }
+
+    /// Get a human readable breakdown of the IBAN, which can be displayed.
+    ///
+    /// # Example
+    /// ```
+    /// use iban::*;
+    /// let iban: Iban = "DE44 5001 0517 5407 3249 31".parse()?;
+    /// assert_eq!(
+    ///     iban.explain().to_string(),
+    ///     "country code: DE\n\
+    ///      check digits: 44\n\
+    ///      bank identifier: 50010517\n\
+    ///      account number: 5407324931"
+    /// );
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn explain(&self) -> Explain<'_> {
+        Explain::new(self)
+    }
+
+    /// Get a formatting of the IBAN with configurable options, which can be
+    /// displayed. See [`Formatted`] for the options.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let iban: Iban = "DE44 5001 0517 5407 3249 31".parse()?;
+    /// assert_eq!(
+    ///     iban.formatted().group_size(2).to_string(),
+    ///     "DE 44 50 01 05 17 54 07 32 49 31"
+    /// );
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn formatted(&self) -> Formatted<'_> {
+        self.base_iban.formatted()
+    }
+
+    /// Get the IBAN rendered for reading aloud, with letters spelled using
+    /// the given phonetic alphabet. See [`Spoken`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let iban: Iban = "DE44 5001 0517 5407 3249 31".parse()?;
+    /// assert_eq!(
+    ///     iban.spoken(PhoneticAlphabet::Din5009).to_string(),
+    ///     "Düsseldorf Essen 4 4, 5 0 0 1, 0 5 1 7, 5 4 0 7, 3 2 4 9, 3 1"
+    /// );
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn spoken(&self, alphabet: PhoneticAlphabet) -> Spoken<'_> {
+        self.base_iban.spoken(alphabet)
+    }
+
+    /// Get a view of the IBAN in which most characters are replaced by `*`,
+    /// which can be displayed. In contrast to [`BaseIban::masked`], the bank
+    /// identifier can be kept visible. See [`Masked`] for the options.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let iban: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    /// assert_eq!(
+    ///     iban.masked().keep_bank_identifier().to_string(),
+    ///     "GB29 NWBK **** **** **68 19"
+    /// );
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn masked(&self) -> Masked<'_> {
+        Masked::new(
+            self.electronic_str(),
+            self.bank_identifier_range()
+                .map(|range| range.start + 4..range.end + 4),
+        )
     }
 }
 
@@ -224,11 +486,15 @@ impl Display for Iban {
 /// formatting, [`Debug`] can be used to output the former and [`Display`] for
 /// the latter. This is true for a [`BaseIban`] as well as an [`Iban`].
 /// Alternatively, you can use [`IbanLike::electronic_str`] to obtain the
-/// electronic format as a string slice.
+/// electronic format as a string slice. If the `redact_debug` feature is
+/// enabled, [`Debug`] shows a [`masked`](Iban::masked) IBAN instead. The
+/// alternate flag, `{:#}`, makes [`Display`] output the electronic format. For
+/// more options, see [`Iban::formatted`].
 /// ```
 /// # use iban::ParseIbanError;
 /// let iban: iban::Iban = "RO66BACX0000001234567890".parse()?;
 /// // Use Debug for the electronic format.
+/// # #[cfg(not(feature = "redact_debug"))]
 /// assert_eq!(&format!("{:?}", iban), "RO66BACX0000001234567890");
 /// // Use Display for the pretty print format.
 /// assert_eq!(&format!("{}", iban), "RO66 BACX 0000 0012 3456 7890");
@@ -376,23 +642,75 @@ impl TryFrom<BaseIban> for Iban {
     fn try_from(base_iban: BaseIban) -> Result<Iban, ParseIbanError> {
         use countries::Matchable;
 
-        #[cfg(not(feature = "zeroize"))]
-        let pattern = generated::country_pattern(base_iban.country_code())
-            .ok_or(ParseIbanError::UnknownCountry(base_iban));
+        match countries::country_pattern(base_iban.country_code()) {
+            None => Err(ParseIbanError::UnknownCountry(base_iban)),
+            Some(matcher) if matcher.match_str(base_iban.bban_unchecked()) => {
+                Ok(Iban { base_iban })
+            }
+            Some(_) => Err(ParseIbanError::InvalidBban(base_iban)),
+        }
+    }
+}
 
-        #[cfg(feature = "zeroize")]
-        let binding = base_iban.clone();
-        #[cfg(feature = "zeroize")]
-        let pattern = generated::country_pattern(binding.country_code())
-            .ok_or(ParseIbanError::UnknownCountry(base_iban.clone()));
+impl Iban {
+    /// Validate the BBAN of a [`BaseIban`] using the country formats that were
+    /// in effect at the given date, for example to validate IBANs from a
+    /// historical archive. The bank and branch identifiers and the
+    /// [components](Iban::components) of the returned IBAN follow the format
+    /// its BBAN was accepted with.
+    ///
+    /// Only the historical formats that have been collected are known. At the
+    /// moment, this is the format Costa Rica used before July 2017. For other
+    /// countries, the current format is used for any date at which the IBAN
+    /// was in effect.
+    ///
+    /// # Errors
+    /// If the country format is unknown or the IBAN of the country was not
+    /// yet in effect at the given date, [`ParseIbanError::UnknownCountry`]
+    /// is returned. If the BBAN does not follow the format that was in effect,
+    /// [`ParseIbanError::InvalidBban`] is returned. Countries that are not in
+    /// the registry have no effective date and are always validated using the
+    /// current format.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// // Costa Rica extended the BBAN by a leading zero in 2017.
+    /// let old: BaseIban = "CR05 1520 2001 0262 8406 6".parse()?;
+    /// let iban = Iban::try_from_as_of(old.clone(), RegistryDate::new(2015, 1))?;
+    /// assert_eq!(iban.bank_identifier(), Some("152"));
+    /// assert!(Iban::try_from(old).is_err());
+    ///
+    /// // Oman was added to the registry in 2024.
+    /// let oman: BaseIban = "OM81 0180 0000 0129 9123 456".parse()?;
+    /// assert_eq!(
+    ///     Iban::try_from_as_of(oman.clone(), RegistryDate::new(2020, 1)),
+    ///     Err(ParseIbanError::UnknownCountry(oman))
+    /// );
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    pub fn try_from_as_of(base_iban: BaseIban, date: RegistryDate) -> Result<Iban, ParseIbanError> {
+        use countries::Matchable;
 
-        pattern.and_then(|matcher: &[(usize, _)]| {
-            if matcher.match_str(base_iban.bban_unchecked()) {
+        match countries::country_pattern_as_of(base_iban.country_code(), date) {
+            None => Err(ParseIbanError::UnknownCountry(base_iban)),
+            Some(matcher) if matcher.match_str(base_iban.bban_unchecked()) => {
                 Ok(Iban { base_iban })
-            } else {
-                Err(ParseIbanError::InvalidBban(base_iban))
             }
-        })
+            Some(_) => Err(ParseIbanError::InvalidBban(base_iban)),
+        }
+    }
+
+    /// Parse an IBAN using the country formats that were in effect at the
+    /// given date. See [`Iban::try_from_as_of`].
+    ///
+    /// # Errors
+    /// If the string does not match the IBAN format or the checksum is
+    /// invalid, [`ParseIbanError::InvalidBaseIban`] will be returned. The
+    /// other variants are returned as described in [`Iban::try_from_as_of`].
+    #[inline]
+    pub fn parse_as_of(s: &str, date: RegistryDate) -> Result<Iban, ParseIbanError> {
+        Iban::try_from_as_of(s.parse()?, date)
     }
 }
 
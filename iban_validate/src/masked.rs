@@ -0,0 +1,157 @@
+//! Display an IBAN with most characters replaced, so that it can be logged or
+//! shown without revealing the full account number.
+
+use crate::base_iban::{groups, MAX_IBAN_LEN, PAPER_GROUP_SIZE};
+use crate::formatted::pad;
+use arrayvec::ArrayString;
+use core::fmt::{self, Debug, Display, Write};
+use core::ops::Range;
+
+/// The character that replaces hidden characters.
+const MASK: char = '*';
+
+/// The maximum length of a masked IBAN in the paper format.
+const MAX_MASKED_LEN: usize = MAX_IBAN_LEN + MAX_IBAN_LEN / PAPER_GROUP_SIZE;
+
+/// An IBAN in which most characters are replaced by `*`. It is obtained via
+/// [`Iban::masked`](crate::Iban::masked) or
+/// [`BaseIban::masked`](crate::BaseIban::masked). By default, the country
+/// code, the check digits and the last four characters are kept and the IBAN
+/// is displayed in the paper format.
+///
+/// The [`Debug`] implementation shows the masked IBAN as well, so this type
+/// never reveals the hidden characters.
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let iban: Iban = "DE44 5001 0517 5407 3249 31".parse()?;
+/// assert_eq!(iban.masked().to_string(), "DE44 **** **** **** **49 31");
+/// assert_eq!(
+///     iban.masked().keep_prefix(2).keep_suffix(2).to_string(),
+///     "DE** **** **** **** **** 31"
+/// );
+/// assert_eq!(
+///     iban.masked().keep_bank_identifier().to_string(),
+///     "DE44 5001 0517 **** **49 31"
+/// );
+/// assert_eq!(iban.masked().electronic().to_string(), "DE44**************4931");
+/// # Ok::<(), ParseIbanError>(())
+/// ```
+#[derive(Clone)]
+pub struct Masked<'a> {
+    electronic: &'a str,
+    /// The position of the bank identifier in the electronic format, if known.
+    bank_identifier: Option<Range<usize>>,
+    prefix: usize,
+    suffix: usize,
+    keep_bank_identifier: bool,
+    paper_format: bool,
+}
+
+impl<'a> Masked<'a> {
+    pub(crate) fn new(electronic: &'a str, bank_identifier: Option<Range<usize>>) -> Self {
+        Masked {
+            electronic,
+            bank_identifier,
+            prefix: 4,
+            suffix: 4,
+            keep_bank_identifier: false,
+            paper_format: true,
+        }
+    }
+
+    /// Set the number of characters at the start of the IBAN that are kept.
+    /// The default of four keeps the country code and the check digits.
+    #[inline]
+    #[must_use]
+    pub fn keep_prefix(self, prefix: usize) -> Self {
+        Masked { prefix, ..self }
+    }
+
+    /// Set the number of characters at the end of the IBAN that are kept. The
+    /// default is four.
+    #[inline]
+    #[must_use]
+    pub fn keep_suffix(self, suffix: usize) -> Self {
+        Masked { suffix, ..self }
+    }
+
+    /// Keep the bank identifier visible, so that the bank can be identified.
+    /// This has no effect if the bank identifier is not known, which is
+    /// always the case for a [`BaseIban`](crate::BaseIban).
+    #[inline]
+    #[must_use]
+    pub fn keep_bank_identifier(self) -> Self {
+        Masked {
+            keep_bank_identifier: true,
+            ..self
+        }
+    }
+
+    /// Display the IBAN in the electronic format, without spaces.
+    #[inline]
+    #[must_use]
+    pub fn electronic(self) -> Self {
+        Masked {
+            paper_format: false,
+            ..self
+        }
+    }
+
+    /// Check whether the character at the given position is kept.
+    fn is_kept(&self, i: usize) -> bool {
+        i < self.prefix
+            || i >= self.electronic.len().saturating_sub(self.suffix)
+            || (self.keep_bank_identifier
+                && self
+                    .bank_identifier
+                    .as_ref()
+                    .is_some_and(|range| range.contains(&i)))
+    }
+}
+
+impl Display for Masked<'_> {
+    /// Display the masked IBAN. Padding is supported.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let group_size = if self.paper_format {
+            PAPER_GROUP_SIZE
+        } else {
+            0
+        };
+        let mut s = ArrayString::<MAX_MASKED_LEN>::new();
+        let mut i = 0;
+        for (j, group) in groups(self.electronic, group_size).enumerate() {
+            if j != 0 {
+                s.write_char(' ')?;
+            }
+            for c in group.chars() {
+                s.write_char(if self.is_kept(i) { c } else { MASK })?;
+                i += 1;
+            }
+        }
+        pad(f, &s)
+    }
+}
+
+impl Debug for Masked<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Show a string in [`Debug`] output as is, or masked with the `redact_debug`
+/// feature. It is used by types that contain (parts of) an IBAN.
+pub(crate) struct Redacted<'a>(pub(crate) Masked<'a>);
+
+impl Debug for Redacted<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if cfg!(feature = "redact_debug") {
+            Debug::fmt(&self.0, f)
+        } else {
+            Debug::fmt(self.0.electronic, f)
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Validation of the national check digits that the BBAN of some countries
+//! contains in addition to the check digits of the IBAN. The algorithms are
+//! NOT part of the registry, but come from national documentation.
+
+use crate::{Country, Iban, IbanLike};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The result of validating the national check digits of an IBAN, obtained
+/// via [`Iban::national_check`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NationalCheck {
+    /// The national check digits are correct.
+    Valid,
+    /// The national check digits are incorrect. The IBAN was likely created
+    /// by hand instead of by a bank, or contains an error that the IBAN
+    /// checksum did not catch.
+    Invalid,
+    /// The country has no national check digits, or its algorithm is not
+    /// supported.
+    Unsupported,
+}
+
+/// Get the value of an ASCII digit.
+fn digit(c: u8) -> u32 {
+    u32::from(c - b'0')
+}
+
+/// Compute the remainder modulo 97 of a string of digits and uppercase
+/// letters, where a letter counts as the two digits 10 to 35, like in the
+/// IBAN checksum.
+pub(crate) fn mod97(s: &[u8]) -> u32 {
+    s.iter().fold(0, |acc, &c| {
+        if c.is_ascii_digit() {
+            (acc * 10 + digit(c)) % 97
+        } else {
+            (acc * 100 + u32::from(c - b'A') + 10) % 97
+        }
+    })
+}
+
+/// Compute the sum of digits multiplied by their weights.
+fn weighted_sum(digits: &[u8], weights: &[u32]) -> u32 {
+    digits
+        .iter()
+        .zip(weights)
+        .map(|(&c, weight)| digit(c) * weight)
+        .sum()
+}
+
+/// Get the number that two digits form.
+fn two_digits(digits: &[u8]) -> u32 {
+    digit(digits[0]) * 10 + digit(digits[1])
+}
+
+/// Belgium: the first ten digits modulo 97, where a remainder of zero is
+/// written as 97.
+fn belgium(bban: &[u8]) -> bool {
+    let check = match mod97(&bban[..10]) {
+        0 => 97,
+        remainder => remainder,
+    };
+    check == two_digits(&bban[10..])
+}
+
+/// France and Monaco: the clé RIB over the bank code, branch code and
+/// account number, in which letters are replaced by digits.
+fn france(bban: &[u8]) -> bool {
+    let account = bban[10..21].iter().fold(0, |acc, &c| {
+        let value = match c {
+            b'0'..=b'9' => digit(c),
+            b'A'..=b'I' => u32::from(c - b'A') + 1,
+            b'J'..=b'R' => u32::from(c - b'J') + 1,
+            _ => u32::from(c - b'S') + 2,
+        };
+        (acc * 10 + value) % 97
+    });
+    let sum = 89 * mod97(&bban[..5]) + 15 * mod97(&bban[5..10]) + 3 * account;
+    97 - sum % 97 == two_digits(&bban[21..])
+}
+
+/// Spain: a check digit over the bank and branch codes and one over the
+/// account number.
+fn spain(bban: &[u8]) -> bool {
+    const WEIGHTS: [u32; 10] = [1, 2, 4, 8, 5, 10, 9, 7, 3, 6];
+    let check = |digits: &[u8], weights: &[u32]| match 11 - weighted_sum(digits, weights) % 11 {
+        11 => 0,
+        10 => 1,
+        check => check,
+    };
+    check(&bban[..8], &WEIGHTS[2..]) == digit(bban[8])
+        && check(&bban[10..], &WEIGHTS) == digit(bban[9])
+}
+
+/// Italy and San Marino: the CIN, a letter over the ABI, CAB and account
+/// number.
+fn italy(bban: &[u8]) -> bool {
+    /// The values of the characters at odd positions, where both the digit
+    /// `0` and the letter `A` have index zero.
+    const ODD: [u32; 26] = [
+        1, 0, 5, 7, 9, 13, 15, 17, 19, 21, 2, 4, 18, 20, 11, 3, 6, 8, 12, 14, 16, 10, 22, 25, 24,
+        23,
+    ];
+    let sum: u32 = bban[1..]
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| {
+            let index = if c.is_ascii_digit() {
+                c - b'0'
+            } else {
+                c - b'A'
+            };
+            if i % 2 == 0 {
+                ODD[usize::from(index)]
+            } else {
+                u32::from(index)
+            }
+        })
+        .sum();
+    u32::from(bban[0] - b'A') == sum % 26
+}
+
+/// ISO 7064 MOD 97-10 over the BBAN, with the last two digits as the check
+/// digits, as used by Portugal and several countries of former Yugoslavia.
+fn iso7064_mod97(bban: &[u8]) -> bool {
+    let (rest, check) = bban.split_at(bban.len() - 2);
+    98 - mod97(rest) * 100 % 97 == two_digits(check)
+}
+
+/// Norway: MOD 11 over the first ten digits, where a check digit of ten
+/// does not occur.
+fn norway(bban: &[u8]) -> bool {
+    match 11 - weighted_sum(&bban[..10], &[5, 4, 3, 2, 7, 6, 5, 4, 3, 2]) % 11 {
+        10 => false,
+        11 => digit(bban[10]) == 0,
+        check => digit(bban[10]) == check,
+    }
+}
+
+/// Finland: the Luhn algorithm over the BBAN.
+fn finland(bban: &[u8]) -> bool {
+    let sum: u32 = bban
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &c)| match (i % 2, digit(c) * 2) {
+            (0, _) => digit(c),
+            (_, doubled) if doubled > 9 => doubled - 9,
+            (_, doubled) => doubled,
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+/// Czech Republic and Slovakia: MOD 11 over the account number prefix and
+/// over the account number.
+fn czechoslovakia(bban: &[u8]) -> bool {
+    weighted_sum(&bban[4..10], &[10, 5, 8, 4, 2, 1]).is_multiple_of(11)
+        && weighted_sum(&bban[10..], &[6, 3, 7, 9, 10, 5, 8, 4, 2, 1]).is_multiple_of(11)
+}
+
+impl Iban {
+    /// Validate the national check digits that the BBAN of some countries
+    /// contains, in addition to the check digits of the IBAN. Dependent
+    /// territories use the algorithm of the country whose format they use.
+    ///
+    /// The supported countries are Belgium, Bosnia and Herzegovina, the Czech
+    /// Republic, Finland, France, Italy, Monaco, Montenegro, North Macedonia,
+    /// Norway, Portugal, San Marino, Serbia, Slovakia, Slovenia, Spain and
+    /// Timor-Leste. If the BBAN does not follow the built-in format of the
+    /// country, for example because it was validated using a custom
+    /// [`Registry`](crate::Registry) or a historical format,
+    /// [`NationalCheck::Unsupported`] is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let iban: Iban = "BE68 5390 0754 7034".parse()?;
+    /// assert_eq!(iban.national_check(), NationalCheck::Valid);
+    ///
+    /// // The IBAN checksum is correct, but the national check digits are not.
+    /// let iban: Iban = "BE41 5390 0754 7035".parse()?;
+    /// assert_eq!(iban.national_check(), NationalCheck::Invalid);
+    ///
+    /// // German BBANs have no national check digits.
+    /// let iban: Iban = "DE44 5001 0517 5407 3249 31".parse()?;
+    /// assert_eq!(iban.national_check(), NationalCheck::Unsupported);
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[must_use]
+    pub fn national_check(&self) -> NationalCheck {
+        use crate::countries::{self, Matchable};
+
+        let country = self.parent_country().unwrap_or_else(|| self.country());
+        // The algorithms rely on the built-in format, which an IBAN validated
+        // by a custom registry or a historical format may not follow.
+        if !countries::country_pattern(country.as_str())
+            .is_some_and(|pattern| pattern.match_str(self.bban()))
+        {
+            return NationalCheck::Unsupported;
+        }
+        let bban = self.bban().as_bytes();
+        let valid = match country {
+            Country::BE => belgium(bban),
+            Country::FR | Country::MC => france(bban),
+            Country::ES => spain(bban),
+            Country::IT | Country::SM => italy(bban),
+            Country::BA
+            | Country::ME
+            | Country::MK
+            | Country::PT
+            | Country::RS
+            | Country::SI
+            | Country::TL => iso7064_mod97(bban),
+            Country::NO => norway(bban),
+            Country::FI => finland(bban),
+            Country::CZ | Country::SK => czechoslovakia(bban),
+            _ => return NationalCheck::Unsupported,
+        };
+        if valid {
+            NationalCheck::Valid
+        } else {
+            NationalCheck::Invalid
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Recovery of IBANs that were read by optical character recognition, in
+//! which letters and digits that look alike are easily confused.
+
+use crate::base_iban::MAX_IBAN_LEN;
+use crate::countries::{self, CharacterType};
+use crate::Iban;
+use arrayvec::ArrayString;
+use core::error::Error;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::vec::Vec;
+
+/// Pairs of a letter and a digit that look alike.
+const LOOK_ALIKES: [(char, char); 5] = [('O', '0'), ('I', '1'), ('S', '5'), ('B', '8'), ('Z', '2')];
+
+/// The maximum number of positions at which both a letter and a digit are
+/// allowed and a look-alike character was read. Every such position doubles
+/// the number of readings that are tried.
+const MAX_AMBIGUOUS_POSITIONS: usize = 12;
+
+/// Get the character that looks like the given character, if any.
+fn look_alike(c: char) -> Option<char> {
+    LOOK_ALIKES.iter().find_map(|&(letter, digit)| match c {
+        _ if c == letter => Some(digit),
+        _ if c == digit => Some(letter),
+        _ => None,
+    })
+}
+
+/// A possible reading of a scanned IBAN, which is a valid [`Iban`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct OcrCandidate {
+    iban: Iban,
+    corrections: usize,
+    confidence: f64,
+}
+
+impl OcrCandidate {
+    /// Get the IBAN of this reading.
+    #[inline]
+    #[must_use]
+    pub fn iban(&self) -> &Iban {
+        &self.iban
+    }
+
+    /// Get the number of characters that differ from the scanned input.
+    #[inline]
+    #[must_use]
+    pub fn corrections(&self) -> usize {
+        self.corrections
+    }
+
+    /// Get the confidence in this reading, between 0 and 1. The confidences
+    /// of all readings of an input add up to 1. Characters that must be
+    /// corrected because of their position do not lower the confidence, but
+    /// every other correction halves the relative likelihood of a reading.
+    #[inline]
+    #[must_use]
+    pub fn confidence(&self) -> f64 {
+        self.confidence
+    }
+}
+
+/// The valid readings of a scanned IBAN, obtained via [`recover_ocr`]. There
+/// is at least one reading.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OcrRecovery {
+    /// The readings, ordered from most to least likely.
+    candidates: Vec<OcrCandidate>,
+}
+
+impl OcrRecovery {
+    /// Get the IBAN if there is only a single valid reading.
+    #[must_use]
+    pub fn unique(&self) -> Option<&Iban> {
+        match self.candidates.as_slice() {
+            [candidate] => Some(candidate.iban()),
+            _ => None,
+        }
+    }
+
+    /// Get the most likely reading.
+    #[must_use]
+    pub fn best(&self) -> &OcrCandidate {
+        self.candidates.first().expect(
+            "An OCR recovery without candidates was created. Please create an issue at \
+             https://github.com/ThomasdenH/iban_validate.",
+        )
+    }
+
+    /// Get all valid readings, ordered from most to least likely.
+    #[inline]
+    #[must_use]
+    pub fn candidates(&self) -> &[OcrCandidate] {
+        &self.candidates
+    }
+}
+
+/// An error indicating that a scanned IBAN could not be recovered.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RecoverOcrError {
+    /// A character is not a letter, digit or whitespace, or it cannot be
+    /// corrected to the type that is required at its position.
+    InvalidCharacter,
+    /// The country code wasn't recognized.
+    UnknownCountry,
+    /// The length does not match the length of an IBAN of the country.
+    InvalidLength,
+    /// There are too many positions at which both a letter and a digit are
+    /// allowed and a look-alike character was read.
+    TooAmbiguous,
+    /// None of the readings has a valid checksum.
+    NoValidReading,
+}
+
+impl fmt::Display for RecoverOcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                RecoverOcrError::InvalidCharacter =>
+                    "a character cannot be corrected to fit its position",
+                RecoverOcrError::UnknownCountry => "the IBAN country code wasn't recognized",
+                RecoverOcrError::InvalidLength => "the length doesn't match that of the country",
+                RecoverOcrError::TooAmbiguous => "too many characters are ambiguous",
+                RecoverOcrError::NoValidReading => "no reading has a valid checksum",
+            }
+        )
+    }
+}
+
+impl Error for RecoverOcrError {}
+
+/// Correct a character so that it has the given type, if needed.
+fn correct(c: char, character_type: CharacterType) -> Result<char, RecoverOcrError> {
+    if character_type.matches(c as u8) {
+        Ok(c)
+    } else {
+        look_alike(c)
+            .filter(|&other| character_type.matches(other as u8))
+            .ok_or(RecoverOcrError::InvalidCharacter)
+    }
+}
+
+/// Recover an IBAN that was read by optical character recognition. Letters
+/// and digits that look alike, such as `O` and `0`, `I` and `1`, `S` and `5`,
+/// `B` and `8` and `Z` and `2`, are disambiguated using the format of the
+/// country. Where the format allows both a letter and a digit, both readings
+/// are tried, and only those with a valid checksum are kept.
+///
+/// Whitespace is ignored and lowercase letters are converted to uppercase.
+///
+/// # Errors
+/// If no reading is a valid IBAN, a [`RecoverOcrError`] is returned.
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// // German BBANs consist of digits only.
+/// let recovery = recover_ocr("DE44 5OO1 O517 54O7 3249 3I")?;
+/// assert_eq!(
+///     recovery.unique().map(ToString::to_string),
+///     Some("DE44 5001 0517 5407 3249 31".to_owned())
+/// );
+/// assert_eq!(recovery.best().corrections(), 5);
+/// # Ok::<(), RecoverOcrError>(())
+/// ```
+pub fn recover_ocr(input: &str) -> Result<OcrRecovery, RecoverOcrError> {
+    let mut scanned = ArrayString::<MAX_IBAN_LEN>::new();
+    for c in input.chars().filter(|c| !c.is_whitespace()) {
+        if !c.is_ascii_alphanumeric() {
+            return Err(RecoverOcrError::InvalidCharacter);
+        }
+        scanned
+            .try_push(c.to_ascii_uppercase())
+            .map_err(|_| RecoverOcrError::InvalidLength)?;
+    }
+
+    // The country code and check digits determine the format of the rest.
+    let mut prefix = ArrayString::<4>::new();
+    for (i, c) in scanned.chars().take(4).enumerate() {
+        let character_type = if i < 2 {
+            CharacterType::A
+        } else {
+            CharacterType::N
+        };
+        prefix.push(correct(c, character_type)?);
+    }
+    let pattern = prefix
+        .get(0..2)
+        .and_then(countries::country_pattern)
+        .ok_or(RecoverOcrError::UnknownCountry)?;
+    if scanned.len() != countries::len(pattern) + 4 {
+        return Err(RecoverOcrError::InvalidLength);
+    }
+
+    // Correct the characters at positions that allow a single type, and
+    // remember the positions at which both readings are possible.
+    let mut corrected = ArrayString::<MAX_IBAN_LEN>::new();
+    corrected.push_str(&prefix);
+    let mut ambiguous = Vec::new();
+    let bban_types = pattern
+        .iter()
+        .flat_map(|(count, character_type)| (0..*count).map(move |_| *character_type));
+    for (i, (c, character_type)) in scanned.chars().skip(4).zip(bban_types).enumerate() {
+        if character_type == CharacterType::C && look_alike(c).is_some() {
+            ambiguous.push(i + 4);
+        }
+        corrected.push(correct(c, character_type)?);
+    }
+    if ambiguous.len() > MAX_AMBIGUOUS_POSITIONS {
+        return Err(RecoverOcrError::TooAmbiguous);
+    }
+
+    // Try every combination of readings at the ambiguous positions.
+    let mut candidates = Vec::new();
+    for combination in 0..1_u32 << ambiguous.len() {
+        let mut reading = ArrayString::<MAX_IBAN_LEN>::new();
+        for (i, c) in corrected.chars().enumerate() {
+            let flip = ambiguous
+                .iter()
+                .position(|&position| position == i)
+                .is_some_and(|bit| combination & (1 << bit) != 0);
+            reading.push(if flip { look_alike(c).unwrap_or(c) } else { c });
+        }
+        if let Ok(iban) = reading.parse::<Iban>() {
+            let corrections = scanned
+                .chars()
+                .zip(reading.chars())
+                .filter(|(a, b)| a != b)
+                .count();
+            let weight = 0.5_f64.powi(combination.count_ones() as i32);
+            candidates.push(OcrCandidate {
+                iban,
+                corrections,
+                confidence: weight,
+            });
+        }
+    }
+    if candidates.is_empty() {
+        return Err(RecoverOcrError::NoValidReading);
+    }
+    let total: f64 = candidates
+        .iter()
+        .map(|candidate| candidate.confidence)
+        .sum();
+    for candidate in &mut candidates {
+        candidate.confidence /= total;
+    }
+    candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    Ok(OcrRecovery { candidates })
+}
@@ -0,0 +1,154 @@
+//! A total ordering of IBANs that groups IBANs of the same bank and branch.
+
+use crate::components::component_range;
+use crate::{BaseIban, ComponentKind, Iban, IbanLike};
+use core::cmp::Ordering;
+use core::ops::Range;
+
+/// Get the positions of the bank and branch identifiers in an IBAN in the
+/// electronic format. Dependent territories use the positions of the country
+/// whose format they follow.
+pub(crate) fn identifier_ranges(electronic: &str) -> (Option<Range<usize>>, Option<Range<usize>>) {
+    let country_code = &electronic[0..2];
+    let bban = &electronic[4..];
+    let range = |kind| {
+        component_range(country_code, bban, kind)
+            .filter(|range| bban.get(range.clone()).is_some())
+            .map(|range| range.start + 4..range.end + 4)
+    };
+    (
+        range(ComponentKind::BankIdentifier),
+        range(ComponentKind::BranchIdentifier),
+    )
+}
+
+/// Get the bank and branch identifiers of an IBAN in the electronic format.
+pub(crate) fn identifiers(electronic: &str) -> (Option<&str>, Option<&str>) {
+    let (bank, branch) = identifier_ranges(electronic);
+    (
+        bank.map(|range| &electronic[range]),
+        branch.map(|range| &electronic[range]),
+    )
+}
+
+/// Compare two IBANs in the electronic format, given their bank and branch
+/// identifiers. See [`compare`].
+pub(crate) fn compare_with_identifiers(
+    a: &str,
+    (a_bank, a_branch): (Option<&str>, Option<&str>),
+    b: &str,
+    (b_bank, b_branch): (Option<&str>, Option<&str>),
+) -> Ordering {
+    a[0..2]
+        .cmp(&b[0..2])
+        .then_with(|| a_bank.cmp(&b_bank))
+        .then_with(|| a_branch.cmp(&b_branch))
+        .then_with(|| a[4..].cmp(&b[4..]))
+        .then_with(|| a[2..4].cmp(&b[2..4]))
+}
+
+/// Compare two IBANs in the electronic format by country code, bank
+/// identifier, branch identifier, BBAN and finally check digits. The last
+/// comparison makes the ordering consistent with equality.
+fn compare(a: &str, b: &str) -> Ordering {
+    compare_with_identifiers(a, identifiers(a), b, identifiers(b))
+}
+
+/// IBANs are ordered by country code, then by bank identifier, branch
+/// identifier and the rest of the BBAN. IBANs of the same bank are therefore
+/// adjacent when sorted, even if the bank identifier is not at the start of
+/// the BBAN. IBANs that differ only in their check digits are ordered by
+/// them.
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let mut ibans: Vec<BaseIban> = [
+///     "NL91 ABNA 0417 1643 00",
+///     "DE44 5001 0517 5407 3249 31",
+///     "BE68 5390 0754 7034",
+/// ]
+/// .iter()
+/// .map(|s| s.parse())
+/// .collect::<Result<_, _>>()?;
+/// ibans.sort();
+/// assert_eq!(ibans[0].country_code(), "BE");
+/// assert_eq!(ibans[2].country_code(), "NL");
+/// # Ok::<(), ParseBaseIbanError>(())
+/// ```
+impl Ord for BaseIban {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(self.electronic_str(), other.electronic_str())
+    }
+}
+
+impl PartialOrd for BaseIban {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// IBANs are ordered by country code, then by bank identifier, branch
+/// identifier and the rest of the BBAN. See the implementation for
+/// [`BaseIban`].
+impl Ord for Iban {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(self.electronic_str(), other.electronic_str())
+    }
+}
+
+impl PartialOrd for Iban {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Iban {
+    /// Check whether two IBANs belong to the same bank, meaning that they
+    /// have the same country code and bank identifier. Returns `false` if the
+    /// bank identifier is not defined for the country.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let a: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    /// let b: Iban = "GB07 NWBK 6016 1331 9268 27".parse()?;
+    /// let c: Iban = "GB33 BUKB 2020 1555 5555 55".parse()?;
+    /// assert!(a.same_bank(&b));
+    /// assert!(!a.same_bank(&c));
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[must_use]
+    pub fn same_bank(&self, other: &Iban) -> bool {
+        self.country_code() == other.country_code()
+            && self.bank_identifier().is_some()
+            && self.bank_identifier() == other.bank_identifier()
+    }
+
+    /// Check whether two IBANs belong to the same branch, meaning that they
+    /// belong to the [same bank](Iban::same_bank) and have the same branch
+    /// identifier. Returns `false` if the branch identifier is not defined
+    /// for the country.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let a: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    /// let b: Iban = "GB07 NWBK 6016 1331 9268 27".parse()?;
+    /// assert!(a.same_branch(&b));
+    ///
+    /// // Dutch IBANs have no branch identifier.
+    /// let nl: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+    /// assert!(!nl.same_branch(&nl));
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[must_use]
+    pub fn same_branch(&self, other: &Iban) -> bool {
+        self.same_bank(other)
+            && self.branch_identifier().is_some()
+            && self.branch_identifier() == other.branch_identifier()
+    }
+}
@@ -0,0 +1,547 @@
+//! A registry of country formats that can be loaded at runtime. This makes it
+//! possible to validate IBANs of countries that were added to the IBAN
+//! registry after the release of this crate, or to add custom countries for
+//! testing.
+
+use crate::countries::{self, CharacterType};
+use crate::{BaseIban, BbanFormat, CountryCode, Iban, IbanLike, ParseIbanError};
+use core::error::Error;
+use core::fmt::{self, Display};
+use core::str::FromStr;
+use std::collections::HashMap;
+use std::io::{self, Read};
+use std::string::String;
+use std::vec::Vec;
+
+/// The structure of a BBAN, as described in the registry notation. For
+/// example, the German BBAN consists of 8 digits followed by 10 digits,
+/// written as `8!n10!n`.
+///
+/// # Example
+/// ```rust
+/// use iban::{BbanStructure, CharacterType, ParseStructureError};
+/// let structure: BbanStructure = "4!a6!n8!n".parse()?;
+/// assert_eq!(
+///     structure.segments(),
+///     &[(4, CharacterType::A), (6, CharacterType::N), (8, CharacterType::N)]
+/// );
+/// assert_eq!(structure.len(), 18);
+/// assert!(structure.matches("NWBK60161331926819"));
+/// assert_eq!(structure.to_string(), "4!a6!n8!n");
+/// # Ok::<(), ParseStructureError>(())
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BbanStructure {
+    segments: Vec<(usize, CharacterType)>,
+}
+
+impl BbanStructure {
+    /// Get the segments of the structure. Every segment consists of a number
+    /// of characters of the same type.
+    #[inline]
+    #[must_use]
+    pub fn segments(&self) -> &[(usize, CharacterType)] {
+        &self.segments
+    }
+
+    /// Get the length of a BBAN following this structure.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.as_format().len()
+    }
+
+    /// Check whether the structure is empty, i.e. whether it only matches an
+    /// empty BBAN.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Check whether a BBAN follows this structure. The BBAN should be in the
+    /// electronic format, with uppercase letters.
+    #[inline]
+    #[must_use]
+    pub fn matches(&self, bban: &str) -> bool {
+        self.as_format().matches(bban)
+    }
+
+    /// View the structure as a [`BbanFormat`], for example to render it as a
+    /// regular expression.
+    #[inline]
+    #[must_use]
+    pub fn as_format(&self) -> BbanFormat<'_> {
+        BbanFormat::new(&self.segments)
+    }
+}
+
+impl From<&[(usize, CharacterType)]> for BbanStructure {
+    fn from(segments: &[(usize, CharacterType)]) -> BbanStructure {
+        BbanStructure {
+            segments: segments.to_vec(),
+        }
+    }
+}
+
+impl FromStr for BbanStructure {
+    type Err = ParseStructureError;
+    /// Parse a structure in the registry notation, such as `8!n10!n`. Every
+    /// segment consists of a length, an exclamation mark indicating a fixed
+    /// length and a character type: `n` for digits, `a` for uppercase letters
+    /// and `c` for both.
+    ///
+    /// # Errors
+    /// If the string does not follow the notation, a [`ParseStructureError`]
+    /// will be returned.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = Vec::new();
+        let mut rest = s.trim();
+        if rest.is_empty() {
+            return Err(ParseStructureError);
+        }
+        while !rest.is_empty() {
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .ok_or(ParseStructureError)?;
+            let count: usize = rest[..digits].parse().map_err(|_| ParseStructureError)?;
+            rest = rest[digits..]
+                .strip_prefix('!')
+                .ok_or(ParseStructureError)?;
+            let mut chars = rest.chars();
+            let character_type = chars
+                .next()
+                .and_then(CharacterType::from_notation)
+                .ok_or(ParseStructureError)?;
+            if count == 0 {
+                return Err(ParseStructureError);
+            }
+            segments.push((count, character_type));
+            rest = chars.as_str();
+        }
+        Ok(BbanStructure { segments })
+    }
+}
+
+impl Display for BbanStructure {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.as_format(), f)
+    }
+}
+
+/// An error indicating that a string does not follow the registry notation
+/// for a BBAN structure.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ParseStructureError;
+
+impl fmt::Display for ParseStructureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the string doesn't follow the BBAN structure notation")
+    }
+}
+
+impl Error for ParseStructureError {}
+
+/// An error indicating that a BBAN structure cannot be used for a country that
+/// is compiled into this crate, because it has a different length. The bank
+/// and branch identifiers and the other components of an [`Iban`] are located
+/// using the positions of the built-in format, which would not fit.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct IncompatibleStructureError;
+
+impl fmt::Display for IncompatibleStructureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the BBAN structure has a different length than the built-in format of the country"
+        )
+    }
+}
+
+impl Error for IncompatibleStructureError {}
+
+/// An error indicating that a registry could not be loaded.
+#[derive(Debug)]
+pub enum LoadRegistryError {
+    /// The input could not be read.
+    Io(io::Error),
+    /// A row that is required is missing from the SWIFT registry file.
+    MissingRow(&'static str),
+    /// The line does not have the expected layout. For the SWIFT registry
+    /// file, this is the column of the country instead.
+    InvalidLayout {
+        /// The line or column, starting at 1.
+        line: usize,
+    },
+    /// The country code is not valid.
+    InvalidCountryCode {
+        /// The line or column, starting at 1.
+        line: usize,
+    },
+    /// The BBAN structure does not follow the registry notation.
+    InvalidStructure {
+        /// The line or column, starting at 1.
+        line: usize,
+        /// The error that occurred while parsing the structure.
+        source: ParseStructureError,
+    },
+    /// The BBAN structure of a built-in country has a different length than
+    /// the built-in format.
+    IncompatibleStructure {
+        /// The line or column, starting at 1.
+        line: usize,
+        /// The error indicating that the structure is incompatible.
+        source: IncompatibleStructureError,
+    },
+}
+
+impl fmt::Display for LoadRegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadRegistryError::Io(_) => write!(f, "the registry could not be read"),
+            LoadRegistryError::MissingRow(row) => {
+                write!(f, "the registry doesn't contain the row \"{row}\"")
+            }
+            LoadRegistryError::InvalidLayout { line } => {
+                write!(f, "the registry has an invalid layout at {line}")
+            }
+            LoadRegistryError::InvalidCountryCode { line } => {
+                write!(f, "the registry has an invalid country code at {line}")
+            }
+            LoadRegistryError::InvalidStructure { line, .. } => {
+                write!(f, "the registry has an invalid BBAN structure at {line}")
+            }
+            LoadRegistryError::IncompatibleStructure { line, .. } => write!(
+                f,
+                "the registry has a BBAN structure that is incompatible with the built-in format at {line}"
+            ),
+        }
+    }
+}
+
+impl Error for LoadRegistryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoadRegistryError::Io(source) => Some(source),
+            LoadRegistryError::InvalidStructure { source, .. } => Some(source),
+            LoadRegistryError::IncompatibleStructure { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for LoadRegistryError {
+    #[inline]
+    fn from(source: io::Error) -> LoadRegistryError {
+        LoadRegistryError::Io(source)
+    }
+}
+
+/// A collection of BBAN structures per country, which can be used instead of
+/// the registry that is compiled into this crate. A `Registry` can be loaded
+/// from the SWIFT registry file, from a simple notation file or be built
+/// manually. Countries that are compiled into this crate can only be given a
+/// structure of the same length, see [`Registry::insert`].
+///
+/// # Example
+/// ```rust
+/// use iban::{IbanLike, Registry};
+/// # use core::error::Error;
+///
+/// // Start with the built-in registry and add a country for testing.
+/// let mut registry = Registry::builtin();
+/// registry.extend(Registry::parse_notation("XX: 4!a10!n")?);
+///
+/// let iban = registry.parse_iban("XX87 ABCD 0123 4567 89")?;
+/// assert_eq!(iban.bban(), "ABCD0123456789");
+///
+/// // Countries from the built-in registry are still validated.
+/// assert!(registry.parse_iban("DE44 5001 0517 5407 3249 31").is_ok());
+/// # Ok::<(), Box<dyn Error>>(())
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct Registry {
+    structures: HashMap<CountryCode, BbanStructure>,
+}
+
+impl Registry {
+    /// Create an empty registry.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    /// Create a registry containing the countries that are compiled into
+    /// this crate. This includes dependent territories that use the format of
+    /// their parent country, and the countries that are not in the IBAN
+    /// registry if the `extended_countries` feature is enabled.
+    #[must_use]
+    pub fn builtin() -> Registry {
+        Registry {
+            structures: (b'A'..=b'Z')
+                .flat_map(|a| (b'A'..=b'Z').map(move |b| [a, b]))
+                .filter_map(|code| {
+                    let country_code = core::str::from_utf8(&code).ok()?;
+                    Some((
+                        country_code.parse().ok()?,
+                        countries::country_pattern(country_code)?.into(),
+                    ))
+                })
+                .collect(),
+        }
+    }
+
+    /// Add the structure of a country, returning the previous structure if
+    /// the country was already present. A country that is compiled into this
+    /// crate can only get a structure of the same length as the built-in
+    /// format, since its bank and branch identifiers are located using the
+    /// built-in positions.
+    ///
+    /// # Errors
+    /// If the country is compiled into this crate and the structure has a
+    /// different length, an [`IncompatibleStructureError`] is returned and
+    /// the registry is not changed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::{IncompatibleStructureError, Registry};
+    /// # use core::error::Error;
+    /// let mut registry = Registry::new();
+    /// // Letters are allowed in the account number, but the length is unchanged.
+    /// registry.insert("DE".parse()?, "8!n10!c".parse()?)?;
+    /// assert_eq!(
+    ///     registry.insert("DE".parse()?, "8!n12!n".parse()?),
+    ///     Err(IncompatibleStructureError)
+    /// );
+    /// # Ok::<(), Box<dyn Error>>(())
+    /// ```
+    pub fn insert(
+        &mut self,
+        country_code: CountryCode,
+        structure: BbanStructure,
+    ) -> Result<Option<BbanStructure>, IncompatibleStructureError> {
+        if countries::country_pattern(country_code.as_str())
+            .is_some_and(|pattern| BbanFormat::new(pattern).len() != structure.len())
+        {
+            return Err(IncompatibleStructureError);
+        }
+        Ok(self.structures.insert(country_code, structure))
+    }
+
+    /// Add all countries of another registry. Countries that are in both
+    /// registries get the structure of the other registry.
+    #[inline]
+    pub fn extend(&mut self, other: Registry) {
+        self.structures.extend(other.structures);
+    }
+
+    /// Get the BBAN structure of a country.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, country_code: &str) -> Option<&BbanStructure> {
+        self.structures.get(&country_code.parse().ok()?)
+    }
+
+    /// Iterate over all countries and their structures, in no particular
+    /// order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&CountryCode, &BbanStructure)> {
+        self.structures.iter()
+    }
+
+    /// Get the number of countries in the registry.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.structures.len()
+    }
+
+    /// Check whether the registry contains no countries.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.structures.is_empty()
+    }
+
+    /// Parse a registry in the simple notation format. Every line contains a
+    /// country code, a colon and the BBAN structure. Empty lines and lines
+    /// starting with `#` are ignored.
+    /// ```text
+    /// # Germany
+    /// DE: 8!n10!n
+    /// GB: 4!a6!n8!n
+    /// ```
+    ///
+    /// # Errors
+    /// Returns a [`LoadRegistryError`] with the line number of the first line
+    /// that could not be parsed.
+    pub fn parse_notation(s: &str) -> Result<Registry, LoadRegistryError> {
+        let mut registry = Registry::new();
+        for (i, line) in s.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (country_code, structure) = line
+                .split_once(':')
+                .ok_or(LoadRegistryError::InvalidLayout { line: line_number })?;
+            registry
+                .insert(
+                    country_code
+                        .trim()
+                        .parse()
+                        .map_err(|_| LoadRegistryError::InvalidCountryCode { line: line_number })?,
+                    structure
+                        .parse()
+                        .map_err(|source| LoadRegistryError::InvalidStructure {
+                            line: line_number,
+                            source,
+                        })?,
+                )
+                .map_err(|source| LoadRegistryError::IncompatibleStructure {
+                    line: line_number,
+                    source,
+                })?;
+        }
+        Ok(registry)
+    }
+
+    /// Parse the text version of the IBAN registry as published by SWIFT.
+    /// The file is tab-separated and contains one column per country. Only
+    /// the country code and the BBAN structure are used.
+    ///
+    /// # Errors
+    /// Returns a [`LoadRegistryError`] if a required row is missing or if a
+    /// country contains an invalid country code or structure.
+    pub fn parse_swift_txt(s: &str) -> Result<Registry, LoadRegistryError> {
+        const COUNTRY_CODE_ROW: &str = "IBAN prefix country code";
+        const STRUCTURE_ROW: &str = "BBAN structure";
+
+        let rows = split_separated(s, '\t');
+        let find_row = |label: &'static str| {
+            rows.iter()
+                .find(|row| row.first().is_some_and(|first| first.starts_with(label)))
+                .ok_or(LoadRegistryError::MissingRow(label))
+        };
+        let country_codes = find_row(COUNTRY_CODE_ROW)?;
+        let structures = find_row(STRUCTURE_ROW)?;
+
+        let mut registry = Registry::new();
+        for (column, country_code) in country_codes.iter().enumerate().skip(1) {
+            let structure = structures
+                .get(column)
+                .ok_or(LoadRegistryError::InvalidLayout { line: column })?;
+            registry
+                .insert(
+                    country_code
+                        .parse()
+                        .map_err(|_| LoadRegistryError::InvalidCountryCode { line: column })?,
+                    structure
+                        .parse()
+                        .map_err(|source| LoadRegistryError::InvalidStructure {
+                            line: column,
+                            source,
+                        })?,
+                )
+                .map_err(|source| LoadRegistryError::IncompatibleStructure {
+                    line: column,
+                    source,
+                })?;
+        }
+        Ok(registry)
+    }
+
+    /// Read the text version of the IBAN registry as published by SWIFT. The
+    /// published file is not always valid UTF-8, so invalid characters are
+    /// replaced before parsing. See [`Registry::parse_swift_txt`].
+    ///
+    /// # Errors
+    /// Returns a [`LoadRegistryError`] if the input could not be read or
+    /// parsed.
+    pub fn read_swift_txt(mut reader: impl Read) -> Result<Registry, LoadRegistryError> {
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        Registry::parse_swift_txt(&String::from_utf8_lossy(&buf))
+    }
+
+    /// Read a registry in the simple notation format. See
+    /// [`Registry::parse_notation`].
+    ///
+    /// # Errors
+    /// Returns a [`LoadRegistryError`] if the input could not be read or
+    /// parsed.
+    pub fn read_notation(mut reader: impl Read) -> Result<Registry, LoadRegistryError> {
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        Registry::parse_notation(&s)
+    }
+
+    /// Validate the BBAN of a [`BaseIban`] using this registry instead of the
+    /// built-in registry.
+    ///
+    /// # Errors
+    /// If the country is not in this registry,
+    /// [`ParseIbanError::UnknownCountry`] is returned. If the BBAN does not
+    /// follow the structure, [`ParseIbanError::InvalidBban`] is returned.
+    pub fn validate(&self, base_iban: BaseIban) -> Result<Iban, ParseIbanError> {
+        match self.get(base_iban.country_code()) {
+            None => Err(ParseIbanError::UnknownCountry(base_iban)),
+            Some(structure) if structure.matches(base_iban.bban_unchecked()) => {
+                Ok(Iban { base_iban })
+            }
+            Some(_) => Err(ParseIbanError::InvalidBban(base_iban)),
+        }
+    }
+
+    /// Parse an IBAN, validating the BBAN using this registry instead of the
+    /// built-in registry.
+    ///
+    /// # Errors
+    /// If the string does not match the IBAN format or the checksum is
+    /// invalid, [`ParseIbanError::InvalidBaseIban`] will be returned. If the
+    /// country format is invalid or unknown, the other variants will be
+    /// returned with the [`BaseIban`] giving access to some basic
+    /// functionality nonetheless.
+    pub fn parse_iban(&self, s: &str) -> Result<Iban, ParseIbanError> {
+        self.validate(s.parse::<BaseIban>()?)
+    }
+}
+
+/// Split a tab- or comma-separated file into rows of trimmed fields. Fields
+/// can be enclosed in double quotes, in which case they may contain the
+/// separator and line breaks, and a double quote is escaped as two double
+/// quotes.
+pub(crate) fn split_separated(s: &str, separator: char) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' if in_quotes => in_quotes = false,
+            '"' if field.trim().is_empty() => in_quotes = true,
+            c if c == separator && !in_quotes => {
+                row.push(core::mem::take(&mut field).trim().into())
+            }
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                row.push(core::mem::take(&mut field).trim().into());
+                rows.push(core::mem::take(&mut row));
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field.trim().into());
+        rows.push(row);
+    }
+    rows
+}
@@ -0,0 +1,277 @@
+//! The reachability of IBANs through the Single Euro Payments Area. Which
+//! countries of the registry take part follows from the registry, but the
+//! membership of the European Economic Area and of the dependent territories
+//! is curated by hand.
+
+use crate::{Country, CountryInfo, IbanLike};
+use core::error::Error;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The countries of the IBAN registry that are part of the European Economic
+/// Area: the member states of the European Union, Iceland, Liechtenstein and
+/// Norway.
+const EEA: &[&str] = &[
+    "AT", "BE", "BG", "CY", "CZ", "DE", "DK", "EE", "ES", "FI", "FR", "GR", "HR", "HU", "IE", "IS",
+    "IT", "LI", "LT", "LU", "LV", "MT", "NL", "NO", "PL", "PT", "RO", "SE", "SI", "SK",
+];
+
+/// A dependent territory that takes part in SEPA but is not in the IBAN
+/// registry.
+struct Territory {
+    country_code: &'static str,
+    /// Whether the territory is part of the European Economic Area.
+    eea: bool,
+    /// Whether the territory is part of the country whose format it uses, so
+    /// that transfers between them are domestic.
+    domestic: bool,
+}
+
+/// The dependent territories that take part in SEPA. Other territories, such
+/// as New Caledonia, use the format of a SEPA country but do not take part.
+const TERRITORIES: &[Territory] = &[
+    // Åland Islands
+    Territory {
+        country_code: "AX",
+        eea: true,
+        domestic: true,
+    },
+    // Saint Barthélemy
+    Territory {
+        country_code: "BL",
+        eea: false,
+        domestic: true,
+    },
+    // French Guiana
+    Territory {
+        country_code: "GF",
+        eea: true,
+        domestic: true,
+    },
+    // Guernsey
+    Territory {
+        country_code: "GG",
+        eea: false,
+        domestic: false,
+    },
+    // Guadeloupe
+    Territory {
+        country_code: "GP",
+        eea: true,
+        domestic: true,
+    },
+    // Isle of Man
+    Territory {
+        country_code: "IM",
+        eea: false,
+        domestic: false,
+    },
+    // Jersey
+    Territory {
+        country_code: "JE",
+        eea: false,
+        domestic: false,
+    },
+    // Saint Martin
+    Territory {
+        country_code: "MF",
+        eea: true,
+        domestic: true,
+    },
+    // Martinique
+    Territory {
+        country_code: "MQ",
+        eea: true,
+        domestic: true,
+    },
+    // Saint Pierre and Miquelon
+    Territory {
+        country_code: "PM",
+        eea: false,
+        domestic: true,
+    },
+    // Réunion
+    Territory {
+        country_code: "RE",
+        eea: true,
+        domestic: true,
+    },
+    // Mayotte
+    Territory {
+        country_code: "YT",
+        eea: true,
+        domestic: true,
+    },
+];
+
+/// Find the territory with the given country code.
+fn territory(country_code: &str) -> Option<&'static Territory> {
+    TERRITORIES
+        .iter()
+        .find(|territory| territory.country_code == country_code)
+}
+
+impl Country {
+    /// Check whether the country takes part in the Single Euro Payments Area.
+    /// For countries in the IBAN registry, this is [`CountryInfo::is_sepa`].
+    /// Dependent territories that take part, such as Jersey or Réunion, are
+    /// included, but territories such as New Caledonia that only use the
+    /// format of a SEPA country are not.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::{Country, ParseCountryError};
+    /// assert!(Country::CH.is_sepa());
+    /// assert!(!Country::KZ.is_sepa());
+    /// assert!("JE".parse::<Country>()?.is_sepa());
+    /// assert!(!"NC".parse::<Country>()?.is_sepa());
+    /// # Ok::<(), ParseCountryError>(())
+    /// ```
+    #[must_use]
+    pub fn is_sepa(&self) -> bool {
+        territory(self.as_str()).is_some() || self.info().is_some_and(CountryInfo::is_sepa)
+    }
+
+    /// Check whether the country is part of the European Economic Area. Within
+    /// the EEA, a BIC is not required for a SEPA transfer.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::{Country, ParseCountryError};
+    /// assert!(Country::NO.is_eea());
+    /// assert!(!Country::CH.is_eea());
+    /// assert!("RE".parse::<Country>()?.is_eea());
+    /// # Ok::<(), ParseCountryError>(())
+    /// ```
+    #[must_use]
+    pub fn is_eea(&self) -> bool {
+        EEA.contains(&self.as_str())
+            || territory(self.as_str()).is_some_and(|territory| territory.eea)
+    }
+
+    /// Get the country that is considered the same for domestic transfers.
+    fn sovereign(self) -> Country {
+        match (territory(self.as_str()), self.parent()) {
+            (Some(territory), Some(parent)) if territory.domestic => parent,
+            _ => self,
+        }
+    }
+}
+
+/// Whether a SEPA transfer stays within a country.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SepaScope {
+    /// The debtor and creditor are in the same country. Territories that are
+    /// part of a country, such as Réunion of France, count as that country.
+    Domestic,
+    /// The debtor and creditor are in different countries.
+    CrossBorder,
+}
+
+/// A SEPA credit transfer between two IBANs, obtained via [`sepa_transfer`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SepaTransfer {
+    scope: SepaScope,
+    bic_required: bool,
+}
+
+impl SepaTransfer {
+    /// Get whether the transfer is domestic or cross-border.
+    #[inline]
+    #[must_use]
+    pub fn scope(&self) -> SepaScope {
+        self.scope
+    }
+
+    /// Check whether the BIC of the creditor must be provided. This is the
+    /// case if the debtor or the creditor is outside the European Economic
+    /// Area, such as in Switzerland or the United Kingdom.
+    #[inline]
+    #[must_use]
+    pub fn bic_required(&self) -> bool {
+        self.bic_required
+    }
+}
+
+/// An error indicating that a SEPA credit transfer between two IBANs is not
+/// possible, because one of them is not in a SEPA country.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SepaTransferError {
+    /// The country of the debtor does not take part in SEPA.
+    DebtorNotSepa(Country),
+    /// The country of the creditor does not take part in SEPA.
+    CreditorNotSepa(Country),
+}
+
+impl fmt::Display for SepaTransferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SepaTransferError::DebtorNotSepa(country) => write!(
+                f,
+                "the country of the debtor, {}, is not part of SEPA",
+                country.as_str()
+            ),
+            SepaTransferError::CreditorNotSepa(country) => write!(
+                f,
+                "the country of the creditor, {}, is not part of SEPA",
+                country.as_str()
+            ),
+        }
+    }
+}
+
+impl Error for SepaTransferError {}
+
+/// Determine whether a SEPA credit transfer from the debtor to the creditor
+/// is possible, whether it is domestic or cross-border and whether a BIC is
+/// required. See [`Country::is_sepa`] and [`Country::is_eea`].
+///
+/// # Errors
+/// If the debtor or the creditor is not in a SEPA country, a
+/// [`SepaTransferError`] is returned.
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let german: Iban = "DE44 5001 0517 5407 3249 31".parse()?;
+/// let dutch: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+/// let british: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+///
+/// let transfer = sepa_transfer(&german, &dutch)?;
+/// assert_eq!(transfer.scope(), SepaScope::CrossBorder);
+/// assert!(!transfer.bic_required());
+///
+/// // The United Kingdom is not part of the European Economic Area.
+/// assert!(sepa_transfer(&german, &british)?.bic_required());
+///
+/// let kazakh: Iban = "KZ86 125K ZT50 0410 0100".parse()?;
+/// assert_eq!(
+///     sepa_transfer(&german, &kazakh),
+///     Err(SepaTransferError::CreditorNotSepa(Country::KZ))
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn sepa_transfer(
+    debtor: &impl IbanLike,
+    creditor: &impl IbanLike,
+) -> Result<SepaTransfer, SepaTransferError> {
+    let (debtor, creditor) = (debtor.country(), creditor.country());
+    if !debtor.is_sepa() {
+        return Err(SepaTransferError::DebtorNotSepa(debtor));
+    }
+    if !creditor.is_sepa() {
+        return Err(SepaTransferError::CreditorNotSepa(creditor));
+    }
+    Ok(SepaTransfer {
+        scope: if debtor.sovereign() == creditor.sovereign() {
+            SepaScope::Domestic
+        } else {
+            SepaScope::CrossBorder
+        },
+        bic_required: !(debtor.is_eea() && creditor.is_eea()),
+    })
+}
@@ -0,0 +1,249 @@
+//! Detection of IBANs that are suspiciously similar, for example because of
+//! a typing error or an attempt at fraud.
+
+use crate::base_iban::MAX_IBAN_LEN;
+use crate::{BaseIban, Iban, IbanLike};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// The way in which two IBANs are similar. The variants are ordered from most
+/// to least similar.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SimilarityKind {
+    /// The IBANs are equal.
+    Identical,
+    /// The IBANs have the same country code and BBAN, but different check
+    /// digits. Since the checksum determines the check digits up to a
+    /// multiple of 97 and check digits 00 and 01 are rejected, this only
+    /// occurs for check digits 02 and 99. The standard algorithm never
+    /// computes 99, but it does pass the checksum.
+    CheckDigits,
+    /// The IBANs have the same BBAN, but a different country code. This
+    /// occurs for dependent territories that use the format of another
+    /// country, such as Jersey and the United Kingdom.
+    CountryPrefix,
+    /// The IBANs have the same country code, and the BBANs differ in two
+    /// adjacent characters that are swapped.
+    Transposition,
+    /// The IBANs have the same country code, and the BBANs differ in a single
+    /// character that is replaced, added or removed.
+    SingleEdit,
+    /// The IBANs are not similar in any of the other ways.
+    Different,
+}
+
+impl SimilarityKind {
+    /// Check whether two IBANs of this kind are likely duplicates of each
+    /// other, meaning that the kind is not [`SimilarityKind::Different`].
+    #[inline]
+    #[must_use]
+    pub fn is_likely_duplicate(self) -> bool {
+        self != SimilarityKind::Different
+    }
+}
+
+/// The similarity of two IBANs. It is obtained via [`Iban::similarity`] or
+/// [`BaseIban::similarity`].
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let a: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+/// let b: Iban = "GB25 NWBK 6016 1331 9268 91".parse()?;
+/// let similarity = a.similarity(&b);
+/// assert_eq!(similarity.kind(), SimilarityKind::Transposition);
+/// // Both the check digits and the BBAN differ.
+/// assert_eq!(similarity.distance(), 2);
+/// # Ok::<(), ParseIbanError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Similarity {
+    distance: usize,
+    kind: SimilarityKind,
+}
+
+impl Similarity {
+    /// Get the edit distance between the IBANs in the electronic format. It
+    /// is the number of characters that must be replaced, added, removed or
+    /// swapped with an adjacent character to turn one into the other.
+    #[inline]
+    #[must_use]
+    pub fn distance(&self) -> usize {
+        self.distance
+    }
+
+    /// Get the way in which the IBANs are similar.
+    #[inline]
+    #[must_use]
+    pub fn kind(&self) -> SimilarityKind {
+        self.kind
+    }
+}
+
+/// Compute the optimal string alignment distance between two strings of at
+/// most [`MAX_IBAN_LEN`] bytes. This is the Levenshtein distance where the
+/// swap of two adjacent characters counts as a single edit.
+fn edit_distance(a: &[u8], b: &[u8]) -> usize {
+    // Only the last three rows of the matrix are needed.
+    let mut before_previous = [0; MAX_IBAN_LEN + 1];
+    let mut previous: [usize; MAX_IBAN_LEN + 1] = core::array::from_fn(|j| j);
+    let mut current = [0; MAX_IBAN_LEN + 1];
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before_previous[j - 2] + 1);
+            }
+        }
+        before_previous = previous;
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Check whether two strings of equal length differ only in two adjacent
+/// characters that are swapped.
+fn is_transposition(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b).position(|(x, y)| x != y).is_some_and(|i| {
+            i + 1 < a.len() && a[i] == b[i + 1] && a[i + 1] == b[i] && a[i + 2..] == b[i + 2..]
+        })
+}
+
+/// Compare two IBANs in the electronic format.
+fn similarity(a: &str, b: &str) -> Similarity {
+    let (a_country, a_bban) = (&a[0..2], &a.as_bytes()[4..]);
+    let (b_country, b_bban) = (&b[0..2], &b.as_bytes()[4..]);
+    let kind = if a == b {
+        SimilarityKind::Identical
+    } else if a_bban == b_bban && a_country == b_country {
+        SimilarityKind::CheckDigits
+    } else if a_bban == b_bban {
+        SimilarityKind::CountryPrefix
+    } else if a_country != b_country {
+        SimilarityKind::Different
+    } else if is_transposition(a_bban, b_bban) {
+        SimilarityKind::Transposition
+    } else if edit_distance(a_bban, b_bban) == 1 {
+        SimilarityKind::SingleEdit
+    } else {
+        SimilarityKind::Different
+    };
+    Similarity {
+        distance: edit_distance(a.as_bytes(), b.as_bytes()),
+        kind,
+    }
+}
+
+impl BaseIban {
+    /// Compare this IBAN to another IBAN, to detect near-duplicates. See
+    /// [`Similarity`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let a: BaseIban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    /// let b: BaseIban = "JE90 NWBK 6016 1331 9268 19".parse()?;
+    /// assert_eq!(a.similarity(&b).kind(), SimilarityKind::CountryPrefix);
+    /// # Ok::<(), ParseBaseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn similarity(&self, other: &BaseIban) -> Similarity {
+        similarity(self.electronic_str(), other.electronic_str())
+    }
+}
+
+impl Iban {
+    /// Compare this IBAN to another IBAN, to detect near-duplicates. See
+    /// [`Similarity`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::*;
+    /// let a: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    /// let b: Iban = "GB56 NWBK 6016 1331 9268 18".parse()?;
+    /// assert_eq!(a.similarity(&b).kind(), SimilarityKind::SingleEdit);
+    /// assert!(a.similarity(&b).kind().is_likely_duplicate());
+    /// # Ok::<(), ParseIbanError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn similarity(&self, other: &Iban) -> Similarity {
+        similarity(self.electronic_str(), other.electronic_str())
+    }
+}
+
+/// Group IBANs that are [likely duplicates](SimilarityKind::is_likely_duplicate)
+/// of each other. Two IBANs are in the same group if they are connected by a
+/// chain of likely duplicates. Only groups of at least two IBANs are
+/// returned, in the order of their first IBAN. Within a group, the IBANs keep
+/// their order.
+///
+/// Every pair of IBANs is compared, so this takes time quadratic in the number
+/// of IBANs.
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let ibans: Vec<Iban> = [
+///     "GB29 NWBK 6016 1331 9268 19",
+///     "NL91 ABNA 0417 1643 00",
+///     "GB25 NWBK 6016 1331 9268 91",
+///     "JE90 NWBK 6016 1331 9268 19",
+/// ]
+/// .iter()
+/// .map(|s| s.parse())
+/// .collect::<Result<_, _>>()?;
+/// let clusters = cluster_similar(&ibans);
+/// assert_eq!(clusters, [vec![&ibans[0], &ibans[2], &ibans[3]]]);
+/// # Ok::<(), ParseIbanError>(())
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn cluster_similar<T: IbanLike>(ibans: &[T]) -> Vec<Vec<&T>> {
+    /// Find the representative of a group, compressing the path to it.
+    fn find(parents: &mut [usize], mut i: usize) -> usize {
+        while parents[i] != i {
+            parents[i] = parents[parents[i]];
+            i = parents[i];
+        }
+        i
+    }
+
+    let mut parents: Vec<usize> = (0..ibans.len()).collect();
+    for (i, a) in ibans.iter().enumerate() {
+        for (j, b) in ibans.iter().enumerate().skip(i + 1) {
+            if similarity(a.electronic_str(), b.electronic_str())
+                .kind()
+                .is_likely_duplicate()
+            {
+                let (root_i, root_j) = (find(&mut parents, i), find(&mut parents, j));
+                // Keep the smallest index as the representative, so that the
+                // groups are ordered by their first IBAN.
+                parents[root_i.max(root_j)] = root_i.min(root_j);
+            }
+        }
+    }
+
+    let mut groups: Vec<Vec<&T>> = Vec::new();
+    let mut group_of_root: Vec<Option<usize>> = std::vec![None; ibans.len()];
+    for (i, iban) in ibans.iter().enumerate() {
+        let root = find(&mut parents, i);
+        let group = *group_of_root[root].get_or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group].push(iban);
+    }
+    groups.retain(|group| group.len() > 1);
+    groups
+}
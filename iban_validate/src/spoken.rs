@@ -0,0 +1,175 @@
+//! Rendering of IBANs for reading aloud, with letters spelled using a
+//! phonetic alphabet.
+
+use crate::base_iban::{groups, PAPER_GROUP_SIZE};
+use crate::masked::{Masked, Redacted};
+use core::fmt::{self, Display};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A spelling alphabet, which assigns a word to every letter.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PhoneticAlphabet {
+    /// The international radiotelephony spelling alphabet used by NATO and
+    /// the ICAO: Alfa, Bravo, Charlie, ...
+    Nato,
+    /// The German spelling alphabet of DIN 5009:2022, which uses city names:
+    /// Aachen, Berlin, Chemnitz, ...
+    Din5009,
+    /// The French spelling alphabet: Anatole, Berthe, Célestin, ...
+    French,
+}
+
+impl PhoneticAlphabet {
+    /// Get the word for an ASCII letter, regardless of its case. Returns `None`
+    /// if the character is not an ASCII letter.
+    ///
+    /// # Example
+    /// ```rust
+    /// use iban::PhoneticAlphabet;
+    /// assert_eq!(PhoneticAlphabet::Nato.word('x'), Some("X-ray"));
+    /// assert_eq!(PhoneticAlphabet::Din5009.word('K'), Some("Köln"));
+    /// assert_eq!(PhoneticAlphabet::French.word('Z'), Some("Zoé"));
+    /// assert_eq!(PhoneticAlphabet::Nato.word('1'), None);
+    /// ```
+    #[must_use]
+    pub fn word(self, letter: char) -> Option<&'static str> {
+        const NATO: [&str; 26] = [
+            "Alfa", "Bravo", "Charlie", "Delta", "Echo", "Foxtrot", "Golf", "Hotel", "India",
+            "Juliett", "Kilo", "Lima", "Mike", "November", "Oscar", "Papa", "Quebec", "Romeo",
+            "Sierra", "Tango", "Uniform", "Victor", "Whiskey", "X-ray", "Yankee", "Zulu",
+        ];
+        const DIN_5009: [&str; 26] = [
+            "Aachen",
+            "Berlin",
+            "Chemnitz",
+            "Düsseldorf",
+            "Essen",
+            "Frankfurt",
+            "Goslar",
+            "Hamburg",
+            "Ingelheim",
+            "Jena",
+            "Köln",
+            "Leipzig",
+            "München",
+            "Nürnberg",
+            "Offenbach",
+            "Potsdam",
+            "Quickborn",
+            "Rostock",
+            "Salzwedel",
+            "Tübingen",
+            "Unna",
+            "Völklingen",
+            "Wuppertal",
+            "Xanten",
+            "Ypsilon",
+            "Zwickau",
+        ];
+        const FRENCH: [&str; 26] = [
+            "Anatole",
+            "Berthe",
+            "Célestin",
+            "Désiré",
+            "Eugène",
+            "François",
+            "Gaston",
+            "Henri",
+            "Irma",
+            "Joseph",
+            "Kléber",
+            "Louis",
+            "Marcel",
+            "Nicolas",
+            "Oscar",
+            "Pierre",
+            "Quintal",
+            "Raoul",
+            "Suzanne",
+            "Thérèse",
+            "Ursule",
+            "Victor",
+            "William",
+            "Xavier",
+            "Yvonne",
+            "Zoé",
+        ];
+        if !letter.is_ascii_alphabetic() {
+            return None;
+        }
+        let index = usize::from(letter.to_ascii_uppercase() as u8 - b'A');
+        Some(match self {
+            PhoneticAlphabet::Nato => NATO[index],
+            PhoneticAlphabet::Din5009 => DIN_5009[index],
+            PhoneticAlphabet::French => FRENCH[index],
+        })
+    }
+}
+
+/// An IBAN rendered for reading aloud, which can be displayed. It is
+/// obtained via [`Iban::spoken`](crate::Iban::spoken) or
+/// [`BaseIban::spoken`](crate::BaseIban::spoken).
+///
+/// The characters are grouped as in the paper format. Groups are separated
+/// by a comma and a space, the characters within a group by a space. Letters
+/// are spelled using a [`PhoneticAlphabet`], digits are written as is.
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let iban: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+/// assert_eq!(
+///     iban.spoken(PhoneticAlphabet::Nato).to_string(),
+///     "Golf Bravo 2 9, November Whiskey Bravo Kilo, 6 0 1 6, 1 3 3 1, 9 2 6 8, 1 9"
+/// );
+/// # Ok::<(), ParseIbanError>(())
+/// ```
+#[derive(Copy, Clone)]
+pub struct Spoken<'a> {
+    electronic: &'a str,
+    alphabet: PhoneticAlphabet,
+}
+
+impl<'a> Spoken<'a> {
+    pub(crate) fn new(electronic: &'a str, alphabet: PhoneticAlphabet) -> Self {
+        Spoken {
+            electronic,
+            alphabet,
+        }
+    }
+}
+
+impl fmt::Debug for Spoken<'_> {
+    /// With the `redact_debug` feature, the IBAN is masked.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Spoken")
+            .field(
+                "electronic",
+                &Redacted(Masked::new(self.electronic, None).electronic()),
+            )
+            .field("alphabet", &self.alphabet)
+            .finish()
+    }
+}
+
+impl Display for Spoken<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, group) in groups(self.electronic, PAPER_GROUP_SIZE).enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            for (j, c) in group.chars().enumerate() {
+                if j != 0 {
+                    write!(f, " ")?;
+                }
+                match self.alphabet.word(c) {
+                    Some(word) => write!(f, "{}", word)?,
+                    None => write!(f, "{}", c)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,265 @@
+//! Detection of characters that look like the characters of an IBAN but are
+//! not, such as Cyrillic letters, fullwidth digits and invisible characters.
+//! Such characters are used to make a fraudulent IBAN look legitimate.
+
+use core::error::Error;
+use core::fmt::{self, Display, Write};
+use core::str::CharIndices;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The kind of a [`SuspiciousCharacter`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SuspiciousKind {
+    /// A Cyrillic or Greek letter that looks like a Latin letter, such as the
+    /// Cyrillic `А`.
+    Homoglyph,
+    /// A fullwidth letter or digit, such as `１`, as used in East Asian
+    /// typography.
+    Fullwidth,
+    /// A character without width, such as the zero-width space or the soft
+    /// hyphen.
+    Invisible,
+    /// A space other than the ASCII space, such as the no-break space.
+    UnusualSpace,
+    /// A control character that changes the direction of text, which can make
+    /// the displayed order of characters differ from their actual order.
+    BidiControl,
+}
+
+impl Display for SuspiciousKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SuspiciousKind::Homoglyph => "homoglyph",
+                SuspiciousKind::Fullwidth => "fullwidth character",
+                SuspiciousKind::Invisible => "invisible character",
+                SuspiciousKind::UnusualSpace => "unusual space",
+                SuspiciousKind::BidiControl => "bidirectional control character",
+            }
+        )
+    }
+}
+
+/// The way in which a suspicious character is folded.
+enum Fold {
+    /// Replace the character by an ASCII character.
+    Replace(char),
+    /// Remove the character.
+    Remove,
+    /// Keep the character, because it cannot be folded safely.
+    Keep,
+}
+
+/// Classify a character that is not ASCII.
+fn classify(c: char) -> Option<(SuspiciousKind, Fold)> {
+    let homoglyph = match c {
+        // Cyrillic
+        'А' | 'а' => 'A',
+        'В' => 'B',
+        'С' | 'с' => 'C',
+        'ԁ' => 'D',
+        'Е' | 'е' => 'E',
+        'Н' | 'һ' => 'H',
+        'І' | 'і' | 'Ӏ' => 'I',
+        'Ј' | 'ј' => 'J',
+        'К' => 'K',
+        'М' => 'M',
+        'О' | 'о' => 'O',
+        'Р' | 'р' => 'P',
+        'Ԛ' | 'ԛ' => 'Q',
+        'Ѕ' | 'ѕ' => 'S',
+        'Т' => 'T',
+        'Ԝ' | 'ԝ' => 'W',
+        'Х' | 'х' => 'X',
+        'У' | 'у' | 'Ү' => 'Y',
+        // Greek
+        'Α' => 'A',
+        'Β' => 'B',
+        'Ε' => 'E',
+        'Η' => 'H',
+        'Ι' => 'I',
+        'Κ' => 'K',
+        'Μ' => 'M',
+        'Ν' => 'N',
+        'Ο' | 'ο' => 'O',
+        'Ρ' => 'P',
+        'Τ' => 'T',
+        'Χ' => 'X',
+        'Υ' => 'Y',
+        'Ζ' => 'Z',
+        _ => {
+            return match u32::from(c) {
+                // Fullwidth digits and letters are offset from ASCII.
+                0xFF10..=0xFF19 | 0xFF21..=0xFF3A | 0xFF41..=0xFF5A => Some((
+                    SuspiciousKind::Fullwidth,
+                    char::from_u32(u32::from(c) - 0xFEE0).map_or(Fold::Keep, Fold::Replace),
+                )),
+                0x00AD | 0x200B..=0x200D | 0x2060 | 0xFEFF => {
+                    Some((SuspiciousKind::Invisible, Fold::Remove))
+                }
+                0x00A0 | 0x2000..=0x200A | 0x202F | 0x205F | 0x3000 => {
+                    Some((SuspiciousKind::UnusualSpace, Fold::Replace(' ')))
+                }
+                0x061C | 0x200E | 0x200F | 0x202A..=0x202E | 0x2066..=0x2069 => {
+                    Some((SuspiciousKind::BidiControl, Fold::Keep))
+                }
+                _ => None,
+            };
+        }
+    };
+    Some((SuspiciousKind::Homoglyph, Fold::Replace(homoglyph)))
+}
+
+/// A character in the input that looks like a character of an IBAN, or that
+/// is not visible, but is not a valid character of an IBAN. It is found by
+/// [`suspicious_characters`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SuspiciousCharacter {
+    position: usize,
+    character: char,
+    kind: SuspiciousKind,
+}
+
+impl SuspiciousCharacter {
+    /// Get the position of the character in the input, as a byte offset.
+    #[inline]
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Get the character.
+    #[inline]
+    #[must_use]
+    pub fn character(&self) -> char {
+        self.character
+    }
+
+    /// Get the kind of the character.
+    #[inline]
+    #[must_use]
+    pub fn kind(&self) -> SuspiciousKind {
+        self.kind
+    }
+}
+
+impl Display for SuspiciousCharacter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the input contains a {} (U+{:04X}) at byte {}",
+            self.kind,
+            u32::from(self.character),
+            self.position
+        )
+    }
+}
+
+impl Error for SuspiciousCharacter {}
+
+/// An iterator over the suspicious characters in an input, obtained via
+/// [`suspicious_characters`].
+#[derive(Clone, Debug)]
+pub struct SuspiciousCharacters<'a> {
+    chars: CharIndices<'a>,
+}
+
+impl Iterator for SuspiciousCharacters<'_> {
+    type Item = SuspiciousCharacter;
+    fn next(&mut self) -> Option<SuspiciousCharacter> {
+        self.chars.find_map(|(position, character)| {
+            classify(character).map(|(kind, _)| SuspiciousCharacter {
+                position,
+                character,
+                kind,
+            })
+        })
+    }
+}
+
+/// Find the characters in an input that look like characters of an IBAN, or
+/// that are invisible, but that are not valid in an IBAN. Parsing such an
+/// input fails with [`ParseBaseIbanError::InvalidFormat`](crate::ParseBaseIbanError::InvalidFormat),
+/// but since these characters are a known means of fraud, this function can
+/// be used to report them separately.
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// // The first letter is a Cyrillic 'Е', the space is a no-break space.
+/// let input = "DЕ44\u{a0}5001 0517 5407 3249 31";
+/// assert!(input.parse::<Iban>().is_err());
+///
+/// let suspicious: Vec<SuspiciousCharacter> = suspicious_characters(input).collect();
+/// assert_eq!(suspicious.len(), 2);
+/// assert_eq!(suspicious[0].kind(), SuspiciousKind::Homoglyph);
+/// assert_eq!(suspicious[0].position(), 1);
+/// assert_eq!(suspicious[1].kind(), SuspiciousKind::UnusualSpace);
+/// ```
+#[inline]
+#[must_use]
+pub fn suspicious_characters(input: &str) -> SuspiciousCharacters<'_> {
+    SuspiciousCharacters {
+        chars: input.char_indices(),
+    }
+}
+
+/// The input with suspicious characters folded, obtained via
+/// [`fold_suspicious`]. It can be iterated over or displayed.
+#[derive(Clone, Debug)]
+pub struct FoldSuspicious<'a> {
+    chars: core::str::Chars<'a>,
+}
+
+impl Iterator for FoldSuspicious<'_> {
+    type Item = char;
+    fn next(&mut self) -> Option<char> {
+        loop {
+            let c = self.chars.next()?;
+            match classify(c) {
+                None | Some((_, Fold::Keep)) => return Some(c),
+                Some((_, Fold::Replace(replacement))) => return Some(replacement),
+                Some((_, Fold::Remove)) => {}
+            }
+        }
+    }
+}
+
+impl Display for FoldSuspicious<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.clone().try_for_each(|c| f.write_char(c))
+    }
+}
+
+/// Fold the suspicious characters in an input to the characters they look
+/// like: homoglyphs become Latin letters, fullwidth characters become ASCII
+/// characters, unusual spaces become an ASCII space and invisible characters
+/// are removed. Bidirectional control characters are kept, since the order
+/// in which the input was displayed cannot be recovered, so that parsing the
+/// result still fails.
+///
+/// Only fold an input if it is known to be entered by the person it belongs
+/// to, such as when it is copied from a document. Otherwise, the presence of
+/// suspicious characters should rather be reported; see
+/// [`suspicious_characters`].
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let input = "ＤＥ44 5001\u{200b} 0517 5407 3249 31";
+/// let folded = fold_suspicious(input).to_string();
+/// assert_eq!(folded, "DE44 5001 0517 5407 3249 31");
+/// assert!(folded.parse::<Iban>().is_ok());
+/// ```
+#[inline]
+#[must_use]
+pub fn fold_suspicious(input: &str) -> FoldSuspicious<'_> {
+    FoldSuspicious {
+        chars: input.chars(),
+    }
+}
@@ -0,0 +1,290 @@
+//! IBANs of which the country is known at the type level.
+
+use crate::{ComponentKind, Country, Iban, IbanLike, ParseIbanError};
+use core::error::Error;
+use core::fmt::{self, Debug, Display};
+use core::hash::Hash;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::str::FromStr;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A trait that cannot be implemented outside of this crate, which ensures
+/// that every [`CountryMarker`] corresponds to a country of the registry.
+pub trait Sealed {}
+
+/// A type that represents a country of the IBAN registry. A marker type for
+/// every country is available in the [`markers`](crate::markers) module.
+pub trait CountryMarker:
+    Sealed + Copy + Eq + Ord + Hash + Debug + Default + Send + Sync + 'static
+{
+    /// The country.
+    const COUNTRY: Country;
+    /// The two-letter country code of the country.
+    const COUNTRY_CODE: &'static str;
+}
+
+/// An [`Iban`] of which the country is guaranteed by the type, such as a
+/// [`GermanIban`](crate::de::GermanIban). This makes it possible for an API
+/// to only accept IBANs of a single country.
+///
+/// It dereferences to an [`Iban`], so all its methods are available.
+/// Modules such as [`de`](crate::de) and [`gb`](crate::gb) add accessors
+/// that are specific to the country.
+///
+/// # Example
+/// ```rust
+/// use iban::markers::{De, Nl};
+/// use iban::*;
+/// let iban: TypedIban<De> = "DE44 5001 0517 5407 3249 31".parse()?;
+/// assert_eq!(iban.bank_identifier(), Some("50010517"));
+///
+/// let dutch: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+/// assert!(TypedIban::<De>::try_from(dutch.clone()).is_err());
+/// assert!(TypedIban::<Nl>::try_from(dutch).is_ok());
+/// # Ok::<(), ParseTypedIbanError>(())
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(not(feature = "zeroize"), derive(Copy))]
+pub struct TypedIban<C> {
+    iban: Iban,
+    country: PhantomData<C>,
+}
+
+impl<C: CountryMarker> TypedIban<C> {
+    /// Get the country of the IBAN.
+    #[inline]
+    #[must_use]
+    pub fn country(&self) -> Country {
+        C::COUNTRY
+    }
+
+    /// Get the inner IBAN.
+    #[inline]
+    #[must_use]
+    pub fn as_iban(&self) -> &Iban {
+        &self.iban
+    }
+
+    /// Convert into the inner IBAN.
+    #[inline]
+    #[must_use]
+    pub fn into_iban(self) -> Iban {
+        self.iban
+    }
+
+    /// Get a component of the BBAN that every IBAN of the country has. The
+    /// BBAN follows the built-in format, so the component is always present.
+    pub(crate) fn component(&self, kind: ComponentKind) -> &str {
+        self.iban
+            .components()
+            .find(|component| component.kind() == kind)
+            .map(|component| component.as_str())
+            .expect(
+                "A component of a country is missing. Please create an issue at \
+                 https://github.com/ThomasdenH/iban_validate.",
+            )
+    }
+}
+
+impl<C> Debug for TypedIban<C> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.iban, f)
+    }
+}
+
+impl<C> Display for TypedIban<C> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.iban, f)
+    }
+}
+
+impl<C> Deref for TypedIban<C> {
+    type Target = Iban;
+    #[inline]
+    fn deref(&self) -> &Iban {
+        &self.iban
+    }
+}
+
+impl<C> AsRef<Iban> for TypedIban<C> {
+    #[inline]
+    fn as_ref(&self) -> &Iban {
+        &self.iban
+    }
+}
+
+impl<C> From<TypedIban<C>> for Iban {
+    #[inline]
+    fn from(value: TypedIban<C>) -> Iban {
+        value.iban
+    }
+}
+
+impl<C: CountryMarker> TryFrom<Iban> for TypedIban<C> {
+    type Error = ParseTypedIbanError;
+    /// Check that the IBAN belongs to the country and that its BBAN follows
+    /// the built-in format of the country, which the national accessors rely
+    /// on.
+    ///
+    /// # Errors
+    /// If the IBAN belongs to another country,
+    /// [`ParseTypedIbanError::WrongCountry`] is returned. If the BBAN does not
+    /// follow the built-in format, for example because the IBAN was validated
+    /// using a custom [`Registry`](crate::Registry) or a historical format,
+    /// [`ParseTypedIbanError::InvalidIban`] is returned with
+    /// [`ParseIbanError::InvalidBban`].
+    fn try_from(iban: Iban) -> Result<Self, Self::Error> {
+        use crate::countries::{self, Matchable};
+
+        if iban.country_code() != C::COUNTRY_CODE {
+            return Err(WrongCountryError {
+                expected: C::COUNTRY,
+                found: iban.country(),
+            }
+            .into());
+        }
+        if !countries::country_pattern(C::COUNTRY_CODE)
+            .is_some_and(|pattern| pattern.match_str(iban.bban()))
+        {
+            return Err(ParseIbanError::InvalidBban(iban.into()).into());
+        }
+        Ok(TypedIban {
+            iban,
+            country: PhantomData,
+        })
+    }
+}
+
+impl<C: CountryMarker> FromStr for TypedIban<C> {
+    type Err = ParseTypedIbanError;
+    /// Parse an IBAN and check that it belongs to the country.
+    ///
+    /// # Errors
+    /// If the string is not a valid IBAN or the IBAN belongs to another
+    /// country, a [`ParseTypedIbanError`] is returned.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TypedIban::try_from(s.parse::<Iban>()?)
+    }
+}
+
+impl<'a, C: CountryMarker> TryFrom<&'a str> for TypedIban<C> {
+    type Error = ParseTypedIbanError;
+    /// Parse an IBAN and check that it belongs to the country.
+    ///
+    /// # Errors
+    /// If the string is not a valid IBAN or the IBAN belongs to another
+    /// country, a [`ParseTypedIbanError`] is returned.
+    #[inline]
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C> Serialize for TypedIban<C> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.iban.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C: CountryMarker> Deserialize<'de> for TypedIban<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        TypedIban::try_from(Iban::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// An error indicating that an IBAN does not belong to the country of a
+/// [`TypedIban`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WrongCountryError {
+    expected: Country,
+    found: Country,
+}
+
+impl WrongCountryError {
+    /// Get the country of the [`TypedIban`].
+    #[inline]
+    #[must_use]
+    pub fn expected(&self) -> Country {
+        self.expected
+    }
+
+    /// Get the country of the IBAN.
+    #[inline]
+    #[must_use]
+    pub fn found(&self) -> Country {
+        self.found
+    }
+}
+
+impl Display for WrongCountryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected an IBAN of {}, but found one of {}",
+            self.expected.as_str(),
+            self.found.as_str()
+        )
+    }
+}
+
+impl Error for WrongCountryError {}
+
+/// An error indicating that a string could not be parsed as a [`TypedIban`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(not(feature = "zeroize"), derive(Copy))]
+pub enum ParseTypedIbanError {
+    /// The string is not a valid IBAN, or the BBAN does not follow the
+    /// built-in format of the country.
+    InvalidIban {
+        /// The error indicating why the string is not a valid IBAN.
+        source: ParseIbanError,
+    },
+    /// The IBAN belongs to another country.
+    WrongCountry {
+        /// The error indicating the expected and actual country.
+        source: WrongCountryError,
+    },
+}
+
+impl From<ParseIbanError> for ParseTypedIbanError {
+    #[inline]
+    fn from(source: ParseIbanError) -> Self {
+        ParseTypedIbanError::InvalidIban { source }
+    }
+}
+
+impl From<WrongCountryError> for ParseTypedIbanError {
+    #[inline]
+    fn from(source: WrongCountryError) -> Self {
+        ParseTypedIbanError::WrongCountry { source }
+    }
+}
+
+impl Display for ParseTypedIbanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseTypedIbanError::InvalidIban { .. } => write!(f, "the input is not a valid IBAN"),
+            ParseTypedIbanError::WrongCountry { .. } => {
+                write!(f, "the IBAN belongs to another country")
+            }
+        }
+    }
+}
+
+impl Error for ParseTypedIbanError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseTypedIbanError::InvalidIban { source } => Some(source),
+            ParseTypedIbanError::WrongCountry { source } => Some(source),
+        }
+    }
+}
@@ -0,0 +1,325 @@
+//! A reusable validation policy, which combines parsing with rules about the
+//! countries that are accepted and the national check digits.
+
+use crate::{BaseIban, Country, Iban, IbanLike, NationalCheck, ParseIbanError};
+use core::error::Error;
+use core::fmt::{self, Debug, Display};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The number of possible country codes of two letters.
+const COUNTRY_CODES: usize = 26 * 26;
+
+/// A set of country codes, stored as a bit set so that it does not allocate.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default)]
+struct CountrySet {
+    bits: [u64; COUNTRY_CODES.div_ceil(64)],
+}
+
+impl CountrySet {
+    /// Get the index of a country code of two uppercase ASCII letters.
+    fn index(country_code: &str) -> usize {
+        let code = country_code.as_bytes();
+        usize::from(code[0] - b'A') * 26 + usize::from(code[1] - b'A')
+    }
+
+    fn insert(&mut self, country: Country) {
+        let index = CountrySet::index(country.as_str());
+        self.bits[index / 64] |= 1 << (index % 64);
+    }
+
+    fn contains(&self, country_code: &str) -> bool {
+        let index = CountrySet::index(country_code);
+        self.bits[index / 64] & (1 << (index % 64)) != 0
+    }
+}
+
+impl Debug for CountrySet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut set = f.debug_set();
+        for index in
+            (0..COUNTRY_CODES).filter(|index| self.bits[index / 64] & (1 << (index % 64)) != 0)
+        {
+            // The index is smaller than 26 * 26, so both letters are valid.
+            let code = [b'A' + (index / 26) as u8, b'A' + (index % 26) as u8];
+            set.entry(&core::str::from_utf8(&code).expect(
+                "A country code contained non-ASCII characters. Please create an issue at \
+                 https://github.com/ThomasdenH/iban_validate.",
+            ));
+        }
+        set.finish()
+    }
+}
+
+/// A validation policy that is built once and can then be used to validate
+/// any number of IBANs, also from multiple threads. By default, it accepts
+/// exactly the IBANs that can be parsed as an [`Iban`]. Rules can be added
+/// to restrict the countries, to accept countries that are not in the
+/// registry or to require correct national check digits.
+///
+/// The country rules apply to the country code of the IBAN itself, so a
+/// dependent territory such as Jersey must be allowed or denied separately
+/// from the United Kingdom.
+///
+/// # Example
+/// ```rust
+/// use iban::*;
+/// let validator = IbanValidator::new()
+///     .sepa_only()
+///     .deny_countries([Country::GB])
+///     .require_national_check_digits();
+///
+/// assert!(validator.validate("DE44 5001 0517 5407 3249 31").is_ok());
+/// assert_eq!(
+///     validator.validate("GB29 NWBK 6016 1331 9268 19"),
+///     Err(ValidateIbanError::CountryDenied(Country::GB))
+/// );
+/// assert_eq!(
+///     validator.validate("KZ86 125K ZT50 0410 0100"),
+///     Err(ValidateIbanError::NotSepa(Country::KZ))
+/// );
+/// assert!(matches!(
+///     validator.validate("BE41 5390 0754 7035"),
+///     Err(ValidateIbanError::InvalidNationalCheckDigits(_))
+/// ));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct IbanValidator {
+    allowed: Option<CountrySet>,
+    denied: CountrySet,
+    sepa_only: bool,
+    allow_unknown_countries: bool,
+    require_national_check_digits: bool,
+}
+
+impl IbanValidator {
+    /// Create a validator that accepts exactly the IBANs that can be parsed
+    /// as an [`Iban`].
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        IbanValidator::default()
+    }
+
+    /// Only accept IBANs of the given countries. If this is called multiple
+    /// times, the IBANs of all given countries are accepted.
+    #[must_use]
+    pub fn allow_countries(self, countries: impl IntoIterator<Item = Country>) -> Self {
+        let mut allowed = self.allowed.unwrap_or_default();
+        countries
+            .into_iter()
+            .for_each(|country| allowed.insert(country));
+        IbanValidator {
+            allowed: Some(allowed),
+            ..self
+        }
+    }
+
+    /// Reject IBANs of the given countries. This takes precedence over
+    /// [`IbanValidator::allow_countries`].
+    #[must_use]
+    pub fn deny_countries(mut self, countries: impl IntoIterator<Item = Country>) -> Self {
+        countries
+            .into_iter()
+            .for_each(|country| self.denied.insert(country));
+        self
+    }
+
+    /// Only accept IBANs of countries that take part in the Single Euro
+    /// Payments Area. See [`Country::is_sepa`].
+    #[inline]
+    #[must_use]
+    pub fn sepa_only(self) -> Self {
+        IbanValidator {
+            sepa_only: true,
+            ..self
+        }
+    }
+
+    /// Accept IBANs of countries that are not in the registry, for which the
+    /// BBAN cannot be validated. They are returned as
+    /// [`ValidatedIban::UnknownCountry`].
+    #[inline]
+    #[must_use]
+    pub fn allow_unknown_countries(self) -> Self {
+        IbanValidator {
+            allow_unknown_countries: true,
+            ..self
+        }
+    }
+
+    /// Reject IBANs of which the national check digits are incorrect. IBANs
+    /// of countries for which the national check digits cannot be validated
+    /// are accepted. See [`Iban::national_check`].
+    #[inline]
+    #[must_use]
+    pub fn require_national_check_digits(self) -> Self {
+        IbanValidator {
+            require_national_check_digits: true,
+            ..self
+        }
+    }
+
+    /// Parse and validate an IBAN.
+    ///
+    /// # Errors
+    /// If the string is not a valid IBAN or a rule of the validator rejects
+    /// it, a [`ValidateIbanError`] is returned that indicates the rule.
+    #[inline]
+    pub fn validate(&self, s: &str) -> Result<ValidatedIban, ValidateIbanError> {
+        let base_iban: BaseIban = s.parse().map_err(ParseIbanError::from)?;
+        self.validate_base_iban(base_iban)
+    }
+
+    /// Validate a [`BaseIban`].
+    ///
+    /// # Errors
+    /// If the BBAN is invalid or a rule of the validator rejects the IBAN, a
+    /// [`ValidateIbanError`] is returned that indicates the rule.
+    pub fn validate_base_iban(
+        &self,
+        base_iban: BaseIban,
+    ) -> Result<ValidatedIban, ValidateIbanError> {
+        let country = base_iban.country();
+        if self.denied.contains(country.as_str()) {
+            return Err(ValidateIbanError::CountryDenied(country));
+        }
+        if self
+            .allowed
+            .is_some_and(|allowed| !allowed.contains(country.as_str()))
+        {
+            return Err(ValidateIbanError::CountryNotAllowed(country));
+        }
+        if self.sepa_only && !country.is_sepa() {
+            return Err(ValidateIbanError::NotSepa(country));
+        }
+        let iban = match Iban::try_from(base_iban) {
+            Ok(iban) => iban,
+            Err(ParseIbanError::UnknownCountry(base_iban)) if self.allow_unknown_countries => {
+                return Ok(ValidatedIban::UnknownCountry(base_iban));
+            }
+            Err(source) => return Err(ValidateIbanError::InvalidIban { source }),
+        };
+        if self.require_national_check_digits && iban.national_check() == NationalCheck::Invalid {
+            return Err(ValidateIbanError::InvalidNationalCheckDigits(iban));
+        }
+        Ok(ValidatedIban::Iban(iban))
+    }
+}
+
+/// An IBAN that was accepted by an [`IbanValidator`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(not(feature = "zeroize"), derive(Copy))]
+pub enum ValidatedIban {
+    /// An IBAN of which the BBAN was validated.
+    Iban(Iban),
+    /// An IBAN of a country that is not in the registry, which is accepted
+    /// because of [`IbanValidator::allow_unknown_countries`].
+    UnknownCountry(BaseIban),
+}
+
+impl ValidatedIban {
+    /// Get the IBAN if its BBAN was validated.
+    #[inline]
+    #[must_use]
+    pub fn iban(&self) -> Option<&Iban> {
+        match self {
+            ValidatedIban::Iban(iban) => Some(iban),
+            ValidatedIban::UnknownCountry(_) => None,
+        }
+    }
+
+    /// Get the IBAN as a [`BaseIban`].
+    #[inline]
+    #[must_use]
+    pub fn base_iban(&self) -> &BaseIban {
+        match self {
+            ValidatedIban::Iban(iban) => iban.as_ref(),
+            ValidatedIban::UnknownCountry(base_iban) => base_iban,
+        }
+    }
+}
+
+impl IbanLike for ValidatedIban {
+    #[inline]
+    fn electronic_str(&self) -> &str {
+        self.base_iban().electronic_str()
+    }
+}
+
+impl Display for ValidatedIban {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.base_iban(), f)
+    }
+}
+
+impl From<ValidatedIban> for BaseIban {
+    #[inline]
+    fn from(value: ValidatedIban) -> BaseIban {
+        match value {
+            ValidatedIban::Iban(iban) => iban.into(),
+            ValidatedIban::UnknownCountry(base_iban) => base_iban,
+        }
+    }
+}
+
+/// An error indicating that an [`IbanValidator`] rejected an IBAN. The
+/// variant indicates the rule that rejected it.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(not(feature = "zeroize"), derive(Copy))]
+pub enum ValidateIbanError {
+    /// The input is not a valid IBAN, or its country is not in the registry
+    /// and [`IbanValidator::allow_unknown_countries`] was not set.
+    InvalidIban {
+        /// The error indicating why the input is not a valid IBAN.
+        source: ParseIbanError,
+    },
+    /// The country was denied by [`IbanValidator::deny_countries`].
+    CountryDenied(Country),
+    /// The country was not allowed by [`IbanValidator::allow_countries`].
+    CountryNotAllowed(Country),
+    /// The country is not part of the Single Euro Payments Area, which is
+    /// required by [`IbanValidator::sepa_only`].
+    NotSepa(Country),
+    /// The national check digits are incorrect, which is rejected because of
+    /// [`IbanValidator::require_national_check_digits`].
+    InvalidNationalCheckDigits(Iban),
+}
+
+impl From<ParseIbanError> for ValidateIbanError {
+    #[inline]
+    fn from(source: ParseIbanError) -> Self {
+        ValidateIbanError::InvalidIban { source }
+    }
+}
+
+impl Display for ValidateIbanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidateIbanError::InvalidIban { .. } => write!(f, "the input is not a valid IBAN"),
+            ValidateIbanError::CountryDenied(country) => {
+                write!(f, "IBANs of {} are denied", country.as_str())
+            }
+            ValidateIbanError::CountryNotAllowed(country) => {
+                write!(f, "IBANs of {} are not allowed", country.as_str())
+            }
+            ValidateIbanError::NotSepa(country) => {
+                write!(f, "{} is not part of SEPA", country.as_str())
+            }
+            ValidateIbanError::InvalidNationalCheckDigits(..) => {
+                write!(f, "the national check digits are incorrect")
+            }
+        }
+    }
+}
+
+impl Error for ValidateIbanError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ValidateIbanError::InvalidIban { source } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! This module tests validation using the formats in effect at a given date.
+
+use iban::{BaseIban, ComponentKind, Country, Iban, IbanLike, ParseIbanError, RegistryDate};
+
+#[test]
+/// Validation at a recent date should be identical to the current validation.
+fn test_current_date() -> Result<(), ParseIbanError> {
+    let today = RegistryDate::new(2100, 1);
+    for &country in Country::ALL {
+        let example = country.info().expect("registry country").example();
+        assert_eq!(Iban::parse_as_of(example, today)?, example.parse::<Iban>()?);
+    }
+    Ok(())
+}
+
+#[test]
+/// Before the effective date, a country is not yet known.
+fn test_not_yet_in_effect() -> Result<(), ParseIbanError> {
+    for &country in Country::ALL {
+        let info = country.info().expect("registry country");
+        let base_iban: BaseIban = info.example().parse()?;
+        assert!(info.is_in_effect(info.effective_date()));
+        assert_eq!(
+            Iban::parse_as_of(info.example(), RegistryDate::new(2006, 12)),
+            Err(ParseIbanError::UnknownCountry(base_iban))
+        );
+    }
+    Ok(())
+}
+
+#[test]
+/// Costa Rica used a shorter BBAN before 2017.
+fn test_historical_format() -> Result<(), ParseIbanError> {
+    let old = "CR0515202001026284066";
+    let new = "CR05015202001026284066";
+
+    let before = RegistryDate::new(2017, 6);
+    let after = RegistryDate::new(2017, 7);
+
+    assert_eq!(Iban::parse_as_of(old, before)?.bban(), "15202001026284066");
+    assert!(matches!(
+        Iban::parse_as_of(new, before),
+        Err(ParseIbanError::InvalidBban(_))
+    ));
+    assert!(matches!(
+        Iban::parse_as_of(old, after),
+        Err(ParseIbanError::InvalidBban(_))
+    ));
+    assert_eq!(Iban::parse_as_of(new, after)?.country_code(), "CR");
+    Ok(())
+}
+
+#[test]
+/// The identifiers and components of an IBAN in a historical format should
+/// follow that format.
+fn test_historical_components() -> Result<(), ParseIbanError> {
+    let old = Iban::parse_as_of("CR0515202001026284066", RegistryDate::new(2017, 6))?;
+    assert_eq!(old.bank_identifier(), Some("152"));
+    assert_eq!(old.branch_identifier(), None);
+    assert_eq!(
+        old.components()
+            .map(|component| (component.kind(), component.as_str()))
+            .collect::<Vec<_>>(),
+        [
+            (ComponentKind::BankIdentifier, "152"),
+            (ComponentKind::AccountNumber, "02001026284066"),
+        ]
+    );
+    assert_eq!(
+        old.explain().to_string(),
+        "country code: CR\n\
+         check digits: 05\n\
+         bank identifier: 152\n\
+         account number: 02001026284066"
+    );
+
+    let new = Iban::parse_as_of("CR05015202001026284066", RegistryDate::new(2017, 7))?;
+    assert_eq!(new.bank_identifier(), Some("0152"));
+    Ok(())
+}
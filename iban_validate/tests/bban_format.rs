@@ -0,0 +1,77 @@
+//! This module tests the public BBAN formats and the regular expressions
+//! rendered from them.
+
+use iban::{Country, Iban, ParseIbanError, RegexFlavor};
+
+#[test]
+/// The format of every country should match the example in the registry.
+fn test_formats_match_examples() -> Result<(), ParseIbanError> {
+    for country in Country::ALL {
+        let info = country.info().expect("every country has information");
+        let format = country.bban_format().expect("every country has a format");
+        let iban: Iban = info.example().parse()?;
+        assert_eq!(format.len(), info.bban_length());
+        assert!(format.matches(iban.bban()));
+        assert_eq!(iban::bban_format(country.as_str()), Some(format));
+    }
+    Ok(())
+}
+
+#[test]
+/// The notation of a format.
+fn test_format_notation() {
+    let format = Country::DE.bban_format().expect("the format is known");
+    assert_eq!(format.to_string(), "8!n10!n");
+    assert!(!format.is_empty());
+    assert!(!format.matches("50010517540732493"));
+    assert!(!format.matches("50010517540732493A"));
+
+    // Territories have the format of their parent country.
+    assert_eq!(iban::bban_format("RE"), Country::FR.bban_format());
+    assert_eq!(iban::bban_format("XX"), None);
+}
+
+#[test]
+/// The regular expressions in every flavor.
+fn test_regex() {
+    let format = Country::BG.bban_format().expect("the format is known");
+    assert_eq!(
+        format.regex(RegexFlavor::JavaScript).to_string(),
+        r"^[A-Z]{4}\d{6}[A-Z0-9]{8}$"
+    );
+    assert_eq!(
+        format.regex(RegexFlavor::Pcre).to_string(),
+        r"\A[A-Z]{4}[0-9]{6}[A-Z0-9]{8}\z"
+    );
+    assert_eq!(
+        format.regex(RegexFlavor::Posix).to_string(),
+        "^[A-Z]{4}[0-9]{6}[A-Z0-9]{8}$"
+    );
+    assert_eq!(
+        format
+            .regex(RegexFlavor::JavaScript)
+            .for_country(Country::BG)
+            .to_string(),
+        r"^BG\d{2}[A-Z]{4}\d{6}[A-Z0-9]{8}$"
+    );
+
+    // A single character has no quantifier.
+    let format = Country::IT.bban_format().expect("the format is known");
+    assert_eq!(
+        format.regex(RegexFlavor::Posix).to_string(),
+        "^[A-Z][0-9]{10}[A-Z0-9]{12}$"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+/// A structure loaded at runtime can be used as a format.
+fn test_structure_as_format() -> Result<(), iban::ParseStructureError> {
+    let structure: iban::BbanStructure = "4!a6!n8!n".parse()?;
+    assert_eq!(Some(structure.as_format()), Country::GB.bban_format());
+    assert_eq!(
+        structure.as_format().regex(RegexFlavor::Posix).to_string(),
+        "^[A-Z]{4}[0-9]{14}$"
+    );
+    Ok(())
+}
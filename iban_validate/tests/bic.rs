@@ -0,0 +1,159 @@
+use iban::{Bic, Country, Iban, InconsistentBicError, ParseBicError};
+
+fn bic(s: &str) -> Bic {
+    s.parse().unwrap()
+}
+
+fn iban(s: &str) -> Iban {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parse_bic() {
+    let bic8 = bic("NWBKGB2L");
+    assert_eq!(bic8.as_str(), "NWBKGB2L");
+    assert_eq!(bic8.institution_code(), "NWBK");
+    assert_eq!(bic8.country(), Country::GB);
+    assert_eq!(bic8.location_code(), "2L");
+    assert_eq!(bic8.branch_code(), None);
+    assert!(bic8.is_primary_office());
+
+    let bic11 = bic("deutdeffxxx");
+    assert_eq!(bic11.to_string(), "DEUTDEFFXXX");
+    assert_eq!(format!("{bic11:?}"), "DEUTDEFFXXX");
+    assert_eq!(bic11.branch_code(), Some("XXX"));
+    assert!(bic11.is_primary_office());
+
+    // Countries outside the registry are allowed.
+    assert_eq!(
+        bic("BKCHCNBJ").country(),
+        Country::Other("CN".parse().unwrap())
+    );
+}
+
+#[test]
+fn test_bics() {
+    assert!(bic("DEUTDEF0").is_test());
+    assert!(bic("NWBKGB20XXX").is_test());
+    assert!(!bic("NWBKGB2L").is_test());
+}
+
+#[test]
+fn invalid_bics() {
+    assert_eq!("".parse::<Bic>(), Err(ParseBicError::InvalidLength));
+    assert_eq!(
+        "DEUTDEFF5".parse::<Bic>(),
+        Err(ParseBicError::InvalidLength)
+    );
+    assert_eq!(
+        "DEUTDEFF5000".parse::<Bic>(),
+        Err(ParseBicError::InvalidLength)
+    );
+    assert_eq!(
+        "DE-TDEFF".parse::<Bic>(),
+        Err(ParseBicError::InvalidInstitutionCode)
+    );
+    assert_eq!(
+        "DEUT1EFF".parse::<Bic>(),
+        Err(ParseBicError::InvalidCountryCode)
+    );
+    assert_eq!(
+        "DEUTDEF ".parse::<Bic>(),
+        Err(ParseBicError::InvalidLocationCode)
+    );
+    assert_eq!(
+        "DEUTDEFF5_0".parse::<Bic>(),
+        Err(ParseBicError::InvalidBranchCode)
+    );
+    assert_eq!("DEUTDÉFF".parse::<Bic>(), Err(ParseBicError::InvalidLength));
+}
+
+#[test]
+fn consistent_with_iban() {
+    let british = iban("GB29 NWBK 6016 1331 9268 19");
+    assert_eq!(bic("NWBKGB2L").check_iban(&british), Ok(()));
+    assert_eq!(bic("NWBKGB2L601").check_iban(&british), Ok(()));
+    assert_eq!(
+        bic("BARCGB22").check_iban(&british),
+        Err(InconsistentBicError::DifferentInstitution)
+    );
+
+    let dutch = iban("NL91 ABNA 0417 1643 00");
+    assert_eq!(bic("ABNANL2A").check_iban(&dutch), Ok(()));
+    assert_eq!(
+        bic("RABONL2U").check_iban(&dutch),
+        Err(InconsistentBicError::DifferentInstitution)
+    );
+    assert_eq!(
+        bic("ABNADEFF").check_iban(&dutch),
+        Err(InconsistentBicError::DifferentCountry)
+    );
+
+    // The bank identifier of German IBANs is not related to the BIC.
+    let german = iban("DE44 5001 0517 5407 3249 31");
+    assert_eq!(bic("INGDDEFF").check_iban(&german), Ok(()));
+}
+
+#[test]
+fn consistent_with_territory_iban() {
+    let jersey = iban("JE90 NWBK 6016 1331 9268 19");
+    assert_eq!(bic("NWBKJESH").check_iban(&jersey), Ok(()));
+    assert_eq!(bic("NWBKGB2L").check_iban(&jersey), Ok(()));
+    assert_eq!(
+        bic("BARCJESH").check_iban(&jersey),
+        Err(InconsistentBicError::DifferentInstitution)
+    );
+    assert_eq!(
+        bic("NWBKIE2D").check_iban(&jersey),
+        Err(InconsistentBicError::DifferentCountry)
+    );
+}
+
+#[test]
+fn institution_code_countries() {
+    // The countries of which the BBAN starts with a bank identifier of four
+    // letters.
+    let countries = [
+        "AZ", "BG", "BH", "GB", "GI", "IE", "IQ", "JO", "KW", "LC", "LV", "MT", "NI", "NL", "PS",
+        "QA", "RO", "SV", "VG", "YE",
+    ];
+    for country in Country::ALL {
+        let cc = country.as_str();
+        let example = iban(country.info().unwrap().example());
+        let other = bic(&format!("ZZZZ{cc}22"));
+        if countries.contains(&cc) {
+            let bank_identifier = example.bank_identifier().unwrap();
+            assert_eq!(
+                bic(&format!("{bank_identifier}{cc}22")).check_iban(&example),
+                Ok(()),
+                "{cc}"
+            );
+            assert_eq!(
+                other.check_iban(&example),
+                Err(InconsistentBicError::DifferentInstitution),
+                "{cc}"
+            );
+        } else {
+            assert_eq!(other.check_iban(&example), Ok(()), "{cc}");
+        }
+    }
+}
+
+#[test]
+fn institution_code_examples() {
+    for (iban_str, bic_str) in [
+        ("MT84 MALT 0110 0001 2345 MTLC AST0 01S", "MALTMTMT"),
+        ("JO94 CBJO 0010 0000 0000 0131 0003 02", "CBJOJOAX"),
+        ("KW81 CBKU 0000 0000 0000 1234 5601 01", "CBKUKWKW"),
+        ("PS92 PALS 0000 0000 0400 1234 5670 2", "PALSPS22"),
+        ("LC55 HEMM 0001 0001 0012 0012 0002 3015", "HEMMLCLC"),
+    ] {
+        let iban = iban(iban_str);
+        assert_eq!(bic(bic_str).check_iban(&iban), Ok(()), "{iban}");
+        assert_eq!(
+            bic(&format!("BARC{}", &bic_str[4..])).check_iban(&iban),
+            Err(InconsistentBicError::DifferentInstitution),
+            "{iban}"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use iban::{
+    ClearingMemberId, ClearingSystem, Country, FromClearingError, Iban, IbanLike,
+    InvalidMemberIdError, ParseClearingSystemError,
+};
+
+/// IBANs with their clearing system, member identification and the other
+/// characters of the BBAN.
+const CASES: &[(&str, ClearingSystem, &str, &str)] = &[
+    (
+        "AT611904300234573201",
+        ClearingSystem::AtBlz,
+        "19043",
+        "00234573201",
+    ),
+    (
+        "CH9300762011623852957",
+        ClearingSystem::ChBcc,
+        "00762",
+        "011623852957",
+    ),
+    (
+        "LI21088100002324013AA",
+        ClearingSystem::ChBcc,
+        "08810",
+        "0002324013AA",
+    ),
+    (
+        "DE44500105175407324931",
+        ClearingSystem::DeBlz,
+        "50010517",
+        "5407324931",
+    ),
+    (
+        "ES9121000418450200051332",
+        ClearingSystem::EsNcc,
+        "21000418",
+        "450200051332",
+    ),
+    (
+        "GB29NWBK60161331926819",
+        ClearingSystem::GbDsc,
+        "601613",
+        "NWBK31926819",
+    ),
+    (
+        "JE90NWBK60161331926819",
+        ClearingSystem::GbDsc,
+        "601613",
+        "NWBK31926819",
+    ),
+    (
+        "GR1601101250000000012300695",
+        ClearingSystem::GrBic,
+        "0110125",
+        "0000000012300695",
+    ),
+    (
+        "IE29AIBK93115212345678",
+        ClearingSystem::IeNcc,
+        "931152",
+        "AIBK12345678",
+    ),
+    (
+        "IT60X0542811101000000123456",
+        ClearingSystem::ItNcc,
+        "0542811101",
+        "X000000123456",
+    ),
+    (
+        "PL61109010140000071219812874",
+        ClearingSystem::PlKnr,
+        "10901014",
+        "0000071219812874",
+    ),
+    (
+        "PT50000201231234567890154",
+        ClearingSystem::PtNcc,
+        "00020123",
+        "1234567890154",
+    ),
+];
+
+#[test]
+fn clearing_member_id_from_iban() {
+    for &(iban, system, member_id, _) in CASES {
+        let iban: Iban = iban.parse().unwrap();
+        let member = iban.clearing_member_id().unwrap();
+        assert_eq!(member.system(), system, "{iban}");
+        assert_eq!(member.member_id(), member_id, "{iban}");
+    }
+    let iban: Iban = "NL91 ABNA 0417 1643 00".parse().unwrap();
+    assert_eq!(iban.clearing_member_id(), None);
+}
+
+#[test]
+fn iban_from_clearing_member_id() {
+    for &(iban, system, member_id, rest) in CASES {
+        let iban: Iban = iban.parse().unwrap();
+        let member = ClearingMemberId::new(system, member_id).unwrap();
+        assert_eq!(member.to_iban(iban.country(), rest), Ok(iban));
+    }
+}
+
+#[test]
+fn lowercase_rest_is_normalized() {
+    let member = ClearingMemberId::new(ClearingSystem::GbDsc, "601613").unwrap();
+    let iban = member.to_iban(Country::GB, "nwbk31926819").unwrap();
+    assert_eq!(iban.to_string(), "GB29 NWBK 6016 1331 9268 19");
+}
+
+#[test]
+fn invalid_member_id() {
+    assert_eq!(
+        ClearingMemberId::new(ClearingSystem::DeBlz, "5001051"),
+        Err(InvalidMemberIdError)
+    );
+    assert_eq!(
+        ClearingMemberId::new(ClearingSystem::DeBlz, "500105170"),
+        Err(InvalidMemberIdError)
+    );
+    assert_eq!(
+        ClearingMemberId::new(ClearingSystem::GbDsc, "60-161"),
+        Err(InvalidMemberIdError)
+    );
+}
+
+#[test]
+fn invalid_iban_from_clearing_member_id() {
+    let member = ClearingMemberId::new(ClearingSystem::DeBlz, "50010517").unwrap();
+    assert_eq!(
+        member.to_iban(Country::AT, "5407324931"),
+        Err(FromClearingError::UnsupportedCountry)
+    );
+    assert_eq!(
+        member.to_iban(Country::DE, "540732493"),
+        Err(FromClearingError::InvalidBban)
+    );
+    assert_eq!(
+        member.to_iban(Country::DE, "540732493X"),
+        Err(FromClearingError::InvalidBban)
+    );
+    assert_eq!(
+        member.to_iban(Country::DE, "540732493-"),
+        Err(FromClearingError::InvalidBban)
+    );
+    assert_eq!(
+        member.to_iban(Country::DE, "540732493é"),
+        Err(FromClearingError::InvalidBban)
+    );
+}
+
+#[test]
+fn clearing_system_codes() {
+    for system in ClearingSystem::ALL {
+        assert_eq!(system.code().parse(), Ok(*system));
+        assert_eq!(system.to_string(), system.code());
+        assert!(system.code().starts_with(system.country().as_str()));
+    }
+    assert_eq!(
+        "USABA".parse::<ClearingSystem>(),
+        Err(ParseClearingSystemError)
+    );
+}
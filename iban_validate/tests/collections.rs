@@ -0,0 +1,156 @@
+//! This module tests the IBAN collections.
+#![cfg(feature = "std")]
+
+use iban::{Iban, IbanLike, IbanMap, IbanSet, ParseIbanError};
+
+fn parse_all(ibans: &[&str]) -> Result<Vec<Iban>, ParseIbanError> {
+    ibans.iter().map(|s| s.parse()).collect()
+}
+
+#[test]
+/// Lookups of single IBANs.
+fn test_membership() -> Result<(), ParseIbanError> {
+    let ibans = parse_all(&[
+        "GB29 NWBK 6016 1331 9268 19",
+        "NL91 ABNA 0417 1643 00",
+        "DE44 5001 0517 5407 3249 31",
+    ])?;
+    let mut set: IbanSet = ibans.iter().cloned().collect();
+    assert_eq!(set.len(), 3);
+    assert!(ibans.iter().all(|iban| set.contains(iban)));
+    // `Iban` is not `Copy` with the `zeroize` feature, so parse it for every use.
+    let other = || "BE68 5390 0754 7034".parse::<Iban>();
+    assert!(!set.contains(&other()?));
+    assert!(set.insert(other()?));
+    assert!(!set.insert(other()?));
+    assert!(set.contains(&other()?));
+    assert!(set.remove(&other()?));
+    assert!(!set.remove(&other()?));
+    // The IBANs are iterated in order.
+    assert_eq!(
+        set.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        [
+            "DE44 5001 0517 5407 3249 31",
+            "GB29 NWBK 6016 1331 9268 19",
+            "NL91 ABNA 0417 1643 00"
+        ]
+    );
+    assert_eq!(set.into_iter().len(), 3);
+    Ok(())
+}
+
+#[test]
+/// Building a map keeps the last value of duplicate IBANs.
+fn test_map_duplicates() -> Result<(), ParseIbanError> {
+    let iban = || "NL91 ABNA 0417 1643 00".parse::<Iban>();
+    let mut map: IbanMap<u32> = [(iban()?, 1), (iban()?, 2)].into_iter().collect();
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.get(&iban()?), Some(&2));
+    map.extend([(iban()?, 3)]);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.insert(iban()?, 4), Some(3));
+    *map.get_mut(&iban()?).expect("the IBAN is in the map") += 1;
+    assert_eq!(map.remove(&iban()?), Some(5));
+    assert!(map.is_empty());
+    Ok(())
+}
+
+#[test]
+/// The map is ordered like `Iban` for the IBANs of all countries, also when
+/// iterating by value.
+fn test_map_order() {
+    let mut ibans: Vec<Iban> = iban::known_examples()
+        .filter_map(|s| s.parse().ok())
+        .collect();
+    let map: IbanMap<usize> = ibans.iter().cloned().zip(0..).collect();
+    ibans.sort();
+    ibans.dedup();
+    assert_eq!(map.len(), ibans.len());
+    assert!(ibans.iter().all(|iban| map.contains_key(iban)));
+    // Every IBAN is found among the IBANs of its bank and branch.
+    for iban in &ibans {
+        if let Some(bank) = iban.bank_identifier() {
+            assert!(map
+                .bank(iban.country_code(), bank)
+                .any(|(other, _)| other == iban));
+        }
+        if let (Some(bank), Some(branch)) = (iban.bank_identifier(), iban.branch_identifier()) {
+            assert!(map.contains_branch_of(iban));
+            assert!(map
+                .branch(iban.country_code(), bank, branch)
+                .any(|(other, _)| other == iban));
+        }
+    }
+    assert_eq!(
+        map.clone()
+            .into_iter()
+            .map(|(iban, _)| iban)
+            .collect::<Vec<_>>(),
+        ibans
+    );
+    assert_eq!(
+        map.into_iter()
+            .rev()
+            .map(|(iban, _)| iban)
+            .collect::<Vec<_>>(),
+        ibans.into_iter().rev().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+/// Queries by bank and branch.
+fn test_bank_queries() -> Result<(), ParseIbanError> {
+    let set: IbanSet = parse_all(&[
+        "GB29 NWBK 6016 1331 9268 19",
+        "GB07 NWBK 6016 1331 9268 27",
+        "GB33 BUKB 2020 1555 5555 55",
+        "IT60 X054 2811 1010 0000 0123 456",
+        "JE90 NWBK 6016 1331 9268 19",
+    ])?
+    .into_iter()
+    .collect();
+    assert_eq!(set.bank("GB", "NWBK").len(), 2);
+    assert_eq!(set.branch("GB", "NWBK", "601613").len(), 2);
+    assert_eq!(set.branch("GB", "BUKB", "202015").len(), 1);
+    assert_eq!(set.bank("GB", "ABCD").len(), 0);
+    assert_eq!(set.bank("JE", "NWBK").len(), 1);
+    assert!(set.contains_bank("IT", "05428"));
+    assert!(set.contains_branch("IT", "05428", "11101"));
+    assert!(!set.contains_branch("IT", "05428", "11102"));
+
+    let other: Iban = "GB33 BUKB 2020 1555 5555 55".parse()?;
+    assert!(set.contains_bank_of(&other));
+    // Dutch IBANs have no branch identifier.
+    let nl: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+    assert!(!set.contains_bank_of(&nl));
+    assert!(!set.contains_branch_of(&nl));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "serde")]
+/// Sets are serialized as sequences and maps as maps.
+fn test_serde() -> Result<(), ParseIbanError> {
+    use serde_test::{assert_tokens, Configure, Token};
+    let iban = || "NL91ABNA0417164300".parse::<Iban>();
+    let set: IbanSet = [iban()?].into_iter().collect();
+    assert_tokens(
+        &set.compact(),
+        &[
+            Token::Seq { len: Some(1) },
+            Token::Str("NL91ABNA0417164300"),
+            Token::SeqEnd,
+        ],
+    );
+    let map: IbanMap<u8> = [(iban()?, 1)].into_iter().collect();
+    assert_tokens(
+        &map.compact(),
+        &[
+            Token::Map { len: Some(1) },
+            Token::Str("NL91ABNA0417164300"),
+            Token::U8(1),
+            Token::MapEnd,
+        ],
+    );
+    Ok(())
+}
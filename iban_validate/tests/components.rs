@@ -0,0 +1,136 @@
+//! This module tests the labelled components of the BBAN.
+
+use iban::{ComponentKind, Iban, ParseIbanError};
+
+/// Collect the components of an IBAN as pairs of kind and contents.
+fn components(iban: &Iban) -> Vec<(ComponentKind, &str)> {
+    iban.components()
+        .map(|component| (component.kind(), component.as_str()))
+        .collect()
+}
+
+#[test]
+/// The bank and branch identifiers in the components should match the
+/// registry, and the components should cover the BBAN.
+fn test_components_cover_bban() {
+    for country in iban::Country::ALL {
+        let info = country.info().expect("every country has information");
+        let iban: Iban = info.example().parse().expect("the example is valid");
+        let mut end = 0;
+        for component in iban.components() {
+            assert_eq!(component.range().start, end);
+            assert_eq!(&iban.bban()[component.range()], component.as_str());
+            end = component.range().end;
+            if component.kind() == ComponentKind::BankIdentifier {
+                assert_eq!(iban.bank_identifier(), Some(component.as_str()));
+            }
+        }
+        assert_eq!(end, iban.bban().len(), "{}", country);
+    }
+}
+
+#[test]
+/// Components that are not described by the registry.
+fn test_national_components() -> Result<(), ParseIbanError> {
+    let iban: Iban = "FR1420041010050500013M02606".parse()?;
+    assert_eq!(
+        components(&iban),
+        [
+            (ComponentKind::BankIdentifier, "20041"),
+            (ComponentKind::BranchIdentifier, "01005"),
+            (ComponentKind::AccountNumber, "0500013M026"),
+            (ComponentKind::NationalCheckDigits, "06"),
+        ]
+    );
+
+    let iban: Iban = "IT60X0542811101000000123456".parse()?;
+    assert_eq!(
+        components(&iban),
+        [
+            (ComponentKind::NationalCheckDigits, "X"),
+            (ComponentKind::BankIdentifier, "05428"),
+            (ComponentKind::BranchIdentifier, "11101"),
+            (ComponentKind::AccountNumber, "000000123456"),
+        ]
+    );
+
+    let iban: Iban = "GT82TRAJ01020000001210029690".parse()?;
+    assert_eq!(
+        components(&iban),
+        [
+            (ComponentKind::BankIdentifier, "TRAJ"),
+            (ComponentKind::Currency, "01"),
+            (ComponentKind::AccountType, "02"),
+            (ComponentKind::AccountNumber, "0000001210029690"),
+        ]
+    );
+
+    let iban: Iban = "MU17BOMM0101101030300200000MUR".parse()?;
+    assert_eq!(
+        components(&iban).last(),
+        Some(&(ComponentKind::Currency, "MUR"))
+    );
+    Ok(())
+}
+
+#[test]
+/// Territories use the components of their parent country.
+fn test_territory_components() -> Result<(), ParseIbanError> {
+    let iban: Iban = "RE4220041010050500013M02606".parse()?;
+    let parent: Iban = "FR1420041010050500013M02606".parse()?;
+    assert_eq!(components(&iban), components(&parent));
+    Ok(())
+}
+
+#[test]
+/// The explanation lists the components.
+fn test_explain() -> Result<(), ParseIbanError> {
+    let iban: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    assert_eq!(
+        iban.explain().to_string(),
+        "country code: GB\n\
+         check digits: 29\n\
+         bank identifier: NWBK\n\
+         branch identifier: 601613\n\
+         account number: 31926819"
+    );
+    Ok(())
+}
+
+#[cfg(feature = "extended_countries")]
+#[test]
+/// Countries outside the registry have no known components.
+fn test_extended_explain() -> Result<(), ParseIbanError> {
+    let iban: Iban = "DZ580002100001113000000570".parse()?;
+    assert_eq!(iban.components().count(), 0);
+    assert_eq!(
+        iban.explain().to_string(),
+        "country code: DZ\n\
+         check digits: 58\n\
+         bban: 0002100001113000000570"
+    );
+    Ok(())
+}
+
+#[test]
+/// With the `redact_debug` feature, `Debug` should not reveal the BBAN.
+fn test_debug() -> Result<(), ParseIbanError> {
+    let iban: Iban = "BE68 5390 0754 7034".parse()?;
+    let component = iban.components().next().expect("the bank identifier");
+    if cfg!(feature = "redact_debug") {
+        assert_eq!(
+            format!("{:?}", component),
+            "Component { kind: BankIdentifier, range: 0..3, value: *** }"
+        );
+        assert!(format!("{:?}", iban.components()).starts_with("Components { bban: ************, "));
+    } else {
+        assert_eq!(
+            format!("{:?}", component),
+            "Component { kind: BankIdentifier, range: 0..3, value: \"539\" }"
+        );
+        assert!(
+            format!("{:?}", iban.components()).starts_with("Components { bban: \"539007547034\", ")
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! This module tests the `Country` enum.
+
+use iban::{
+    BaseIban, Country, CountryCode, Iban, IbanLike, ParseCountryError, ParseIbanError, RegistryDate,
+};
+
+#[test]
+/// Every registry country should round trip through its country code.
+fn test_country_round_trip() -> Result<(), ParseCountryError> {
+    for &country in Country::ALL {
+        assert_eq!(country.as_str().parse::<Country>()?, country);
+        assert_eq!(country.to_string(), country.as_str());
+        assert!(!matches!(country, Country::Other(_)));
+    }
+    Ok(())
+}
+
+#[test]
+/// Country codes that are not in the registry are parsed as `Country::Other`.
+fn test_other_country() -> Result<(), ParseCountryError> {
+    let country: Country = "AO".parse()?;
+    assert_eq!(country, Country::Other("AO".parse::<CountryCode>()?));
+    assert_eq!(country.as_str(), "AO");
+    assert_eq!(country.to_string(), "AO");
+    assert!(!Country::ALL.contains(&country));
+    Ok(())
+}
+
+#[test]
+/// `Country::Other` with the code of a registry country is the same country.
+fn test_other_registry_country() -> Result<(), ParseCountryError> {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash(country: Country) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        country.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let other = Country::Other("DE".parse()?);
+    assert_eq!(other, Country::DE);
+    assert_eq!(hash(other), hash(Country::DE));
+    assert_ne!(other, Country::Other("AO".parse()?));
+    Ok(())
+}
+
+#[test]
+/// Only two uppercase ASCII letters form a country code.
+fn test_invalid_country() {
+    for s in ["", "D", "de", "DEU", "D1", "ÄB"] {
+        assert_eq!(s.parse::<Country>(), Err(ParseCountryError));
+        assert_eq!(s.parse::<CountryCode>(), Err(ParseCountryError));
+    }
+}
+
+#[test]
+/// The country of an IBAN can be obtained from both an `Iban` and a `BaseIban`.
+fn test_iban_country() -> Result<(), ParseIbanError> {
+    let iban: Iban = "GB29NWBK60161331926819".parse()?;
+    assert_eq!(iban.country(), Country::GB);
+
+    let base_iban: BaseIban = "AO06004400006729503010102".parse()?;
+    assert_eq!(base_iban.country().as_str(), "AO");
+    assert!(matches!(base_iban.country(), Country::Other(_)));
+    Ok(())
+}
+
+#[test]
+/// The country information should be consistent with the example IBAN.
+fn test_country_info() -> Result<(), ParseIbanError> {
+    for &country in Country::ALL {
+        let info = country.info().expect("registry countries have information");
+        assert_eq!(info.country(), country);
+        assert_eq!(iban::country_info(country.as_str()), Some(info));
+        assert!(!info.name().is_empty());
+
+        let example: Iban = info.example().parse()?;
+        assert_eq!(example.country(), country);
+        assert_eq!(example.electronic_str().len(), info.iban_length());
+        assert_eq!(example.bban().len(), info.bban_length());
+        assert!(info.effective_date() >= RegistryDate::new(2007, 1));
+    }
+
+    let other: Country = "AO".parse().expect("valid country code");
+    assert_eq!(other.info(), None);
+    assert_eq!(iban::country_info("AO"), None);
+    Ok(())
+}
+
+#[test]
+/// Check some of the information against known values.
+fn test_country_info_values() {
+    let info = Country::CH.info().expect("Switzerland is in the registry");
+    assert_eq!(info.name(), "Switzerland");
+    assert!(info.is_sepa());
+
+    let info = Country::TR.info().expect("Turkey is in the registry");
+    assert_eq!(info.iban_length(), 26);
+    assert!(!info.is_sepa());
+}
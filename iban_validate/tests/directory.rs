@@ -0,0 +1,277 @@
+//! This module tests looking up banks in a bank directory.
+#![cfg(feature = "std")]
+
+use iban::{
+    Bank, BankDirectory, BankStatus, Country, Iban, LoadBankDirectoryError, LocalBankDirectory,
+    ParseBicError,
+};
+
+const CSV: &str = "\
+Country,Name,Bank_ID,Branch_ID,BIC,Address,Status,Comment
+GB,National Westminster Bank,NWBK,,NWBKGB2L,\"250 Bishopsgate, London\",active,
+gb,NatWest Stratford,nwbk,601613,NWBKGB2L,,,\"Branch
+with a note\"
+DE,Bundesbank Filiale Hamburg,20000000,,markdeff200,,Active,
+BE,Banque Van Breda,539,,,,inactive,
+
+";
+
+#[test]
+/// A CSV export should be parsed regardless of the column order and case.
+fn test_parse_csv() -> Result<(), LoadBankDirectoryError> {
+    let directory = LocalBankDirectory::parse_csv(CSV)?;
+    assert_eq!(directory.len(), 4);
+    assert!(!directory.is_empty());
+
+    let bank = directory
+        .bank(Country::GB, "NWBK", None)
+        .expect("the bank is listed");
+    assert_eq!(bank.name(), "National Westminster Bank");
+    assert_eq!(
+        bank.bic().map(|bic| bic.to_string()).as_deref(),
+        Some("NWBKGB2L")
+    );
+    assert_eq!(bank.address(), Some("250 Bishopsgate, London"));
+    assert_eq!(bank.status(), BankStatus::Active);
+
+    let bank = directory
+        .bank(Country::DE, "20000000", None)
+        .expect("the bank is listed");
+    assert_eq!(
+        bank.bic().map(|bic| bic.to_string()).as_deref(),
+        Some("MARKDEFF200")
+    );
+    assert_eq!(bank.address(), None);
+
+    let bank = directory
+        .bank(Country::BE, "539", None)
+        .expect("the bank is listed");
+    assert_eq!(bank.bic(), None);
+    assert_eq!(bank.status(), BankStatus::Inactive);
+    Ok(())
+}
+
+#[test]
+/// A branch should be returned if it is listed, and the bank otherwise.
+fn test_branch_fallback() -> Result<(), Box<dyn std::error::Error>> {
+    let directory = LocalBankDirectory::parse_csv(CSV)?;
+
+    let iban: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    assert_eq!(
+        iban.bank_in(&directory).map(Bank::name),
+        Some("NatWest Stratford")
+    );
+
+    let iban: Iban = "GB31 NWBK 6000 0012 3456 78".parse()?;
+    assert_eq!(
+        iban.bank_in(&directory).map(Bank::name),
+        Some("National Westminster Bank")
+    );
+
+    let iban: Iban = "GB33 BUKB 2020 1555 5555 55".parse()?;
+    assert_eq!(iban.bank_in(&directory), None);
+
+    // A directory can also be used as a trait object.
+    let dyn_directory: &dyn BankDirectory = &directory;
+    let iban: Iban = "BE68 5390 0754 7034".parse()?;
+    assert_eq!(
+        iban.bank_in(dyn_directory).map(Bank::name),
+        Some("Banque Van Breda")
+    );
+    Ok(())
+}
+
+#[test]
+/// A directory should be buildable manually.
+fn test_insert() -> Result<(), ParseBicError> {
+    let mut directory = LocalBankDirectory::new();
+    assert!(directory.is_empty());
+    let bank = Bank::new("ING-DiBa").with_bic("INGDDEFF".parse()?);
+    assert_eq!(
+        directory.insert(Country::DE, "50010517", None, bank.clone()),
+        None
+    );
+    assert_eq!(
+        directory.insert(Country::DE, "50010517", None, bank.clone()),
+        Some(bank.clone())
+    );
+
+    let mut other = LocalBankDirectory::new();
+    other.insert(
+        Country::GB,
+        "nwbk",
+        Some("601613"),
+        Bank::new("NatWest Stratford"),
+    );
+    directory.extend(other);
+    assert_eq!(directory.len(), 2);
+    assert_eq!(
+        directory.bank(Country::GB, "NWBK", Some("601613")),
+        Some(&Bank::new("NatWest Stratford"))
+    );
+    // Identifiers are looked up case-insensitively, like they are inserted.
+    assert_eq!(
+        directory.bank(Country::GB, "nwbk", Some("601613")),
+        Some(&Bank::new("NatWest Stratford"))
+    );
+
+    let mut entries: Vec<_> = directory
+        .iter()
+        .map(|(country, bank_identifier, branch_identifier, bank)| {
+            (country, bank_identifier, branch_identifier, bank.name())
+        })
+        .collect();
+    entries.sort_by_key(|&(_, bank_identifier, _, _)| bank_identifier);
+    assert_eq!(
+        entries,
+        [
+            (Country::DE, "50010517", None, "ING-DiBa"),
+            (Country::GB, "NWBK", Some("601613"), "NatWest Stratford"),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+/// Invalid exports should be rejected with the record that is invalid.
+fn test_parse_csv_errors() {
+    assert!(matches!(
+        LocalBankDirectory::parse_csv("country,name\nGB,National Westminster Bank"),
+        Err(LoadBankDirectoryError::MissingColumn("bank_id"))
+    ));
+    assert!(matches!(
+        LocalBankDirectory::parse_csv("country,bank_id,name\nGB,NWBK"),
+        Err(LoadBankDirectoryError::InvalidLayout { record: 2 })
+    ));
+    assert!(matches!(
+        LocalBankDirectory::parse_csv("country,bank_id,name\nGB,NWBK,NatWest\nGB,,Barclays"),
+        Err(LoadBankDirectoryError::MissingField {
+            record: 3,
+            field: "bank_id"
+        })
+    ));
+    assert!(matches!(
+        LocalBankDirectory::parse_csv("country,bank_id,name\nG1,NWBK,NatWest"),
+        Err(LoadBankDirectoryError::InvalidCountryCode { record: 2 })
+    ));
+    assert!(matches!(
+        LocalBankDirectory::parse_csv("country,bank_id,name,bic\nGB,NWBK,NatWest,NWBK"),
+        Err(LoadBankDirectoryError::InvalidBic {
+            record: 2,
+            source: ParseBicError::InvalidLength
+        })
+    ));
+    assert!(matches!(
+        LocalBankDirectory::parse_csv("country,bank_id,name,status\nGB,NWBK,NatWest,closed"),
+        Err(LoadBankDirectoryError::InvalidStatus { record: 2 })
+    ));
+    assert!(matches!(
+        LocalBankDirectory::parse_csv(""),
+        Err(LoadBankDirectoryError::MissingColumn("country"))
+    ));
+}
+
+#[test]
+/// Identifiers should follow the format of the country, if it is known.
+fn test_parse_csv_identifiers() -> Result<(), LoadBankDirectoryError> {
+    assert!(matches!(
+        LocalBankDirectory::parse_csv(
+            "country,bank_id,name
+GB,NWBK,NatWest
+GB,NWB1,NatWest"
+        ),
+        Err(LoadBankDirectoryError::InvalidBankIdentifier { record: 3 })
+    ));
+    assert!(matches!(
+        LocalBankDirectory::parse_csv(
+            "country,bank_id,name
+DE,5001051,ING-DiBa"
+        ),
+        Err(LoadBankDirectoryError::InvalidBankIdentifier { record: 2 })
+    ));
+    assert!(matches!(
+        LocalBankDirectory::parse_csv(
+            "country,bank_id,branch_id,name
+GB,NWBK,60161,NatWest"
+        ),
+        Err(LoadBankDirectoryError::InvalidBranchIdentifier { record: 2 })
+    ));
+    assert!(matches!(
+        LocalBankDirectory::parse_csv(
+            "country,bank_id,branch_id,name
+JE,NWBK,6O1613,NatWest"
+        ),
+        Err(LoadBankDirectoryError::InvalidBranchIdentifier { record: 2 })
+    ));
+
+    // Dependent territories use the format of their parent country, and
+    // countries without a known format are not validated.
+    let directory = LocalBankDirectory::parse_csv(
+        "country,bank_id,branch_id,name\n\
+         JE,nwbk,601613,NatWest Jersey\n\
+         AA,1,,Bank of Atlantis\n",
+    )?;
+    assert_eq!(directory.len(), 2);
+    Ok(())
+}
+
+#[test]
+/// A CSV export should be readable from a reader.
+fn test_read_csv() -> Result<(), LoadBankDirectoryError> {
+    let directory = LocalBankDirectory::read_csv(CSV.as_bytes())?;
+    assert_eq!(directory, LocalBankDirectory::parse_csv(CSV)?);
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[test]
+/// A JSON export should be parsed, where missing and `null` fields are empty.
+fn test_parse_json() -> Result<(), LoadBankDirectoryError> {
+    let json = r#"[
+        {
+            "country": "GB",
+            "bank_id": "NWBK",
+            "branch_id": null,
+            "name": "National Westminster Bank",
+            "bic": "NWBKGB2L",
+            "address": "250 Bishopsgate, London",
+            "status": "active"
+        },
+        {
+            "country": "GB",
+            "bank_id": "NWBK",
+            "branch_id": "601613",
+            "name": "NatWest Stratford",
+            "bic": "NWBKGB2L"
+        },
+        {
+            "country": "BE",
+            "bank_id": "539",
+            "name": "Banque Van Breda",
+            "status": "inactive",
+            "comment": "ignored"
+        },
+        {
+            "country": "DE",
+            "bank_id": "20000000",
+            "name": "Bundesbank Filiale Hamburg",
+            "bic": "MARKDEFF200"
+        }
+    ]"#;
+    let directory = LocalBankDirectory::parse_json(json)?;
+    assert_eq!(directory, LocalBankDirectory::parse_csv(CSV)?);
+    assert_eq!(directory, LocalBankDirectory::read_json(json.as_bytes())?);
+
+    assert!(matches!(
+        LocalBankDirectory::parse_json(r#"[{"country": "GB", "name": "NatWest"}]"#),
+        Err(LoadBankDirectoryError::MissingField {
+            record: 1,
+            field: "bank_id"
+        })
+    ));
+    assert!(matches!(
+        LocalBankDirectory::parse_json("[{\"country\": \"GB\",\n\"bank_id\": 1}]"),
+        Err(LoadBankDirectoryError::InvalidJson { line: 2, .. })
+    ));
+    Ok(())
+}
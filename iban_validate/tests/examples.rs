@@ -0,0 +1,43 @@
+use iban::{country_info, known_examples, BaseIban, Country, Iban};
+
+#[test]
+fn registry_examples_are_known() {
+    for country in Country::ALL {
+        let example: Iban = country_info(country.as_str())
+            .expect("every country has information")
+            .example()
+            .parse()
+            .expect("the example is valid");
+        assert!(example.is_known_example(), "{example}");
+    }
+}
+
+#[test]
+fn known_examples_are_valid() {
+    for example in known_examples() {
+        let iban: Iban = example.parse().expect(example);
+        assert!(iban.is_known_example(), "{example}");
+        assert!(BaseIban::from(iban).is_known_example(), "{example}");
+    }
+}
+
+#[test]
+fn published_examples_are_known() {
+    for example in [
+        "DE89 3704 0044 0532 0130 00",
+        "GB82 WEST 1234 5698 7654 32",
+        "FR76 3000 6000 0112 3456 7890 189",
+        "NL91 ABNA 0417 1643 00",
+    ] {
+        let iban: Iban = example.parse().unwrap();
+        assert!(iban.is_known_example(), "{example}");
+    }
+}
+
+#[test]
+fn other_ibans_are_not_known() {
+    for iban in ["DE63 2005 0000 0193 8475 62", "GB02 NWBK 6016 1331 9268 20"] {
+        let iban: Iban = iban.parse().unwrap();
+        assert!(!iban.is_known_example(), "{iban}");
+    }
+}
@@ -0,0 +1,61 @@
+//! This module tests the countries that are not in the IBAN registry.
+
+use iban::{FormatSource, Iban, ParseIbanError};
+
+/// IBANs of countries that are not in the registry.
+const EXTENDED_IBANS: &[&str] = &[
+    "AO06004400006729503010102",
+    "CI93CI0080111301134291200589",
+    "DZ580002100001113000000570",
+    "HN59BCMM00000000001234567890",
+    "ML03D00890170001002120000447",
+    "SN08SN0100152000048500003035",
+];
+
+#[test]
+/// Registry countries are always reported as such.
+fn test_registry_format_source() -> Result<(), ParseIbanError> {
+    assert_eq!(iban::format_source("DE"), Some(FormatSource::Registry));
+    assert_eq!(iban::format_source("XX"), None);
+    let iban: Iban = "DE44500105175407324931".parse()?;
+    assert_eq!(iban.format_source(), Some(FormatSource::Registry));
+    Ok(())
+}
+
+#[test]
+#[cfg(not(feature = "extended_countries"))]
+/// Without the feature, the extended countries are unknown.
+fn test_extended_countries_disabled() {
+    for s in EXTENDED_IBANS {
+        assert_eq!(iban::format_source(&s[0..2]), None);
+        assert!(matches!(
+            s.parse::<Iban>(),
+            Err(ParseIbanError::UnknownCountry(_))
+        ));
+    }
+}
+
+#[test]
+#[cfg(feature = "extended_countries")]
+/// With the feature, the BBAN of the extended countries is validated.
+fn test_extended_countries_enabled() -> Result<(), ParseIbanError> {
+    use iban::{BaseIban, IbanLike};
+
+    for s in EXTENDED_IBANS {
+        let iban: Iban = s.parse()?;
+        assert_eq!(iban.format_source(), Some(FormatSource::Extended));
+        assert_eq!(
+            iban::format_source(iban.country_code()),
+            Some(FormatSource::Extended)
+        );
+        assert_eq!(iban.bank_identifier(), None);
+    }
+
+    // An Algerian IBAN should only contain digits.
+    let base_iban: BaseIban = "DZ6500021000011130000005AA".parse()?;
+    assert!(matches!(
+        Iban::try_from(base_iban),
+        Err(ParseIbanError::InvalidBban(_))
+    ));
+    Ok(())
+}
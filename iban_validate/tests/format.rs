@@ -1,6 +1,6 @@
 //! This module tests the formatting of IBANs
 
-use iban::{Iban, IbanLike, ParseIbanError};
+use iban::{BaseIban, BufferTooSmallError, Iban, IbanLike, ParseIbanError};
 
 /// This test checks the electronic formatting method.
 #[test]
@@ -39,3 +39,99 @@ fn print() -> Result<(), ParseIbanError> {
     );
     Ok(())
 }
+
+/// This test checks the configurable formatting options.
+#[test]
+fn formatted() -> Result<(), ParseIbanError> {
+    let iban: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+    assert_eq!(iban.formatted().to_string(), iban.to_string());
+    assert_eq!(
+        iban.formatted().group_size(0).to_string(),
+        "NL91ABNA0417164300"
+    );
+    assert_eq!(
+        iban.formatted().group_size(6).separator('·').to_string(),
+        "NL91AB·NA0417·164300"
+    );
+    assert_eq!(
+        iban.formatted().lowercase().to_string(),
+        "nl91 abna 0417 1643 00"
+    );
+    assert_eq!(
+        format!("{:#}", iban.formatted().lowercase()),
+        "nl91abna0417164300"
+    );
+    Ok(())
+}
+
+/// This test checks that the formatter flags are honoured.
+#[test]
+fn flags() -> Result<(), ParseIbanError> {
+    let iban: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+    assert_eq!(format!("{:#}", iban), "NL91ABNA0417164300");
+    assert_eq!(format!("[{:<24}]", iban), "[NL91 ABNA 0417 1643 00  ]");
+    assert_eq!(format!("[{:*^24}]", iban), "[*NL91 ABNA 0417 1643 00*]");
+    // A width smaller than the IBAN does not truncate it.
+    assert_eq!(format!("[{:>10}]", iban), "[NL91 ABNA 0417 1643 00]");
+    // The precision does not truncate it either.
+    assert_eq!(format!("[{:.4}]", iban), "[NL91 ABNA 0417 1643 00]");
+    assert_eq!(format!("[{:>24.4}]", iban), "[  NL91 ABNA 0417 1643 00]");
+
+    let base_iban: BaseIban = "NL91 ABNA 0417 1643 00".parse()?;
+    assert_eq!(format!("{:>#20}", base_iban), "  NL91ABNA0417164300");
+    Ok(())
+}
+
+/// This test checks the allocation-free outputs.
+#[test]
+fn buffers() -> Result<(), ParseIbanError> {
+    let iban: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+    let formatted = iban.formatted().separator('\u{2009}').to_array_string();
+    assert_eq!(
+        formatted.as_str(),
+        "NL91\u{2009}ABNA\u{2009}0417\u{2009}1643\u{2009}00"
+    );
+
+    let mut buffer = [0; 22];
+    assert_eq!(
+        iban.formatted().write_to(&mut buffer),
+        Ok("NL91 ABNA 0417 1643 00")
+    );
+    let mut buffer = [0; 21];
+    assert_eq!(
+        iban.formatted().write_to(&mut buffer),
+        Err(BufferTooSmallError)
+    );
+
+    // A long IBAN with a four-byte separator fits in the array string.
+    let iban: Iban = "LC55 HEMM 0001 0001 0012 0012 0002 3015".parse()?;
+    let formatted = iban
+        .formatted()
+        .group_size(1)
+        .separator('\u{1F4B6}')
+        .to_array_string();
+    assert_eq!(
+        formatted.chars().count(),
+        2 * iban.electronic_str().len() - 1
+    );
+    Ok(())
+}
+
+/// With the `redact_debug` feature, `Debug` of a formatted IBAN is masked.
+#[test]
+fn formatted_debug() -> Result<(), ParseIbanError> {
+    let iban: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+    let electronic = if cfg!(feature = "redact_debug") {
+        "NL91**********4300"
+    } else {
+        "\"NL91ABNA0417164300\""
+    };
+    assert_eq!(
+        format!("{:?}", iban.formatted()),
+        format!(
+            "Formatted {{ electronic: {}, group_size: 4, separator: ' ', lowercase: false }}",
+            electronic
+        )
+    );
+    Ok(())
+}
@@ -3,15 +3,31 @@ use core::convert::TryFrom;
 use core::fmt::{Debug, Display};
 use core::hash::Hash;
 use core::str::FromStr;
-use iban::{BaseIban, Iban, ParseBaseIbanError, ParseIbanError};
+#[cfg(feature = "std")]
+use iban::{
+    Bank, BankStatus, IbanSet, LoadBankDirectoryError, LocalBankDirectory, OcrCandidate,
+    OcrRecovery, RecoverOcrError,
+};
+use iban::{
+    BaseIban, BbanFormat, BbanRegex, Bic, BufferTooSmallError, ClearingMemberId, ClearingSystem,
+    Component, ComponentKind, Country, CountryCode, CountryInfo, Formatted, FromClearingError,
+    Iban, IbanValidator, InconsistentBicError, IncrementalValidator, InputError, InputMask,
+    InputState, InvalidMemberIdError, Masked, NationalCheck, ParseBaseIbanError, ParseBicError,
+    ParseClearingSystemError, ParseCountryError, ParseIbanError, ParseTypedIbanError,
+    PhoneticAlphabet, RegistryDate, SepaScope, SepaTransfer, SepaTransferError, Similarity,
+    SimilarityKind, Spoken, SuspiciousCharacter, SuspiciousKind, TypedIban, ValidateIbanError,
+    ValidatedIban, WrongCountryError,
+};
 use static_assertions::assert_impl_all;
 #[cfg(feature = "zeroize")]
-use zeroize::ZeroizeOnDrop;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 assert_impl_all!(
     BaseIban: Clone,
     Eq,
     PartialEq,
+    Ord,
+    PartialOrd,
     Hash,
     Debug,
     Display,
@@ -27,6 +43,8 @@ assert_impl_all!(
     Iban: Clone,
     Eq,
     PartialEq,
+    Ord,
+    PartialOrd,
     Hash,
     Debug,
     Display,
@@ -69,8 +87,336 @@ assert_impl_all!(
     AsMut<ParseIbanError>
 );
 
+assert_impl_all!(
+    Country: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Display,
+    FromStr,
+    Send,
+    Sync
+);
+assert_impl_all!(
+    CountryCode: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Display,
+    FromStr,
+    Send,
+    Sync
+);
+assert_impl_all!(CountryInfo: Clone, Eq, PartialEq, Hash, Debug, Send, Sync);
+assert_impl_all!(
+    RegistryDate: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Debug,
+    Display,
+    Send,
+    Sync
+);
+assert_impl_all!(
+    ParseCountryError: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Display,
+    Send,
+    Sync
+);
+assert_impl_all!(
+    BbanFormat<'static>: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Display,
+    Send,
+    Sync
+);
+assert_impl_all!(BbanRegex<'static>: Copy, Clone, Eq, PartialEq, Hash, Debug, Display, Send, Sync);
+assert_impl_all!(
+    ComponentKind: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Display,
+    Send,
+    Sync
+);
+assert_impl_all!(Component<'static>: Clone, Eq, PartialEq, Hash, Debug, Display, Send, Sync);
+assert_impl_all!(Formatted<'static>: Copy, Clone, Debug, Display, Send, Sync);
+assert_impl_all!(
+    BufferTooSmallError: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Display,
+    Send,
+    Sync
+);
+assert_impl_all!(
+    IncrementalValidator: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Default,
+    Send,
+    Sync
+);
+assert_impl_all!(InputState: Copy, Clone, Eq, PartialEq, Hash, Debug, Send, Sync);
+assert_impl_all!(
+    InputError: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Display,
+    Send,
+    Sync
+);
+assert_impl_all!(InputMask: Copy, Clone, Eq, PartialEq, Hash, Debug, Display, Send, Sync);
+#[cfg(feature = "std")]
+assert_impl_all!(IbanSet: Clone, Eq, PartialEq, Hash, Debug, Default, Send, Sync);
+#[cfg(feature = "std")]
+assert_impl_all!(iban::IbanMap<u8>: Clone, Eq, PartialEq, Hash, Debug, Default, Send, Sync);
+#[cfg(feature = "std")]
+assert_impl_all!(BankStatus: Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Send, Sync);
+#[cfg(feature = "std")]
+assert_impl_all!(Bank: Clone, Eq, PartialEq, Hash, Debug, Send, Sync);
+#[cfg(feature = "std")]
+assert_impl_all!(LocalBankDirectory: Clone, Eq, PartialEq, Debug, Default, Send, Sync);
+#[cfg(feature = "std")]
+assert_impl_all!(LoadBankDirectoryError: Debug, core::error::Error, Send, Sync);
+#[cfg(feature = "std")]
+assert_impl_all!(OcrCandidate: Clone, PartialEq, Debug, Send, Sync);
+#[cfg(feature = "std")]
+assert_impl_all!(OcrRecovery: Clone, PartialEq, Debug, Send, Sync);
+#[cfg(feature = "std")]
+assert_impl_all!(
+    RecoverOcrError: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Display,
+    Send,
+    Sync,
+    core::error::Error
+);
+assert_impl_all!(
+    SuspiciousCharacter: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Display,
+    Send,
+    Sync,
+    core::error::Error
+);
+assert_impl_all!(
+    SuspiciousKind: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Display,
+    Send,
+    Sync
+);
+assert_impl_all!(
+    TypedIban<iban::markers::De>: Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Debug,
+    Display,
+    FromStr,
+    TryFrom<Iban>,
+    TryFrom<&'static str>,
+    Into<Iban>,
+    AsRef<Iban>,
+    Send,
+    Sync
+);
+assert_impl_all!(
+    WrongCountryError: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Display,
+    Send,
+    Sync,
+    core::error::Error
+);
+assert_impl_all!(
+    ParseTypedIbanError: Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Display,
+    Send,
+    Sync,
+    From<ParseIbanError>,
+    From<WrongCountryError>,
+    core::error::Error
+);
+assert_impl_all!(Similarity: Copy, Clone, Eq, PartialEq, Hash, Debug, Send, Sync);
+assert_impl_all!(
+    SimilarityKind: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Debug,
+    Send,
+    Sync
+);
+assert_impl_all!(PhoneticAlphabet: Copy, Clone, Eq, PartialEq, Hash, Debug, Send, Sync);
+assert_impl_all!(Spoken<'static>: Copy, Clone, Debug, Display, Send, Sync);
+assert_impl_all!(Masked<'static>: Clone, Debug, Display, Send, Sync);
+assert_impl_all!(
+    Bic: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Debug,
+    Display,
+    FromStr,
+    TryFrom<&'static str>,
+    AsRef<str>,
+    Send,
+    Sync
+);
+assert_impl_all!(
+    ParseBicError: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Send,
+    Sync,
+    core::error::Error
+);
+assert_impl_all!(
+    InconsistentBicError: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Send,
+    Sync,
+    core::error::Error
+);
+assert_impl_all!(
+    ClearingSystem: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Debug,
+    Display,
+    FromStr,
+    Send,
+    Sync
+);
+assert_impl_all!(
+    ClearingMemberId: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Debug,
+    Send,
+    Sync
+);
+assert_impl_all!(ParseClearingSystemError: Copy, Clone, Eq, PartialEq, Hash, Debug, core::error::Error);
+assert_impl_all!(InvalidMemberIdError: Copy, Clone, Eq, PartialEq, Hash, Debug, core::error::Error);
+assert_impl_all!(FromClearingError: Copy, Clone, Eq, PartialEq, Hash, Debug, core::error::Error);
+assert_impl_all!(SepaScope: Copy, Clone, Eq, PartialEq, Hash, Debug, Send, Sync);
+assert_impl_all!(SepaTransfer: Copy, Clone, Eq, PartialEq, Hash, Debug, Send, Sync);
+assert_impl_all!(
+    SepaTransferError: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Display,
+    Send,
+    Sync,
+    core::error::Error
+);
+assert_impl_all!(NationalCheck: Copy, Clone, Eq, PartialEq, Hash, Debug, Send, Sync);
+assert_impl_all!(
+    IbanValidator: Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Default,
+    Send,
+    Sync
+);
+assert_impl_all!(ValidatedIban: Clone, Eq, PartialEq, Hash, Debug, Display, Send, Sync);
+assert_impl_all!(
+    ValidateIbanError: Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Send,
+    Sync,
+    From<ParseIbanError>,
+    core::error::Error
+);
+
 assert_impl_all!(ParseBaseIbanError: core::error::Error);
 assert_impl_all!(ParseIbanError: core::error::Error);
+assert_impl_all!(ParseCountryError: core::error::Error);
+assert_impl_all!(BufferTooSmallError: core::error::Error);
+assert_impl_all!(InputError: core::error::Error);
 
 #[cfg(not(feature = "zeroize"))]
 assert_impl_all!(BaseIban: Copy);
@@ -81,12 +427,29 @@ assert_impl_all!(ParseBaseIbanError: Copy);
 #[cfg(not(feature = "zeroize"))]
 assert_impl_all!(ParseIbanError: Copy);
 #[cfg(feature = "zeroize")]
-assert_impl_all!(BaseIban: ZeroizeOnDrop);
+assert_impl_all!(BaseIban: Zeroize, ZeroizeOnDrop);
 
 #[cfg(feature = "serde")]
 mod impls_serde {
-    use super::{assert_impl_all, BaseIban, Iban};
+    use super::{assert_impl_all, BaseIban, ComponentKind, Country, CountryCode, Iban};
     use serde::{Deserialize, Serialize};
     assert_impl_all!(BaseIban: Serialize, Deserialize<'static>);
     assert_impl_all!(Iban: Serialize, Deserialize<'static>);
+    assert_impl_all!(Country: Serialize, Deserialize<'static>);
+    assert_impl_all!(CountryCode: Serialize, Deserialize<'static>);
+    assert_impl_all!(ComponentKind: Serialize, Deserialize<'static>);
+    assert_impl_all!(super::Similarity: Serialize, Deserialize<'static>);
+    assert_impl_all!(super::NationalCheck: Serialize, Deserialize<'static>);
+    assert_impl_all!(super::ClearingSystem: Serialize, Deserialize<'static>);
+    assert_impl_all!(super::Bic: Serialize, Deserialize<'static>);
+    assert_impl_all!(super::ParseBicError: Serialize, Deserialize<'static>);
+    assert_impl_all!(super::SepaTransfer: Serialize, Deserialize<'static>);
+    assert_impl_all!(super::SepaTransferError: Serialize, Deserialize<'static>);
+    assert_impl_all!(super::ValidateIbanError: Serialize, Deserialize<'static>);
+    #[cfg(feature = "std")]
+    assert_impl_all!(super::IbanSet: Serialize, Deserialize<'static>);
+    #[cfg(feature = "std")]
+    assert_impl_all!(super::Bank: Serialize, Deserialize<'static>);
+    #[cfg(feature = "std")]
+    assert_impl_all!(super::BankStatus: Serialize, Deserialize<'static>);
 }
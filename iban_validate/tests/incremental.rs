@@ -0,0 +1,111 @@
+//! This module tests the incremental validation of partial input.
+
+use iban::{CharacterType, IncrementalValidator, InputError, InputState};
+
+#[test]
+/// Feeding a valid IBAN character by character.
+fn test_valid_input() -> Result<(), InputError> {
+    let mut validator = IncrementalValidator::new();
+    assert!(validator.is_empty());
+    assert_eq!(
+        validator.state(),
+        InputState::Incomplete { remaining: None }
+    );
+    assert_eq!(
+        validator.push('G')?,
+        InputState::Incomplete { remaining: None }
+    );
+    assert_eq!(
+        validator.push('B')?,
+        InputState::Incomplete {
+            remaining: Some(20)
+        }
+    );
+    assert_eq!(validator.expected_length(), Some(22));
+    validator.push_str("29")?;
+    assert_eq!(validator.next_character_type(), Some(CharacterType::A));
+    validator.push_str(" nwbk ")?;
+    assert_eq!(validator.next_character_type(), Some(CharacterType::N));
+    validator.push_str("6016 1331 9268 1")?;
+    assert_eq!(validator.remaining(), Some(1));
+    assert!(!validator.can_evaluate_checksum());
+    assert_eq!(validator.push('9')?, InputState::Valid);
+    assert!(validator.can_evaluate_checksum());
+    assert_eq!(validator.next_character_type(), None);
+    assert_eq!(
+        validator.to_iban().map(|iban| iban.to_string()),
+        Some("GB29 NWBK 6016 1331 9268 19".to_owned())
+    );
+    Ok(())
+}
+
+#[test]
+/// An invalid checksum can only be detected once the input is complete.
+fn test_invalid_checksum() -> Result<(), InputError> {
+    let mut validator = IncrementalValidator::new();
+    assert_eq!(
+        validator.push_str("GB29NWBK6016133192681")?,
+        InputState::Incomplete { remaining: Some(1) }
+    );
+    assert_eq!(validator.push('8')?, InputState::InvalidChecksum);
+    assert_eq!(validator.to_iban(), None);
+    assert_eq!(validator.pop(), Some('8'));
+    assert_eq!(validator.push('9')?, InputState::Valid);
+    validator.clear();
+    assert!(validator.is_empty());
+    Ok(())
+}
+
+#[test]
+/// Characters that cannot lead to a valid IBAN are rejected.
+fn test_rejected_characters() -> Result<(), InputError> {
+    let mut validator = IncrementalValidator::new();
+    assert_eq!(validator.push('-'), Err(InputError::InvalidCharacter));
+    assert_eq!(
+        validator.push('1'),
+        Err(InputError::UnexpectedCharacter(CharacterType::A))
+    );
+    validator.push('N')?;
+    assert_eq!(validator.push('X'), Err(InputError::UnknownCountry));
+    validator.push('L')?;
+    assert_eq!(
+        validator.push('A'),
+        Err(InputError::UnexpectedCharacter(CharacterType::N))
+    );
+    validator.push_str("91")?;
+    assert_eq!(
+        validator.push('0'),
+        Err(InputError::UnexpectedCharacter(CharacterType::A))
+    );
+    // A rejected character in a string stops the input at that point.
+    assert_eq!(
+        validator.push_str("ABNA04X"),
+        Err(InputError::UnexpectedCharacter(CharacterType::N))
+    );
+    assert_eq!(validator.as_str(), "NL91ABNA04");
+    assert_eq!(validator.len(), 10);
+
+    // No country code starts with a 'Z'.
+    let mut validator = IncrementalValidator::new();
+    assert_eq!(validator.push('Z'), Err(InputError::UnknownCountry));
+    Ok(())
+}
+
+#[test]
+/// With the `redact_debug` feature, `Debug` only shows the country code.
+fn test_debug() -> Result<(), InputError> {
+    let mut validator = IncrementalValidator::new();
+    validator.push_str("NL91ABNA")?;
+    if cfg!(feature = "redact_debug") {
+        assert_eq!(
+            format!("{:?}", validator),
+            r#"IncrementalValidator { input: "NL", len: 8 }"#
+        );
+    } else {
+        assert_eq!(
+            format!("{:?}", validator),
+            r#"IncrementalValidator { input: "NL91ABNA", len: 8 }"#
+        );
+    }
+    Ok(())
+}
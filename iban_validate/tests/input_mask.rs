@@ -0,0 +1,59 @@
+//! This module tests the input masks.
+
+use iban::{CharacterType, Country, Iban, IbanLike, MaskStyle, ParseIbanError};
+
+#[test]
+/// The masks of some countries.
+fn test_input_masks() {
+    let mask = Country::DE.input_mask().expect("the format is known");
+    assert_eq!(mask.to_string(), "DEkk nnnn nnnn nnnn nnnn nn");
+    assert_eq!(
+        mask.style(MaskStyle::Components).to_string(),
+        "DEkk bbbb bbbb cccc cccc cc"
+    );
+
+    let mask = iban::input_mask("BG").expect("the format is known");
+    assert_eq!(mask.to_string(), "BGkk AAAA nnnn nncc cccc cc");
+    assert_eq!(
+        mask.style(MaskStyle::Components).to_string(),
+        "BGkk bbbb ssss ttcc cccc cc"
+    );
+    assert!(iban::input_mask("XX").is_none());
+    assert!(iban::input_mask("de").is_none());
+}
+
+#[test]
+/// Dependent territories use the format of their parent country, but do not
+/// have a placeholder.
+fn test_territory_mask() {
+    let mask = iban::input_mask("RE").expect("the format is known");
+    assert_eq!(
+        mask.style(MaskStyle::Components).to_string(),
+        "REkk bbbb bsss sscc cccc cccc cxx"
+    );
+    assert!(mask.placeholder().is_none());
+}
+
+#[test]
+/// The placeholder of every country should follow its mask.
+fn test_placeholders() -> Result<(), ParseIbanError> {
+    for country in Country::ALL {
+        let mask = country.input_mask().expect("the format is known");
+        let placeholder = mask.placeholder().expect("every country has an example");
+        let iban: Iban = placeholder.to_string().parse()?;
+        assert_eq!(iban.country(), *country);
+        assert_eq!(mask.iban_length(), iban.electronic_str().len());
+        assert_eq!(mask.to_string().len(), placeholder.to_string().len());
+        for (character_type, c) in mask
+            .character_types()
+            .zip(placeholder.to_string().bytes().filter(|c| *c != b' '))
+        {
+            match character_type {
+                CharacterType::A => assert!(c.is_ascii_uppercase()),
+                CharacterType::N => assert!(c.is_ascii_digit()),
+                CharacterType::C => assert!(c.is_ascii_alphanumeric()),
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! This module tests the masked display of IBANs.
+
+use iban::{BaseIban, Iban, ParseBaseIbanError, ParseIbanError};
+
+#[test]
+/// The default mask keeps the country code, the check digits and the last
+/// four characters.
+fn test_masked() -> Result<(), ParseIbanError> {
+    let iban: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+    assert_eq!(iban.masked().to_string(), "NL91 **** **** **43 00");
+    assert_eq!(format!("{:?}", iban.masked()), "NL91 **** **** **43 00");
+    assert_eq!(iban.masked().electronic().to_string(), "NL91**********4300");
+    assert_eq!(
+        iban.masked().keep_bank_identifier().to_string(),
+        "NL91 ABNA **** **43 00"
+    );
+    assert_eq!(
+        iban.masked().keep_prefix(0).keep_suffix(0).to_string(),
+        "**** **** **** **** **"
+    );
+    // Keeping more characters than there are shows the full IBAN.
+    assert_eq!(
+        iban.masked().keep_prefix(30).to_string(),
+        "NL91 ABNA 0417 1643 00"
+    );
+    assert_eq!(
+        iban.masked().keep_suffix(usize::MAX).to_string(),
+        "NL91 ABNA 0417 1643 00"
+    );
+    assert_eq!(
+        iban.masked()
+            .keep_prefix(usize::MAX)
+            .keep_suffix(usize::MAX)
+            .to_string(),
+        "NL91 ABNA 0417 1643 00"
+    );
+    Ok(())
+}
+
+#[test]
+/// Padding should be supported, also in the redacted `Debug` output.
+fn test_padding() -> Result<(), ParseIbanError> {
+    let iban: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+    assert_eq!(
+        format!("{:>25}|", iban.masked()),
+        "   NL91 **** **** **43 00|"
+    );
+    assert_eq!(
+        format!("{:-<20}|", iban.masked().electronic()),
+        "NL91**********4300--|"
+    );
+    // The precision does not truncate the output.
+    assert_eq!(format!("{:.4}|", iban.masked()), "NL91 **** **** **43 00|");
+    if cfg!(feature = "redact_debug") {
+        assert_eq!(format!("{:>20?}|", iban), "  NL91**********4300|");
+    } else {
+        assert_eq!(format!("{:>20?}|", iban), "  NL91ABNA0417164300|");
+    }
+    Ok(())
+}
+
+#[test]
+/// A bank identifier in the middle of the BBAN is kept as well.
+fn test_masked_bank_identifier() -> Result<(), ParseIbanError> {
+    let iban: Iban = "IT60 X054 2811 1010 0000 0123 456".parse()?;
+    assert_eq!(
+        iban.masked().keep_bank_identifier().to_string(),
+        "IT60 *054 28** **** **** ***3 456"
+    );
+    Ok(())
+}
+
+#[test]
+/// The bank identifier is not known for a `BaseIban`.
+fn test_masked_base_iban() -> Result<(), ParseBaseIbanError> {
+    let iban: BaseIban = "NL91 ABNA 0417 1643 00".parse()?;
+    assert_eq!(
+        iban.masked().keep_bank_identifier().to_string(),
+        "NL91 **** **** **43 00"
+    );
+    Ok(())
+}
+
+#[test]
+/// With the `redact_debug` feature, `Debug` is masked.
+fn test_debug() -> Result<(), ParseIbanError> {
+    let iban: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+    let base_iban: BaseIban = "NL91 ABNA 0417 1643 00".parse()?;
+    if cfg!(feature = "redact_debug") {
+        assert_eq!(format!("{:?}", iban), "NL91**********4300");
+        assert_eq!(format!("{:?}", base_iban), "NL91**********4300");
+    } else {
+        assert_eq!(format!("{:?}", iban), "NL91ABNA0417164300");
+        assert_eq!(format!("{:?}", base_iban), "NL91ABNA0417164300");
+    }
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use iban::{country_info, Country, Iban, NationalCheck};
+
+#[test]
+fn registry_examples_have_valid_national_check_digits() {
+    for country in Country::ALL {
+        let info = country_info(country.as_str()).expect("every country has information");
+        let iban: Iban = info.example().parse().expect("the example is valid");
+        assert_ne!(iban.national_check(), NationalCheck::Invalid, "{iban}");
+    }
+}
+
+#[test]
+fn valid_national_check_digits() {
+    for iban in [
+        "BE68 5390 0754 7034",
+        "BA39 1290 0794 0102 8494",
+        "CZ65 0800 0000 1920 0014 5399",
+        "ES91 2100 0418 4502 0005 1332",
+        "FI21 1234 5600 0007 85",
+        "FR14 2004 1010 0505 0001 3M02 606",
+        "FR76 3000 6000 0112 3456 7890 189",
+        "IT60 X054 2811 1010 0000 0123 456",
+        "MC58 1122 2000 0101 2345 6789 030",
+        "NO93 8601 1117 947",
+        "PT50 0002 0123 1234 5678 9015 4",
+        "SI56 2633 0001 2039 086",
+        "SK31 1200 0000 1987 4263 7541",
+        "SM86 U032 2509 8000 0000 0270 100",
+    ] {
+        let iban: Iban = iban.parse().unwrap();
+        assert_eq!(iban.national_check(), NationalCheck::Valid, "{iban}");
+    }
+}
+
+#[test]
+fn invalid_national_check_digits() {
+    // The IBAN check digits are correct, but the last character of the BBAN
+    // is changed.
+    for iban in [
+        "BE41 5390 0754 7035",
+        "CZ17 0800 0000 1920 0014 5390",
+        "ES64 2100 0418 4502 0005 1333",
+        "FI91 1234 5600 0007 86",
+        "FR84 2004 1010 0505 0001 3M02 607",
+        "IT33 X054 2811 1010 0000 0123 457",
+        "MC31 1122 2000 0101 2345 6789 031",
+        "NO66 8601 1117 948",
+        "PT23 0002 0123 1234 5678 9015 5",
+        "SI29 2633 0001 2039 087",
+    ] {
+        let iban: Iban = iban.parse().unwrap();
+        assert_eq!(iban.national_check(), NationalCheck::Invalid, "{iban}");
+    }
+}
+
+#[test]
+fn unsupported_national_check_digits() {
+    for iban in ["DE44 5001 0517 5407 3249 31", "GB29 NWBK 6016 1331 9268 19"] {
+        let iban: Iban = iban.parse().unwrap();
+        assert_eq!(iban.national_check(), NationalCheck::Unsupported, "{iban}");
+    }
+}
+
+#[test]
+fn territories_use_the_national_check_digits_of_their_parent() {
+    let iban: Iban = "RE42 2004 1010 0505 0001 3M02 606".parse().unwrap();
+    assert_eq!(iban.parent_country(), Some(Country::FR));
+    assert_eq!(iban.national_check(), NationalCheck::Valid);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn custom_formats_are_unsupported() {
+    let registry = iban::Registry::parse_notation("IT: 23!n\nBE: 12!c").unwrap();
+    for iban in ["IT29 1054 2811 1010 0000 0123 456", "BE36 AB90 0754 7034"] {
+        let iban = registry.validate(iban.parse().unwrap()).unwrap();
+        assert_eq!(iban.national_check(), NationalCheck::Unsupported, "{iban}");
+    }
+
+    // IBANs that follow the built-in format are still checked.
+    let iban = registry
+        .validate("BE68 5390 0754 7034".parse().unwrap())
+        .unwrap();
+    assert_eq!(iban.national_check(), NationalCheck::Valid);
+}
+
+#[test]
+fn historical_formats_are_unsupported() {
+    let iban = Iban::parse_as_of(
+        "CR05 1520 2001 0262 8406 6",
+        iban::RegistryDate::new(2015, 1),
+    )
+    .unwrap();
+    assert_eq!(iban.national_check(), NationalCheck::Unsupported);
+}
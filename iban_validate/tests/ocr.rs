@@ -0,0 +1,75 @@
+//! This module tests the recovery of scanned IBANs.
+#![cfg(feature = "std")]
+
+use iban::{recover_ocr, Iban, ParseIbanError, RecoverOcrError};
+
+#[test]
+/// Characters at positions that allow a single type are corrected.
+fn test_forced_corrections() -> Result<(), RecoverOcrError> {
+    let recovery = recover_ocr("G829 NW8K 6O16 I33I 9268 I9")?;
+    let candidate = recovery.best();
+    assert_eq!(candidate.iban().to_string(), "GB29 NWBK 6016 1331 9268 19");
+    assert_eq!(candidate.corrections(), 6);
+    assert_eq!(candidate.confidence(), 1.0);
+    assert_eq!(recovery.unique(), Some(candidate.iban()));
+    Ok(())
+}
+
+#[test]
+/// An input that is already valid is returned as is.
+fn test_valid_input() -> Result<(), RecoverOcrError> {
+    let recovery = recover_ocr("nl91abna0417164300")?;
+    assert_eq!(recovery.best().iban().to_string(), "NL91 ABNA 0417 1643 00");
+    assert_eq!(recovery.best().corrections(), 0);
+    Ok(())
+}
+
+#[test]
+/// Positions that allow both letters and digits yield several readings, of
+/// which the reading with the fewest corrections is the most likely.
+fn test_ambiguous() -> Result<(), RecoverOcrError> {
+    let expected: Iban = "MT84 MALT 0110 0001 2345 MTLC AST0 01S"
+        .parse()
+        .map_err(|_: ParseIbanError| RecoverOcrError::NoValidReading)?;
+    let recovery = recover_ocr("MT84 MALT 0110 0001 2345 MTLC AST0 01S")?;
+    assert_eq!(recovery.best().iban(), &expected);
+    let candidates = recovery.candidates();
+    assert!(candidates
+        .iter()
+        .all(|candidate| candidate.confidence() <= recovery.best().confidence()));
+    let total: f64 = candidates
+        .iter()
+        .map(|candidate| candidate.confidence())
+        .sum();
+    assert!((total - 1.0).abs() < 1e-9);
+    Ok(())
+}
+
+#[test]
+/// Inputs that cannot be recovered result in an error.
+fn test_errors() {
+    assert_eq!(
+        recover_ocr("DE44 5001 0517 5407 3249 3X").map(|_| ()),
+        Err(RecoverOcrError::InvalidCharacter)
+    );
+    assert_eq!(
+        recover_ocr("DE44-5001").map(|_| ()),
+        Err(RecoverOcrError::InvalidCharacter)
+    );
+    assert_eq!(
+        recover_ocr("QQ44 5001 0517 5407 3249 31").map(|_| ()),
+        Err(RecoverOcrError::UnknownCountry)
+    );
+    assert_eq!(
+        recover_ocr("DE44 5001 0517 5407 3249 3").map(|_| ()),
+        Err(RecoverOcrError::InvalidLength)
+    );
+    assert_eq!(
+        recover_ocr("DE45 5001 0517 5407 3249 31").map(|_| ()),
+        Err(RecoverOcrError::NoValidReading)
+    );
+    assert_eq!(
+        recover_ocr("MT84 MALT 0110 0001 2345 5555 5555 555").map(|_| ()),
+        Err(RecoverOcrError::TooAmbiguous)
+    );
+}
@@ -0,0 +1,57 @@
+//! This module tests the ordering of IBANs and the comparison helpers.
+
+use iban::{BaseIban, Iban, IbanLike, ParseBaseIbanError, ParseIbanError};
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+#[test]
+/// IBANs are ordered by country code first.
+fn test_country_first() -> Result<(), ParseIbanError> {
+    let be: Iban = "BE68 5390 0754 7034".parse()?;
+    let de: Iban = "DE44 5001 0517 5407 3249 31".parse()?;
+    let nl: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+    let set: BTreeSet<Iban> = [nl, be, de].into_iter().collect();
+    assert_eq!(
+        set.iter().map(IbanLike::country_code).collect::<Vec<_>>(),
+        ["BE", "DE", "NL"]
+    );
+    Ok(())
+}
+
+#[test]
+/// The bank identifier takes precedence over characters before it in the BBAN.
+fn test_bank_before_bban() -> Result<(), ParseIbanError> {
+    let a: Iban = "IT65 A054 2811 1010 0000 0123 456".parse()?;
+    let z: Iban = "IT58 Z030 6911 1010 0000 0123 456".parse()?;
+    assert_eq!(z.cmp(&a), Ordering::Less);
+    assert_eq!(BaseIban::from(z).cmp(&BaseIban::from(a)), Ordering::Less);
+    Ok(())
+}
+
+#[test]
+/// The ordering is consistent with equality.
+fn test_consistent_with_eq() -> Result<(), ParseBaseIbanError> {
+    let a: BaseIban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    let b: BaseIban = "GB07 NWBK 6016 1331 9268 27".parse()?;
+    assert_eq!(a.cmp(&a), Ordering::Equal);
+    assert_eq!(a.cmp(&b), Ordering::Less);
+    assert_eq!(b.partial_cmp(&a), Some(Ordering::Greater));
+    Ok(())
+}
+
+#[test]
+/// Test `same_bank` and `same_branch`.
+fn test_same_bank() -> Result<(), ParseIbanError> {
+    let a: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    let b: Iban = "GB07 NWBK 6016 1331 9268 27".parse()?;
+    let c: Iban = "GB33 BUKB 2020 1555 5555 55".parse()?;
+    let je: Iban = "JE90 NWBK 6016 1331 9268 19".parse()?;
+    assert!(a.same_bank(&b));
+    assert!(a.same_branch(&b));
+    assert!(!a.same_bank(&c));
+    assert!(!a.same_branch(&c));
+    // Different countries never share a bank.
+    assert!(!a.same_bank(&je));
+    assert!(je.same_bank(&je));
+    Ok(())
+}
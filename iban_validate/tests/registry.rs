@@ -0,0 +1,163 @@
+//! This module tests loading a registry at runtime.
+#![cfg(feature = "std")]
+
+use iban::{
+    BbanStructure, CharacterType, Iban, IncompatibleStructureError, LoadRegistryError,
+    NationalCheck, ParseIbanError, ParseStructureError, Registry,
+};
+
+#[test]
+/// The registry notation should be parsed and formatted.
+fn test_parse_structure() -> Result<(), ParseStructureError> {
+    let structure: BbanStructure = "1!a5!n5!n12!c".parse()?;
+    assert_eq!(
+        structure.segments(),
+        &[
+            (1, CharacterType::A),
+            (5, CharacterType::N),
+            (5, CharacterType::N),
+            (12, CharacterType::C)
+        ]
+    );
+    assert_eq!(structure.len(), 23);
+    assert_eq!(structure.to_string(), "1!a5!n5!n12!c");
+
+    for invalid in ["", "8n", "8!x", "!n", "0!n", "8!n10", "8!n 10!n", "8!N"] {
+        assert_eq!(invalid.parse::<BbanStructure>(), Err(ParseStructureError));
+    }
+    Ok(())
+}
+
+#[test]
+/// The built-in registry should validate the same as `Iban::from_str`.
+fn test_builtin_registry() {
+    let registry = Registry::builtin();
+    for country in iban::Country::ALL {
+        assert!(registry.get(country.as_str()).is_some());
+    }
+    // Dependent territories use the format of their parent country.
+    assert_eq!(registry.get("GF"), registry.get("FR"));
+    for s in [
+        "DE44500105175407324931",
+        "GB29NWBK60161331926819",
+        "AL84212110090000AB023569874",
+        "AO06004400006729503010102",
+        "DE4450010234607324931",
+    ] {
+        assert_eq!(registry.parse_iban(s), s.parse::<Iban>());
+    }
+}
+
+#[test]
+/// Countries can be loaded from the simple notation format.
+fn test_notation() -> Result<(), ParseIbanError> {
+    let registry = Registry::parse_notation(
+        "# Test countries\n\
+         XX: 4!a10!n\n\
+         \n\
+         DE : 8!n10!n\n",
+    )
+    .expect("valid notation");
+    assert_eq!(registry.len(), 2);
+
+    let iban = registry.parse_iban("XX87ABCD0123456789")?;
+    assert_eq!(iban.bban(), "ABCD0123456789");
+    assert!(registry.parse_iban("DE44500105175407324931").is_ok());
+    assert!(matches!(
+        registry.parse_iban("GB29NWBK60161331926819"),
+        Err(ParseIbanError::UnknownCountry(_))
+    ));
+    assert!(matches!(
+        Registry::parse_notation("XX: 4!n10!n")
+            .expect("valid notation")
+            .parse_iban("XX87ABCD0123456789"),
+        Err(ParseIbanError::InvalidBban(_))
+    ));
+    Ok(())
+}
+
+#[test]
+/// An IBAN of a country that is only known to a runtime registry has no
+/// built-in format source.
+fn test_registry_format_source() -> Result<(), ParseIbanError> {
+    let registry = Registry::parse_notation("XX: 4!a10!n").expect("valid notation");
+    let iban = registry.parse_iban("XX87 ABCD 0123 4567 89")?;
+    assert_eq!(iban.format_source(), None);
+    Ok(())
+}
+
+#[test]
+/// Errors in the notation format should indicate the line.
+fn test_notation_errors() {
+    assert!(matches!(
+        Registry::parse_notation("DE: 8!n10!n\nGB 4!a6!n8!n"),
+        Err(LoadRegistryError::InvalidLayout { line: 2 })
+    ));
+    assert!(matches!(
+        Registry::parse_notation("de: 8!n10!n"),
+        Err(LoadRegistryError::InvalidCountryCode { line: 1 })
+    ));
+    assert!(matches!(
+        Registry::parse_notation("\nDE: 8!n10n"),
+        Err(LoadRegistryError::InvalidStructure { line: 2, .. })
+    ));
+}
+
+#[test]
+/// The SWIFT registry file is tab-separated and transposed, with quoted fields.
+fn test_swift_txt() -> Result<(), ParseIbanError> {
+    let txt = "Data element\tAndorra\tCustom\r\n\
+               Name of country\tAndorra\t\"Custom\tcountry\"\r\n\
+               IBAN prefix country code (ISO 3166)\tAD\tXX\r\n\
+               BBAN structure\t4!n4!n12!c\t\"4!a10!n\n\"\r\n";
+    let registry = Registry::read_swift_txt(txt.as_bytes()).expect("valid registry");
+    assert_eq!(registry.len(), 2);
+    assert_eq!(
+        registry.get("XX").map(BbanStructure::to_string).as_deref(),
+        Some("4!a10!n")
+    );
+    assert!(registry.parse_iban("AD1200012030200359100100").is_ok());
+    assert!(registry.parse_iban("XX87ABCD0123456789").is_ok());
+
+    assert!(matches!(
+        Registry::parse_swift_txt("Name of country\tAndorra\n"),
+        Err(LoadRegistryError::MissingRow(_))
+    ));
+    Ok(())
+}
+
+#[test]
+/// Built-in countries can only be overridden by a structure of the same
+/// length, so that the bank identifier and components still fit.
+fn test_override_builtin_country() -> Result<(), ParseIbanError> {
+    assert!(matches!(
+        Registry::parse_notation("XX: 4!n\nBE: 4!n"),
+        Err(LoadRegistryError::IncompatibleStructure {
+            line: 2,
+            source: IncompatibleStructureError
+        })
+    ));
+    let mut registry = Registry::builtin();
+    assert_eq!(
+        registry.insert(
+            "BE".parse().expect("valid country code"),
+            "4!n".parse().expect("valid structure")
+        ),
+        Err(IncompatibleStructureError)
+    );
+    // The registry is unchanged.
+    assert_eq!(registry.get("BE"), Registry::builtin().get("BE"));
+
+    // Territories are checked against the format of their parent country.
+    assert!(matches!(
+        Registry::parse_notation("JE: 4!a6!n"),
+        Err(LoadRegistryError::IncompatibleStructure { line: 1, .. })
+    ));
+
+    // A structure of the same length may use other character types.
+    let registry = Registry::parse_notation("BE: 12!c").expect("valid notation");
+    let iban = registry.parse_iban("BE68539007547034")?;
+    assert_eq!(iban.bank_identifier(), Some("539"));
+    assert_eq!(iban.national_check(), NationalCheck::Valid);
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use iban::{
+    sepa_transfer, BaseIban, Country, Iban, IbanValidator, SepaScope, SepaTransferError,
+    ValidateIbanError,
+};
+
+fn iban(s: &str) -> Iban {
+    s.parse().unwrap()
+}
+
+#[test]
+fn sepa_countries() {
+    for country in ["DE", "CH", "GB", "MC", "SM", "JE", "RE", "AX", "PM"] {
+        assert!(country.parse::<Country>().unwrap().is_sepa(), "{country}");
+    }
+    for country in ["KZ", "BR", "NC", "PF", "WF", "TF", "AA"] {
+        assert!(!country.parse::<Country>().unwrap().is_sepa(), "{country}");
+    }
+}
+
+#[test]
+fn eea_countries() {
+    for country in ["DE", "FR", "IS", "LI", "NO", "RE", "AX"] {
+        assert!(country.parse::<Country>().unwrap().is_eea(), "{country}");
+    }
+    for country in ["CH", "GB", "MC", "SM", "JE", "PM", "KZ"] {
+        assert!(!country.parse::<Country>().unwrap().is_eea(), "{country}");
+    }
+}
+
+#[test]
+fn domestic_transfers() {
+    let german = iban("DE44 5001 0517 5407 3249 31");
+    let transfer = sepa_transfer(&german, &iban("DE89 3704 0044 0532 0130 00")).unwrap();
+    assert_eq!(transfer.scope(), SepaScope::Domestic);
+    assert!(!transfer.bic_required());
+
+    // Réunion is part of France and the EEA.
+    let french = iban("FR14 2004 1010 0505 0001 3M02 606");
+    let transfer = sepa_transfer(&french, &iban("RE42 2004 1010 0505 0001 3M02 606")).unwrap();
+    assert_eq!(transfer.scope(), SepaScope::Domestic);
+    assert!(!transfer.bic_required());
+
+    // Saint Pierre and Miquelon is part of France, but not of the EEA.
+    let transfer = sepa_transfer(&french, &iban("PM36 2004 1010 0505 0001 3M02 606")).unwrap();
+    assert_eq!(transfer.scope(), SepaScope::Domestic);
+    assert!(transfer.bic_required());
+
+    // Switzerland is not part of the EEA.
+    let swiss = iban("CH93 0076 2011 6238 5295 7");
+    let transfer = sepa_transfer(&swiss, &swiss).unwrap();
+    assert_eq!(transfer.scope(), SepaScope::Domestic);
+    assert!(transfer.bic_required());
+}
+
+#[test]
+fn cross_border_transfers() {
+    let german = iban("DE44 5001 0517 5407 3249 31");
+    let transfer = sepa_transfer(&german, &iban("AX21 1234 5600 0007 85")).unwrap();
+    assert_eq!(transfer.scope(), SepaScope::CrossBorder);
+    assert!(!transfer.bic_required());
+
+    // Jersey is not part of the United Kingdom.
+    let transfer = sepa_transfer(
+        &iban("GB29 NWBK 6016 1331 9268 19"),
+        &iban("JE90 NWBK 6016 1331 9268 19"),
+    )
+    .unwrap();
+    assert_eq!(transfer.scope(), SepaScope::CrossBorder);
+    assert!(transfer.bic_required());
+
+    let monegasque = iban("MC58 1122 2000 0101 2345 6789 030");
+    let transfer = sepa_transfer(&monegasque, &german).unwrap();
+    assert_eq!(transfer.scope(), SepaScope::CrossBorder);
+    assert!(transfer.bic_required());
+}
+
+#[test]
+fn transfers_outside_sepa() {
+    let german = iban("DE44 5001 0517 5407 3249 31");
+    let kazakh = iban("KZ86 125K ZT50 0410 0100");
+    assert_eq!(
+        sepa_transfer(&kazakh, &german),
+        Err(SepaTransferError::DebtorNotSepa(Country::KZ))
+    );
+    let caledonian = iban("NC84 2004 1010 0505 0001 3M02 606");
+    assert_eq!(
+        sepa_transfer(&german, &caledonian),
+        Err(SepaTransferError::CreditorNotSepa("NC".parse().unwrap()))
+    );
+    let unknown: BaseIban = "AA110011123Z5678".parse().unwrap();
+    assert_eq!(
+        sepa_transfer(&german, &unknown),
+        Err(SepaTransferError::CreditorNotSepa("AA".parse().unwrap()))
+    );
+}
+
+#[test]
+fn validator_uses_sepa_territories() {
+    let validator = IbanValidator::new().sepa_only();
+    assert!(validator
+        .validate("RE42 2004 1010 0505 0001 3M02 606")
+        .is_ok());
+    assert_eq!(
+        validator.validate("NC84 2004 1010 0505 0001 3M02 606"),
+        Err(ValidateIbanError::NotSepa("NC".parse().unwrap()))
+    );
+}
@@ -1,5 +1,7 @@
 #![cfg(feature = "serde")]
-use iban::{BaseIban, Iban, ParseIbanError};
+use iban::{
+    BaseIban, Bic, ClearingSystem, Country, Iban, ParseBicError, ParseCountryError, ParseIbanError,
+};
 use serde_test::{assert_tokens, Token};
 
 #[test]
@@ -37,3 +39,29 @@ fn iban_readable() -> Result<(), ParseIbanError> {
     assert_tokens(&i.readable(), &[Token::Str(address)]);
     Ok(())
 }
+
+#[test]
+fn country() -> Result<(), ParseCountryError> {
+    assert_tokens(&Country::KW, &[Token::Str("KW")]);
+    let other: Country = "AO".parse()?;
+    assert_tokens(&other, &[Token::Str("AO")]);
+    Ok(())
+}
+
+#[test]
+fn bic() -> Result<(), ParseBicError> {
+    let bic: Bic = "DEUTDEFF500".parse()?;
+    assert_tokens(&bic, &[Token::Str("DEUTDEFF500")]);
+    Ok(())
+}
+
+#[test]
+fn clearing_system() {
+    assert_tokens(
+        &ClearingSystem::GbDsc,
+        &[Token::UnitVariant {
+            name: "ClearingSystem",
+            variant: "GBDSC",
+        }],
+    );
+}
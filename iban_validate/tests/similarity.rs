@@ -0,0 +1,82 @@
+//! This module tests the detection of similar IBANs.
+
+use iban::{BaseIban, Iban, ParseIbanError, SimilarityKind};
+
+#[test]
+/// Every kind of similarity is detected.
+fn test_kinds() -> Result<(), ParseIbanError> {
+    let iban: Iban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    let cases = [
+        ("GB29 NWBK 6016 1331 9268 19", SimilarityKind::Identical, 0),
+        (
+            "JE90 NWBK 6016 1331 9268 19",
+            SimilarityKind::CountryPrefix,
+            4,
+        ),
+        (
+            "GB25 NWBK 6016 1331 9268 91",
+            SimilarityKind::Transposition,
+            2,
+        ),
+        ("GB56 NWBK 6016 1331 9268 18", SimilarityKind::SingleEdit, 3),
+        ("GB78 NWBK 6016 1331 9268 10", SimilarityKind::SingleEdit, 3),
+        ("GB07 NWBK 6016 1331 9268 27", SimilarityKind::Different, 4),
+        ("NL91 ABNA 0417 1643 00", SimilarityKind::Different, 17),
+    ];
+    for (other, kind, distance) in cases {
+        let other: Iban = other.parse()?;
+        let similarity = iban.similarity(&other);
+        assert_eq!(similarity.kind(), kind, "{}", other);
+        assert_eq!(similarity.distance(), distance, "{}", other);
+        assert_eq!(other.similarity(&iban), similarity);
+    }
+    Ok(())
+}
+
+#[test]
+/// Check digits 02 and 99 are both valid, which is the only way for IBANs to
+/// differ in their check digits alone. The alternatives 00 and 01 for check
+/// digits 97 and 98 are rejected.
+fn test_check_digits() -> Result<(), ParseIbanError> {
+    let a: Iban = "GB02 NWBK 6016 1331 9268 20".parse()?;
+    let b: Iban = "GB99 NWBK 6016 1331 9268 20".parse()?;
+    assert_eq!(a.similarity(&b).kind(), SimilarityKind::CheckDigits);
+    assert_eq!(a.similarity(&b).distance(), 2);
+    assert_eq!(
+        BaseIban::from(a).similarity(&BaseIban::from(b)).kind(),
+        SimilarityKind::CheckDigits
+    );
+
+    assert!("GB97 NWBK 6016 1331 9260 80".parse::<Iban>().is_ok());
+    assert!("GB00 NWBK 6016 1331 9260 80".parse::<Iban>().is_err());
+    assert!("GB98 NWBK 6016 1331 9260 62".parse::<Iban>().is_ok());
+    assert!("GB01 NWBK 6016 1331 9260 62".parse::<Iban>().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "std")]
+/// Clusters are formed of chains of likely duplicates.
+fn test_cluster() -> Result<(), ParseIbanError> {
+    let ibans: Vec<Iban> = [
+        "NL91 ABNA 0417 1643 00",
+        "GB29 NWBK 6016 1331 9268 19",
+        "DE44 5001 0517 5407 3249 31",
+        "GB56 NWBK 6016 1331 9268 18",
+        "GB78 NWBK 6016 1331 9268 10",
+        "DE45 5001 0517 5407 3249 13",
+    ]
+    .iter()
+    .map(|s| s.parse())
+    .collect::<Result<_, _>>()?;
+    let clusters = iban::cluster_similar(&ibans);
+    assert_eq!(
+        clusters,
+        [
+            vec![&ibans[1], &ibans[3], &ibans[4]],
+            vec![&ibans[2], &ibans[5]]
+        ]
+    );
+    assert!(iban::cluster_similar::<Iban>(&[]).is_empty());
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! This module tests the rendering of IBANs for reading aloud.
+
+use iban::{BaseIban, Iban, ParseBaseIbanError, ParseIbanError, PhoneticAlphabet};
+
+#[test]
+/// Letters are spelled and digits are kept, grouped as in the paper format.
+fn test_spoken() -> Result<(), ParseIbanError> {
+    let iban: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+    assert_eq!(
+        iban.spoken(PhoneticAlphabet::Nato).to_string(),
+        "November Lima 9 1, Alfa Bravo November Alfa, 0 4 1 7, 1 6 4 3, 0 0"
+    );
+    assert_eq!(
+        iban.spoken(PhoneticAlphabet::Din5009).to_string(),
+        "Nürnberg Leipzig 9 1, Aachen Berlin Nürnberg Aachen, 0 4 1 7, 1 6 4 3, 0 0"
+    );
+    assert_eq!(
+        iban.spoken(PhoneticAlphabet::French).to_string(),
+        "Nicolas Louis 9 1, Anatole Berthe Nicolas Anatole, 0 4 1 7, 1 6 4 3, 0 0"
+    );
+    Ok(())
+}
+
+#[test]
+/// A `BaseIban` can be spoken as well.
+fn test_spoken_base_iban() -> Result<(), ParseBaseIbanError> {
+    let iban: BaseIban = "MT84 MALT 0110 0001 2345 MTLC AST0 01S".parse()?;
+    assert_eq!(
+        iban.spoken(PhoneticAlphabet::Nato).to_string(),
+        "Mike Tango 8 4, Mike Alfa Lima Tango, 0 1 1 0, 0 0 0 1, 2 3 4 5, \
+         Mike Tango Lima Charlie, Alfa Sierra Tango 0, 0 1 Sierra"
+    );
+    Ok(())
+}
+
+#[test]
+/// Every letter has a word in every alphabet.
+fn test_alphabets() {
+    for alphabet in [
+        PhoneticAlphabet::Nato,
+        PhoneticAlphabet::Din5009,
+        PhoneticAlphabet::French,
+    ] {
+        for letter in 'A'..='Z' {
+            let word = alphabet.word(letter).expect("every letter has a word");
+            assert!(word.starts_with(letter));
+            assert_eq!(alphabet.word(letter.to_ascii_lowercase()), Some(word));
+        }
+        assert_eq!(alphabet.word('0'), None);
+        assert_eq!(alphabet.word('é'), None);
+    }
+}
+
+#[test]
+/// With the `redact_debug` feature, `Debug` should not reveal the IBAN.
+fn test_debug() -> Result<(), ParseIbanError> {
+    let iban: Iban = "NL91 ABNA 0417 1643 00".parse()?;
+    let electronic = if cfg!(feature = "redact_debug") {
+        "NL91**********4300"
+    } else {
+        "\"NL91ABNA0417164300\""
+    };
+    assert_eq!(
+        format!("{:?}", iban.spoken(PhoneticAlphabet::Nato)),
+        format!("Spoken {{ electronic: {}, alphabet: Nato }}", electronic)
+    );
+    Ok(())
+}
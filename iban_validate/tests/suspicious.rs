@@ -0,0 +1,96 @@
+//! This module tests the detection and folding of suspicious characters.
+
+use iban::{
+    fold_suspicious, suspicious_characters, BaseIban, Iban, ParseBaseIbanError, ParseIbanError,
+    SuspiciousKind,
+};
+
+#[test]
+/// Every kind of suspicious character is detected at the right position.
+fn test_detection() {
+    let input = "\u{200f}ＧB29 NWВK\u{200b} 6016\u{3000}1331 9268 19";
+    let found: Vec<_> = suspicious_characters(input)
+        .map(|c| (c.position(), c.character(), c.kind()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (0, '\u{200f}', SuspiciousKind::BidiControl),
+            (3, 'Ｇ', SuspiciousKind::Fullwidth),
+            (12, 'В', SuspiciousKind::Homoglyph),
+            (15, '\u{200b}', SuspiciousKind::Invisible),
+            (23, '\u{3000}', SuspiciousKind::UnusualSpace),
+        ]
+    );
+    assert_eq!(
+        suspicious_characters(input).nth(2).map(|c| c.to_string()),
+        Some("the input contains a homoglyph (U+0412) at byte 12".to_owned())
+    );
+}
+
+#[test]
+/// Valid IBANs contain no suspicious characters.
+fn test_no_suspicious_characters() {
+    assert_eq!(
+        suspicious_characters("GB29 NWBK 6016 1331 9268 19").next(),
+        None
+    );
+    assert_eq!(suspicious_characters("").next(), None);
+}
+
+#[test]
+/// Folding makes the input parse, except for bidirectional control characters.
+fn test_folding() {
+    let input = "ＧB29 NWВK\u{200b} 6016\u{a0}1331 9268 １９";
+    let folded = fold_suspicious(input).to_string();
+    assert_eq!(folded, "GB29 NWBK 6016 1331 9268 19");
+    assert!(folded.parse::<Iban>().is_ok());
+    assert_eq!(fold_suspicious("ｎｌ91").collect::<String>(), "nl91");
+    assert_eq!(fold_suspicious("Σ").to_string(), "Σ");
+
+    let bidi = "\u{202e}GB29 NWBK 6016 1331 9268 19";
+    assert_eq!(fold_suspicious(bidi).to_string(), bidi);
+    assert!(fold_suspicious(bidi).to_string().parse::<Iban>().is_err());
+}
+
+#[test]
+/// Parsing an input with suspicious characters fails with an invalid format,
+/// and the characters can be found using `suspicious_characters`.
+fn test_parse_error() {
+    let cases = [
+        ("DЕ44 5001 0517 5407 3249 31", 1, SuspiciousKind::Homoglyph),
+        ("DE44 ５001 0517 5407 3249 31", 5, SuspiciousKind::Fullwidth),
+        (
+            "DE44500105175407\u{200b}324931",
+            16,
+            SuspiciousKind::Invisible,
+        ),
+        (
+            "DE44\u{a0}5001 0517 5407 3249 31",
+            4,
+            SuspiciousKind::UnusualSpace,
+        ),
+        (
+            "\u{202e}DE44 5001 0517 5407 3249 31",
+            0,
+            SuspiciousKind::BidiControl,
+        ),
+    ];
+    for (input, position, kind) in cases {
+        assert_eq!(
+            input.parse::<BaseIban>(),
+            Err(ParseBaseIbanError::InvalidFormat)
+        );
+        assert_eq!(
+            input.parse::<Iban>(),
+            Err(ParseIbanError::InvalidBaseIban {
+                source: ParseBaseIbanError::InvalidFormat
+            })
+        );
+        let character = suspicious_characters(input)
+            .next()
+            .expect("the input contains a suspicious character");
+        assert_eq!(character.position(), position);
+        assert_eq!(character.kind(), kind);
+    }
+}
@@ -0,0 +1,50 @@
+//! This module tests dependent territories that use the format of another country.
+
+use iban::{Country, FormatSource, Iban, IbanLike, ParseCountryError, ParseIbanError};
+
+#[test]
+/// Territories are validated using the format of their parent country.
+fn test_territories() -> Result<(), ParseIbanError> {
+    let iban: Iban = "RE4220041010050500013M02606".parse()?;
+    assert_eq!(iban.parent_country(), Some(Country::FR));
+    assert_eq!(iban.country().as_str(), "RE");
+    assert_eq!(iban.bank_identifier(), Some("20041"));
+    assert_eq!(iban.format_source(), Some(FormatSource::Registry));
+
+    let iban: Iban = "AX2112345600000785".parse()?;
+    assert_eq!(iban.parent_country(), Some(Country::FI));
+    assert_eq!(iban.bank_identifier(), Some("123"));
+
+    let iban: Iban = "JE90NWBK60161331926819".parse()?;
+    assert_eq!(iban.parent_country(), Some(Country::GB));
+    assert_eq!(iban.branch_identifier(), Some("601613"));
+    Ok(())
+}
+
+#[test]
+/// The BBAN of a territory should follow the format of the parent.
+fn test_invalid_territory_bban() {
+    // A valid checksum, but the bank code of Jersey should be letters.
+    assert!(matches!(
+        "JE22123460161331926819".parse::<Iban>(),
+        Err(ParseIbanError::InvalidBban(_))
+    ));
+}
+
+#[test]
+/// Countries in the registry have no parent country.
+fn test_parent() -> Result<(), ParseCountryError> {
+    for &country in Country::ALL {
+        assert_eq!(country.parent(), None);
+    }
+    for territory in ["GF", "GP", "MQ", "RE", "YT", "AX", "IM", "JE", "GG"] {
+        let country: Country = territory.parse()?;
+        assert!(matches!(country, Country::Other(_)));
+        assert!(matches!(
+            country.parent(),
+            Some(Country::FR | Country::FI | Country::GB)
+        ));
+    }
+    assert_eq!("AO".parse::<Country>()?.parent(), None);
+    Ok(())
+}
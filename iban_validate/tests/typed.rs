@@ -0,0 +1,125 @@
+//! This module tests the IBANs of which the country is known at the type
+//! level.
+
+use iban::de::GermanIban;
+use iban::gb::BritishIban;
+use iban::markers::{Gb, Nl};
+use iban::{
+    Country, CountryMarker, Iban, IbanLike, ParseIbanError, ParseTypedIbanError, TypedIban,
+};
+
+#[test]
+/// Conversions check the country.
+fn test_conversions() -> Result<(), ParseTypedIbanError> {
+    // Parse twice, since `Iban` is not `Copy` with the `zeroize` feature.
+    let parse_iban = || {
+        "NL91 ABNA 0417 1643 00"
+            .parse::<Iban>()
+            .map_err(|source| ParseTypedIbanError::InvalidIban { source })
+    };
+    let iban = parse_iban()?;
+    let dutch = TypedIban::<Nl>::try_from(parse_iban()?)?;
+    assert_eq!(dutch.country(), Country::NL);
+    assert_eq!(dutch.as_iban(), &iban);
+    assert_eq!(dutch.to_string(), "NL91 ABNA 0417 1643 00");
+    assert_eq!(Iban::from(dutch), iban);
+
+    let error = match GermanIban::try_from(iban) {
+        Err(ParseTypedIbanError::WrongCountry { source }) => source,
+        result => panic!("the IBAN is Dutch, but found {:?}", result),
+    };
+    assert_eq!(error.expected(), Country::DE);
+    assert_eq!(error.found(), Country::NL);
+    assert_eq!(
+        error.to_string(),
+        "expected an IBAN of DE, but found one of NL"
+    );
+    Ok(())
+}
+
+#[test]
+/// Parsing reports invalid IBANs and IBANs of other countries.
+fn test_parse() {
+    assert!(matches!(
+        "DE44 5001 0517 5407 3249 32".parse::<GermanIban>(),
+        Err(ParseTypedIbanError::InvalidIban {
+            source: ParseIbanError::InvalidBaseIban { .. }
+        })
+    ));
+    assert!(matches!(
+        "JE90 NWBK 6016 1331 9268 19".parse::<BritishIban>(),
+        Err(ParseTypedIbanError::WrongCountry { .. })
+    ));
+    assert!(BritishIban::try_from("GB29 NWBK 6016 1331 9268 19").is_ok());
+}
+
+#[test]
+/// The typed accessors return the national components.
+fn test_accessors() -> Result<(), ParseTypedIbanError> {
+    let german: GermanIban = "DE44 5001 0517 5407 3249 31".parse()?;
+    assert_eq!(german.blz(), "50010517");
+    assert_eq!(german.kontonummer(), "5407324931");
+    assert_eq!(german.country_code(), "DE");
+
+    let british: BritishIban = "GB29 NWBK 6016 1331 9268 19".parse()?;
+    assert_eq!(british.sort_code(), "601613");
+    assert_eq!(british.account_number(), "31926819");
+    assert_eq!(british.branch_identifier(), Some("601613"));
+    Ok(())
+}
+
+#[test]
+/// An IBAN that does not follow the built-in format of its country is
+/// rejected, so that the accessors can rely on it.
+fn test_format_checked() -> Result<(), ParseIbanError> {
+    use iban::markers::Cr;
+    use iban::RegistryDate;
+
+    let old = Iban::parse_as_of("CR05 1520 2001 0262 8406 6", RegistryDate::new(2015, 1))?;
+    assert!(matches!(
+        TypedIban::<Cr>::try_from(old),
+        Err(ParseTypedIbanError::InvalidIban {
+            source: ParseIbanError::InvalidBban(_)
+        })
+    ));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "std")]
+/// An IBAN validated with a custom format of the same length is rejected if
+/// it does not follow the built-in format.
+fn test_format_checked_registry() -> Result<(), Box<dyn std::error::Error>> {
+    let registry = iban::Registry::parse_notation("DE: 18!c")?;
+    let letters = registry.validate("DE50 ABCD 0517 5407 3249 31".parse()?)?;
+    assert!(matches!(
+        GermanIban::try_from(letters),
+        Err(ParseTypedIbanError::InvalidIban {
+            source: ParseIbanError::InvalidBban(_)
+        })
+    ));
+    let digits = registry.validate("DE44 5001 0517 5407 3249 31".parse()?)?;
+    assert_eq!(GermanIban::try_from(digits)?.blz(), "50010517");
+    Ok(())
+}
+
+#[test]
+/// The markers match their country.
+fn test_markers() {
+    assert_eq!(Gb::COUNTRY, Country::GB);
+    assert_eq!(Gb::COUNTRY_CODE, Country::GB.as_str());
+    assert_eq!(Nl::COUNTRY_CODE, "NL");
+}
+
+#[test]
+#[cfg(feature = "serde")]
+/// Deserialization checks the country.
+fn test_serde() {
+    use serde::de::value::{Error, StrDeserializer};
+    use serde::de::IntoDeserializer;
+    use serde::Deserialize;
+    let deserializer: StrDeserializer<'_, Error> = "NL91ABNA0417164300".into_deserializer();
+    assert!(GermanIban::deserialize(deserializer).is_err());
+    let deserializer: StrDeserializer<'_, Error> = "DE44500105175407324931".into_deserializer();
+    assert!(GermanIban::deserialize(deserializer).is_ok());
+}
@@ -0,0 +1,110 @@
+use iban::{
+    BaseIban, Country, IbanLike, IbanValidator, ParseBaseIbanError, ParseIbanError,
+    ValidateIbanError, ValidatedIban,
+};
+
+#[test]
+fn default_validator_accepts_ibans() {
+    let validator = IbanValidator::new();
+    let validated = validator.validate("DE44 5001 0517 5407 3249 31").unwrap();
+    assert!(validated.iban().is_some());
+    assert_eq!(validated.electronic_str(), "DE44500105175407324931");
+    assert_eq!(validated.to_string(), "DE44 5001 0517 5407 3249 31");
+    assert_eq!(
+        validator.validate("DE45 5001 0517 5407 3249 31"),
+        Err(ValidateIbanError::InvalidIban {
+            source: ParseIbanError::InvalidBaseIban {
+                source: ParseBaseIbanError::InvalidChecksum
+            }
+        })
+    );
+    let base_iban: BaseIban = "AA110011123Z5678".parse().unwrap();
+    assert_eq!(
+        validator.validate("AA110011123Z5678"),
+        Err(ValidateIbanError::InvalidIban {
+            source: ParseIbanError::UnknownCountry(base_iban)
+        })
+    );
+}
+
+#[test]
+fn allow_unknown_countries() {
+    let validator = IbanValidator::new().allow_unknown_countries();
+    let validated = validator.validate("AA110011123Z5678").unwrap();
+    let base_iban: BaseIban = "AA110011123Z5678".parse().unwrap();
+    assert!(matches!(validated, ValidatedIban::UnknownCountry(_)));
+    assert_eq!(validated.base_iban(), &base_iban);
+    assert_eq!(validated.iban(), None);
+    assert_eq!(BaseIban::from(validated), base_iban);
+    // The BBAN of known countries is still validated.
+    assert!(matches!(
+        validator.validate("AL84212110090000AB023569874"),
+        Err(ValidateIbanError::InvalidIban {
+            source: ParseIbanError::InvalidBban(_)
+        })
+    ));
+}
+
+#[test]
+fn allow_and_deny_countries() {
+    let validator = IbanValidator::new()
+        .allow_countries([Country::DE, Country::NL])
+        .allow_countries([Country::GB])
+        .deny_countries([Country::NL]);
+    assert!(validator.validate("DE44 5001 0517 5407 3249 31").is_ok());
+    assert!(validator.validate("GB29 NWBK 6016 1331 9268 19").is_ok());
+    assert_eq!(
+        validator.validate("NL91 ABNA 0417 1643 00"),
+        Err(ValidateIbanError::CountryDenied(Country::NL))
+    );
+    assert_eq!(
+        validator.validate("BE68 5390 0754 7034"),
+        Err(ValidateIbanError::CountryNotAllowed(Country::BE))
+    );
+    // Territories are not included in the country whose format they use.
+    assert_eq!(
+        validator.validate("JE90 NWBK 6016 1331 9268 19"),
+        Err(ValidateIbanError::CountryNotAllowed("JE".parse().unwrap()))
+    );
+}
+
+#[test]
+fn sepa_only() {
+    let validator = IbanValidator::new().sepa_only().allow_unknown_countries();
+    assert!(validator.validate("DE44 5001 0517 5407 3249 31").is_ok());
+    assert!(validator.validate("JE90 NWBK 6016 1331 9268 19").is_ok());
+    assert_eq!(
+        validator.validate("KZ86 125K ZT50 0410 0100"),
+        Err(ValidateIbanError::NotSepa(Country::KZ))
+    );
+    assert_eq!(
+        validator.validate("AA110011123Z5678"),
+        Err(ValidateIbanError::NotSepa("AA".parse().unwrap()))
+    );
+}
+
+#[test]
+fn require_national_check_digits() {
+    let validator = IbanValidator::new().require_national_check_digits();
+    assert!(validator.validate("BE68 5390 0754 7034").is_ok());
+    // Countries without supported national check digits are accepted.
+    assert!(validator.validate("DE44 5001 0517 5407 3249 31").is_ok());
+    let iban = "BE41 5390 0754 7035".parse().unwrap();
+    assert_eq!(
+        validator.validate("BE41 5390 0754 7035"),
+        Err(ValidateIbanError::InvalidNationalCheckDigits(iban))
+    );
+    assert!(IbanValidator::new().validate("BE41 5390 0754 7035").is_ok());
+}
+
+#[test]
+fn validator_is_shared_between_threads() {
+    let validator = IbanValidator::new().sepa_only();
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                assert!(validator.validate("DE44 5001 0517 5407 3249 31").is_ok());
+            });
+        }
+    });
+}
@@ -21,7 +21,13 @@ use std::str::FromStr;
 
 #[derive(Debug)]
 struct RegistryRecord<'a> {
+    country_name: &'a str,
     country_code: &'a str,
+    territories: Vec<&'a str>,
+    sepa: bool,
+    bban_length: usize,
+    iban_length: usize,
+    effective_date: (u16, u8),
     bban: &'a str,
     iban_electronic: &'a str,
     iban_print: &'a str,
@@ -210,6 +216,16 @@ impl<'a> RegistryRecord<'a> {
             assert!(self.bank_identifier_pattern.is_none());
         }
 
+        // The lengths should match the structure and the example.
+        let structure_length: usize = self
+            .iban_structure
+            .iter()
+            .map(|(len, _)| len.parse::<usize>().unwrap())
+            .sum();
+        assert_eq!(self.bban_length, structure_length);
+        assert_eq!(self.iban_length, self.bban_length + 4);
+        assert_eq!(self.iban_electronic.len(), self.iban_length);
+
         // Branch info
         if let Some(branch_position) = &self.branch_identifier_position {
             let branch_example = self.branch_identifier_example.expect("expected example");
@@ -236,7 +252,13 @@ impl<'a> RegistryReader<'a> {
         let mut records: Vec<RegistryRecord<'a>> = (1..records_transposed[0].len())
             .map(|i| -> anyhow::Result<_> {
                 Ok(RegistryRecord {
+                    country_name: &records_transposed[1][i],
                     country_code: &records_transposed[2][i],
+                    territories: territories(&records_transposed[3][i]),
+                    sepa: parse_yes_no(&records_transposed[4][i]).unwrap().1,
+                    bban_length: records_transposed[9][i].parse()?,
+                    iban_length: records_transposed[19][i].parse()?,
+                    effective_date: effective_date(&records_transposed[20][i]).unwrap().1,
                     bban: &records_transposed[16][i],
                     iban_electronic: &records_transposed[21][i],
                     iban_print: &records_transposed[22][i],
@@ -274,6 +296,89 @@ impl<'a> RegistryReader<'a> {
 
 const FILE_PATH: &str = "./swift_iban_registry.txt";
 
+/// The kind of a component of the BBAN, and its position.
+type NationalComponent = (&'static str, Range<usize>);
+
+/// A BBAN structure that has been replaced: the country code, the year and
+/// month from which it was no longer in effect, the structure and its
+/// components.
+type HistoricalStructure = (
+    &'static str,
+    (u16, u8),
+    &'static str,
+    &'static [NationalComponent],
+);
+
+/// BBAN structures that have been replaced in a registry release. The registry
+/// itself only contains the current structure, so these are collected from
+/// the release notes. Every entry contains the country code, the date from
+/// which the structure was no longer in effect, the structure itself and its
+/// components other than the account number. For every country, the most
+/// recent change should come first.
+///
+/// An IBAN does not record the format it was validated with, so the format is
+/// recognized by the length of the BBAN. Every structure of a country should
+/// therefore have a different length. Only changes of the structure are
+/// listed, and so far only the change of Costa Rica has been collected.
+const HISTORICAL_STRUCTURES: &[HistoricalStructure] = &[
+    // Costa Rica added a leading zero to the bank code, extending the IBAN
+    // from 21 to 22 characters.
+    ("CR", (2017, 7), "3!n14!n", &[("BankIdentifier", 0..3)]),
+];
+
+/// Components of the BBAN that are not described by the registry, collected
+/// from national documentation. Every entry contains the country code and the
+/// components with their position in the BBAN. The kind is the name of a
+/// variant of `ComponentKind`. Positions that are not covered by the bank or
+/// branch identifier or by one of these components are the account number.
+const NATIONAL_COMPONENTS: &[(&str, &[NationalComponent])] = &[
+    ("AL", &[("NationalCheckDigits", 7..8)]),
+    ("BA", &[("NationalCheckDigits", 14..16)]),
+    ("BE", &[("NationalCheckDigits", 10..12)]),
+    ("BG", &[("AccountType", 8..10)]),
+    ("BI", &[("NationalCheckDigits", 21..23)]),
+    ("BR", &[("AccountType", 23..24)]),
+    ("DJ", &[("NationalCheckDigits", 21..23)]),
+    ("DK", &[("NationalCheckDigits", 13..14)]),
+    ("EE", &[("NationalCheckDigits", 15..16)]),
+    ("ES", &[("NationalCheckDigits", 8..10)]),
+    ("FI", &[("NationalCheckDigits", 13..14)]),
+    ("FO", &[("NationalCheckDigits", 13..14)]),
+    // The registry does not specify the branch code (code guichet) of France,
+    // even though it is specified for Monaco, which uses the same format.
+    (
+        "FR",
+        &[("BranchIdentifier", 5..10), ("NationalCheckDigits", 21..23)],
+    ),
+    ("GL", &[("NationalCheckDigits", 13..14)]),
+    ("GT", &[("Currency", 4..6), ("AccountType", 6..8)]),
+    (
+        "HU",
+        &[
+            ("NationalCheckDigits", 7..8),
+            ("NationalCheckDigits", 23..24),
+        ],
+    ),
+    ("IS", &[("AccountType", 4..6)]),
+    ("IT", &[("NationalCheckDigits", 0..1)]),
+    ("MC", &[("NationalCheckDigits", 21..23)]),
+    ("ME", &[("NationalCheckDigits", 16..18)]),
+    ("MK", &[("NationalCheckDigits", 13..15)]),
+    ("MR", &[("NationalCheckDigits", 21..23)]),
+    ("MU", &[("Currency", 23..26)]),
+    ("NO", &[("NationalCheckDigits", 10..11)]),
+    ("PT", &[("NationalCheckDigits", 19..21)]),
+    ("RS", &[("NationalCheckDigits", 16..18)]),
+    ("SC", &[("Currency", 24..27)]),
+    ("SE", &[("NationalCheckDigits", 19..20)]),
+    ("SI", &[("NationalCheckDigits", 13..15)]),
+    ("SM", &[("NationalCheckDigits", 0..1)]),
+    ("ST", &[("NationalCheckDigits", 19..21)]),
+    ("TL", &[("NationalCheckDigits", 17..19)]),
+    ("TN", &[("NationalCheckDigits", 18..20)]),
+    ("XK", &[("NationalCheckDigits", 14..16)]),
+];
+
 /// Fix the UTF8 of a file by performing a lossless conversion.
 fn fix_utf8(file_name: &str) -> anyhow::Result<()> {
     // The file is invalid utf8, so we will first process it.
@@ -306,11 +411,25 @@ fn main() -> anyhow::Result<()> {
     // Generate this file for checking and getting country specific info.
     let mut generated_file = File::create("../iban_validate/src/generated.rs")?;
     writeln!(generated_file, "//! This file is automatically generated by `iban_validate_registry_generation` from the IBAN registry.")?;
+    generate_country_enum(&mut generated_file, &registry)?;
+    writeln!(generated_file)?;
     generate_bank_identifier_position_in_bban_match_arm(&mut generated_file, &registry)?;
     writeln!(generated_file)?;
     generate_branch_identifier_position_in_bban_match_arm(&mut generated_file, &registry)?;
     writeln!(generated_file)?;
     generate_format_match_arm(&mut generated_file, &registry)?;
+    writeln!(generated_file)?;
+    generate_country_info_match_arm(&mut generated_file, &registry)?;
+    writeln!(generated_file)?;
+    generate_historical_format_match_arm(&mut generated_file, &registry)?;
+    writeln!(generated_file)?;
+    generate_parent_country_match_arm(&mut generated_file, &registry)?;
+    writeln!(generated_file)?;
+    generate_components_match_arm(&mut generated_file, &registry)?;
+    writeln!(generated_file)?;
+    generate_country_markers(&mut generated_file, &registry)?;
+    writeln!(generated_file)?;
+    generate_registry_examples(&mut generated_file, &registry)?;
 
     // Generate this file with test cases.
     let mut generated_file = File::create("../iban_validate/tests/registry_examples_generated.rs")?;
@@ -319,6 +438,85 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Generate the `Country` enum, with one variant for every country in the
+/// registry.
+fn generate_country_enum(write: &mut impl Write, contents: &RegistryReader) -> anyhow::Result<()> {
+    writeln!(
+        write,
+        "
+/// A country, identified by its two-letter country code. Every country in the
+/// IBAN registry has its own variant. Other country codes, for example of a
+/// [`BaseIban`](crate::BaseIban) from a country that is not in the registry,
+/// are represented by [`Country::Other`]. Countries are compared by their
+/// country code, so `Country::Other` with the code of a country in the
+/// registry is equal to the variant of that country.
+#[derive(Copy, Clone, Debug)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Country {{"
+    )?;
+    for record in &contents.records {
+        writeln!(write, "\t/// {}", record.country_name)?;
+        writeln!(write, "\t{},", record.country_code)?;
+    }
+    writeln!(
+        write,
+        "\t/// A country that is not in the IBAN registry.
+\tOther(crate::CountryCode),
+}}
+
+impl Country {{
+\t/// All countries in the IBAN registry, ordered by country code. This does
+\t/// not include [`Country::Other`].
+\tpub const ALL: &'static [Country] = &["
+    )?;
+    for record in &contents.records {
+        writeln!(write, "\t\tCountry::{},", record.country_code)?;
+    }
+    writeln!(
+        write,
+        "\t];
+
+\t/// Get the two-letter country code.
+\t///
+\t/// # Example
+\t/// ```rust
+\t/// use iban::Country;
+\t/// assert_eq!(Country::DE.as_str(), \"DE\");
+\t/// ```
+\t#[must_use]
+\tpub fn as_str(&self) -> &str {{
+\t\tmatch self {{"
+    )?;
+    for record in &contents.records {
+        writeln!(
+            write,
+            "\t\t\tCountry::{} => \"{}\",",
+            record.country_code, record.country_code
+        )?;
+    }
+    writeln!(
+        write,
+        "\t\t\tCountry::Other(country_code) => country_code.as_str(),
+\t\t}}
+\t}}
+
+\t/// Get the country in the registry with the given country code.
+\t#[inline]
+\tpub(crate) fn from_registry_code(country_code: &str) -> Option<Country> {{
+\t\tmatch country_code {{"
+    )?;
+    for record in &contents.records {
+        writeln!(
+            write,
+            "\t\t\t\"{}\" => Some(Country::{}),",
+            record.country_code, record.country_code
+        )?;
+    }
+    writeln!(write, "\t\t\t_ => None,")?;
+    writeln!(write, "\t\t}}\n\t}}\n}}")?;
+    Ok(())
+}
+
 fn generate_bank_identifier_position_in_bban_match_arm(
     mut writer: &mut impl Write,
     contents: &RegistryReader,
@@ -401,6 +599,62 @@ pub(crate) fn branch_identifier(country_code: &str) -> Option<core::ops::Range<u
     Ok(())
 }
 
+/// Find the country codes of the territories that are included in a country.
+/// The registry lists them as "French Guyana (GF), Guadeloupe (GP), ...", so
+/// we look for two uppercase letters between parentheses.
+fn territories(input: &str) -> Vec<&str> {
+    input
+        .split('(')
+        .skip(1)
+        .filter_map(|part| part.get(0..3))
+        .filter_map(|part| part.strip_suffix(')'))
+        .filter(|code| code.chars().all(|c| c.is_ascii_uppercase()))
+        .collect()
+}
+
+#[test]
+fn test_territories() {
+    assert_eq!(territories("N/A"), Vec::<&str>::new());
+    assert_eq!(
+        territories("Isle of Man (IM), Jersey (JE), Guernsey (GG)"),
+        ["IM", "JE", "GG"]
+    );
+    assert_eq!(territories("Saint Martin (French part) (MF)"), ["MF"]);
+}
+
+/// Parse a "Yes" or "No" entry.
+fn parse_yes_no(input: &str) -> IResult<&str, bool> {
+    alt((map(tag("Yes"), |_| true), map(tag("No"), |_| false)))(input)
+}
+
+/// Parse an effective date such as "Apr-07" to a year and month.
+fn effective_date(input: &str) -> IResult<&str, (u16, u8)> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    map(
+        separated_pair(
+            map_res(take(3_usize), |month: &str| {
+                MONTHS
+                    .iter()
+                    .position(|m| *m == month)
+                    .map(|m| m as u8 + 1)
+                    .ok_or("unknown month")
+            }),
+            tag("-"),
+            map_res(digit1, u16::from_str),
+        ),
+        |(month, year)| (2000 + year, month),
+    )(input)
+}
+
+#[test]
+fn test_effective_date() {
+    assert_eq!(effective_date("Apr-07"), Ok(("", (2007, 4))));
+    assert_eq!(effective_date("Dec-11"), Ok(("", (2011, 12))));
+    assert!(effective_date("Foo-11").is_err());
+}
+
 fn parse_pattern(contents: &str) -> IResult<&str, Vec<(&str, &str)>> {
     many1(separated_pair(digit1, tag("!"), alpha1))(contents)
 }
@@ -437,10 +691,10 @@ fn generate_format_match_arm(
 ) -> anyhow::Result<()> {
     writeln!(
         write,
-        "use crate::countries::CharacterType;
+        "use crate::countries::{{CharacterType, HistoricalFormat}};
 
 #[inline]
-pub(crate) fn country_pattern(country_code: &str) -> Option<&[(usize, CharacterType)]> {{
+pub(crate) fn country_pattern(country_code: &str) -> Option<&'static [(usize, CharacterType)]> {{
 \tuse CharacterType::*;
 \tuse core::borrow::Borrow;
 \tmatch country_code {{"
@@ -464,6 +718,356 @@ pub(crate) fn country_pattern(country_code: &str) -> Option<&[(usize, CharacterT
     Ok(())
 }
 
+/// Generate match arms for the registry information of every country.
+fn generate_country_info_match_arm(
+    write: &mut impl Write,
+    contents: &RegistryReader,
+) -> anyhow::Result<()> {
+    writeln!(
+        write,
+        "use crate::{{CountryInfo, RegistryDate}};
+
+/// Get the information about a country in the registry.
+#[inline]
+pub(crate) fn country_info(country_code: &str) -> Option<&'static CountryInfo> {{
+\tmatch country_code {{"
+    )?;
+    for record in &contents.records {
+        writeln!(
+            write,
+            "\t\t\"{}\" => Some(&CountryInfo {{
+\t\t\tcountry: Country::{},
+\t\t\tname: \"{}\",
+\t\t\tiban_length: {},
+\t\t\tbban_length: {},
+\t\t\tsepa: {},
+\t\t\teffective_date: RegistryDate {{ year: {}, month: {} }},
+\t\t\texample: \"{}\",
+\t\t}}),",
+            record.country_code,
+            record.country_code,
+            record.country_name,
+            record.iban_length,
+            record.bban_length,
+            record.sepa,
+            record.effective_date.0,
+            record.effective_date.1,
+            record.iban_electronic
+        )?;
+    }
+    writeln!(write, "\t\t_ => None,")?;
+    writeln!(write, "\t}}\n}}")?;
+    Ok(())
+}
+
+/// Generate match arms for the structures that were in effect before the
+/// current structure.
+fn generate_historical_format_match_arm(
+    write: &mut impl Write,
+    contents: &RegistryReader,
+) -> anyhow::Result<()> {
+    writeln!(
+        write,
+        "/// Get the BBAN formats that preceded the current format, together with
+/// the date from which they were no longer in effect and the components of
+/// the BBAN. The most recent format comes first.
+#[inline]
+pub(crate) fn historical_country_patterns(
+\tcountry_code: &str,
+) -> &'static [HistoricalFormat] {{
+\t#[allow(clippy::match_same_arms)] // For clarity, identical arms are not combined.
+\tmatch country_code {{"
+    )?;
+    for record in &contents.records {
+        let history: Vec<_> = HISTORICAL_STRUCTURES
+            .iter()
+            .filter(|(country_code, _, _, _)| *country_code == record.country_code)
+            .collect();
+        if history.is_empty() {
+            continue;
+        }
+        // The dates should be after the effective date and in reverse
+        // chronological order.
+        let mut previous = (u16::MAX, u8::MAX);
+        for (_, until, _, _) in &history {
+            assert!(*until > record.effective_date && *until < previous);
+            previous = *until;
+        }
+        let mut lengths = vec![record.bban_length];
+        let formatted = history
+            .iter()
+            .map(|(_, (year, month), structure, components)| {
+                let (rest, structure) = parse_pattern(structure).unwrap();
+                assert!(rest.is_empty());
+                // The format of an IBAN is recognized by its length.
+                let length: usize = structure
+                    .iter()
+                    .map(|(num, _)| num.parse::<usize>().unwrap())
+                    .sum();
+                assert!(!lengths.contains(&length));
+                lengths.push(length);
+                format!(
+                    "(RegistryDate {{ year: {}, month: {} }}, &[{}], &[{}])",
+                    year,
+                    month,
+                    structure
+                        .iter()
+                        .map(|(num, t)| {
+                            format!("({}, CharacterType::{})", num, t.to_ascii_uppercase())
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    fill_account_number(components.to_vec(), length)
+                        .iter()
+                        .map(|(kind, position)| {
+                            format!(
+                                "(ComponentKind::{}, {}..{})",
+                                kind, position.start, position.end
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            write,
+            "\t\t\"{}\" => &[{}],",
+            record.country_code, formatted
+        )?;
+    }
+    writeln!(write, "\t\t_ => &[],")?;
+    writeln!(write, "\t}}\n}}")?;
+    Ok(())
+}
+
+/// Generate match arms for the territories that use the format of another
+/// country.
+fn generate_parent_country_match_arm(
+    write: &mut impl Write,
+    contents: &RegistryReader,
+) -> anyhow::Result<()> {
+    writeln!(
+        write,
+        "/// Get the country whose format is used by a dependent territory.
+#[inline]
+pub(crate) fn parent_country(country_code: &str) -> Option<Country> {{
+\t#[allow(clippy::match_same_arms)] // For clarity, identical arms are not combined.
+\tmatch country_code {{"
+    )?;
+    let mut territories: Vec<(&str, &str)> = contents
+        .records
+        .iter()
+        .flat_map(|record| {
+            record
+                .territories
+                .iter()
+                .map(|territory| (*territory, record.country_code))
+        })
+        .collect();
+    territories.sort_unstable();
+    for (territory, country_code) in territories {
+        // A territory with its own entry in the registry is not an alias.
+        assert!(contents
+            .records
+            .iter()
+            .all(|record| record.country_code != territory));
+        writeln!(
+            write,
+            "\t\t\"{}\" => Some(Country::{}),",
+            territory, country_code
+        )?;
+    }
+    writeln!(write, "\t\t_ => None,")?;
+    writeln!(write, "\t}}\n}}")?;
+    Ok(())
+}
+
+/// Get the labelled components of the BBAN of a country, ordered by position.
+/// The bank and branch identifiers come from the registry, other components
+/// from `NATIONAL_COMPONENTS` and the remaining positions form the account
+/// number.
+fn components(record: &RegistryRecord) -> Vec<(&'static str, Range<usize>)> {
+    let mut components: Vec<NationalComponent> = Vec::new();
+    if let Some(position) = &record.bank_identifier_position {
+        components.push(("BankIdentifier", position.clone()));
+    }
+    if let Some(position) = &record.branch_identifier_position {
+        components.push(("BranchIdentifier", position.clone()));
+    }
+    for (_, national) in NATIONAL_COMPONENTS
+        .iter()
+        .filter(|(country_code, _)| *country_code == record.country_code)
+    {
+        for (kind, position) in national.iter() {
+            // Only add a branch if the registry does not specify one.
+            assert!(*kind != "BranchIdentifier" || record.branch_identifier_position.is_none());
+            components.push((kind, position.clone()));
+        }
+    }
+    fill_account_number(components, record.bban_length)
+}
+
+/// Sort the components and fill the gaps between them with the account
+/// number, so that they cover a BBAN of the given length.
+fn fill_account_number(
+    mut components: Vec<NationalComponent>,
+    bban_length: usize,
+) -> Vec<NationalComponent> {
+    components.sort_unstable_by_key(|(_, position)| position.start);
+
+    // Fill the gaps with the account number.
+    let mut result = Vec::new();
+    let mut end = 0;
+    for (kind, position) in components {
+        assert!(position.start >= end, "components should not overlap");
+        assert!(position.start < position.end);
+        if position.start > end {
+            result.push(("AccountNumber", end..position.start));
+        }
+        end = position.end;
+        result.push((kind, position));
+    }
+    assert!(end <= bban_length);
+    if end < bban_length {
+        result.push(("AccountNumber", end..bban_length));
+    }
+    result
+}
+
+#[test]
+fn test_components() {
+    let record = RegistryRecord {
+        country_name: "Belgium",
+        country_code: "BE",
+        territories: Vec::new(),
+        sepa: true,
+        bban_length: 12,
+        iban_length: 16,
+        effective_date: (2007, 4),
+        bban: "539007547034",
+        iban_electronic: "BE68539007547034",
+        iban_print: "BE68 5390 0754 7034",
+        bank_identifier_position: Some(0..3),
+        bank_identifier_pattern: Some(vec!["3"]),
+        bank_identifier_example: Some("539"),
+        branch_identifier_position: None,
+        branch_identifier_example: None,
+        iban_structure: vec![("3", "n"), ("7", "n"), ("2", "n")],
+    };
+    assert_eq!(
+        components(&record),
+        vec![
+            ("BankIdentifier", 0..3),
+            ("AccountNumber", 3..10),
+            ("NationalCheckDigits", 10..12)
+        ]
+    );
+}
+
+/// Generate match arms for the labelled components of the BBAN.
+fn generate_components_match_arm(
+    write: &mut impl Write,
+    contents: &RegistryReader,
+) -> anyhow::Result<()> {
+    writeln!(
+        write,
+        "use crate::ComponentKind;
+
+/// Get the components of the BBAN of a country, ordered by their position.
+#[inline]
+pub(crate) fn components(country_code: &str) -> &'static [(ComponentKind, core::ops::Range<usize>)] {{
+	#[allow(clippy::match_same_arms)] // For clarity, identical arms are not combined.
+	match country_code {{"
+    )?;
+    for record in &contents.records {
+        let formatted = components(record)
+            .iter()
+            .map(|(kind, position)| {
+                format!(
+                    "(ComponentKind::{}, {}..{})",
+                    kind, position.start, position.end
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            write,
+            "\t\t\"{}\" => &[{}],",
+            record.country_code, formatted
+        )?;
+    }
+    writeln!(write, "\t\t_ => &[],")?;
+    writeln!(write, "\t}}\n}}")?;
+    Ok(())
+}
+
+/// Generate a type for every country in the registry, which represents the
+/// country at the type level.
+fn generate_country_markers(
+    write: &mut impl Write,
+    contents: &RegistryReader,
+) -> anyhow::Result<()> {
+    writeln!(
+        write,
+        "/// Types that represent a country of the IBAN registry at the type level,
+/// for use with [`TypedIban`](crate::TypedIban).
+pub mod markers {{
+\tuse crate::{{typed::Sealed, Country, CountryMarker}};"
+    )?;
+    for record in &contents.records {
+        let mut name = record.country_code.chars();
+        let name: String = name
+            .next()
+            .into_iter()
+            .chain(name.flat_map(char::to_lowercase))
+            .collect();
+        writeln!(
+            write,
+            "
+\t/// The country marker of {}.
+\t#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+\tpub struct {};
+
+\timpl Sealed for {} {{}}
+
+\timpl CountryMarker for {} {{
+\t\tconst COUNTRY: Country = Country::{};
+\t\tconst COUNTRY_CODE: &'static str = \"{}\";
+\t}}",
+            record.country_name, name, name, name, record.country_code, record.country_code
+        )?;
+    }
+    writeln!(write, "}}")?;
+    Ok(())
+}
+
+/// Generate the sorted list of the examples in the registry, so that they
+/// can be recognized.
+fn generate_registry_examples(
+    write: &mut impl Write,
+    contents: &RegistryReader,
+) -> anyhow::Result<()> {
+    let mut examples: Vec<&str> = contents
+        .records
+        .iter()
+        .map(|record| record.iban_electronic)
+        .collect();
+    examples.sort_unstable();
+    examples.dedup();
+    writeln!(
+        write,
+        "/// The examples in the registry, in the electronic format and sorted.
+pub(crate) const REGISTRY_EXAMPLES: &[&str] = &["
+    )?;
+    for example in examples {
+        writeln!(write, "\t\"{}\",", example)?;
+    }
+    writeln!(write, "];")?;
+    Ok(())
+}
+
 #[derive(Debug)]
 #[allow(dead_code)] // Allow since it is used for printing
 struct RegistryExample<'a> {